@@ -1,11 +1,29 @@
+use super::set_meta::{SetMeta, SetType};
 use super::state::{ActiveModal, AddExerciseField, AddWorkoutField, App};
+use super::suggestions::rank_suggestions;
+use super::AppInputError;
 use anyhow::Result;
+use chrono::{DateTime, TimeZone, Utc};
 use ratatui::widgets::ListState;
 use task_athlete_lib::{ExerciseDefinition, ExerciseType, Units, Workout};
 
+/// The Edit Workout modal's fields, filled from the set being edited.
+struct EditWorkoutInputs {
+    sets_input: String,
+    reps_input: String,
+    weight_input: String,
+    duration_input: String,
+    distance_input: String,
+    set_type: Option<SetType>,
+    rpe_input: String,
+    rir_input: String,
+    notes_input: String,
+}
+
 // Make handle_key_event a method on App
 impl App {
     pub fn open_add_workout_modal(&mut self) -> Result<()> {
+        self.log_pending_planned_set = None; // A plain add doesn't confirm a planned set
         let mut initial_exercise_input = String::new();
         let mut initial_sets = "1".to_string();
         let mut initial_reps = String::new();
@@ -14,6 +32,7 @@ impl App {
         let mut initial_distance = String::new();
         let initial_notes = String::new();
         let mut resolved_exercise = None;
+        let mut progression_suggestion = None;
 
         // Fetch all identifiers for suggestions
         let all_identifiers = self.get_all_exercise_identifiers();
@@ -37,6 +56,7 @@ impl App {
                             &mut initial_duration,
                             &mut initial_distance,
                         );
+                        progression_suggestion = self.progression_suggestion_for(&def);
                         resolved_exercise = Some(def.clone());
                     }
                     Ok(None) => { /* Handle unlikely case where selected name doesn't resolve */ }
@@ -55,6 +75,9 @@ impl App {
             weight_input: initial_weight,
             duration_input: initial_duration,
             distance_input: initial_distance,
+            set_type: None,
+            rpe_input: String::new(),
+            rir_input: String::new(),
             notes_input: initial_notes,
             focused_field: AddWorkoutField::Exercise,
             error_message: None,
@@ -62,10 +85,86 @@ impl App {
             all_exercise_identifiers: all_identifiers,
             exercise_suggestions: Vec::new(), // Start with empty suggestions ALWAYS
             suggestion_list_state: ListState::default(),
+            progression_suggestion,
         };
         Ok(())
     }
 
+    /// Opens AddWorkout prefilled from the selected planned set. Submitting it logs the set
+    /// and removes it from the plan.
+    pub fn open_add_workout_modal_for_planned_set(&mut self) -> Result<()> {
+        let Some(planned) = self
+            .log_planned_list_state
+            .selected()
+            .and_then(|i| self.planned_sets_for_viewed_date().get(i))
+            .cloned()
+        else {
+            return Ok(());
+        };
+
+        let def = match self
+            .service
+            .resolve_exercise_identifier(&planned.exercise_name)
+        {
+            Ok(Some(def)) => def,
+            Ok(None) => {
+                self.set_error(format!("Exercise '{}' not found", planned.exercise_name));
+                return Ok(());
+            }
+            Err(e) => {
+                self.set_error(format!("Error resolving exercise: {}", e));
+                return Ok(());
+            }
+        };
+
+        // Start from the last workout, then apply what the routine asks for
+        let mut sets_input = "1".to_string();
+        let mut reps_input = String::new();
+        let mut weight_input = String::new();
+        let mut duration_input = String::new();
+        let mut distance_input = String::new();
+        let last_workout = self.get_last_or_specific_workout(&def.name, None);
+        self.populate_workout_inputs_from_def_and_last_workout(
+            &def,
+            last_workout,
+            &mut sets_input,
+            &mut reps_input,
+            &mut weight_input,
+            &mut duration_input,
+            &mut distance_input,
+        );
+        sets_input = "1".to_string(); // Planned sets are confirmed one at a time
+        if let Some(reps) = planned.reps {
+            reps_input = reps.to_string();
+        }
+        if let Some(weight) = planned.weight {
+            weight_input = format!("{:.1}", weight);
+        }
+
+        self.active_modal = ActiveModal::AddWorkout {
+            exercise_input: def.name.clone(),
+            sets_input,
+            reps_input,
+            weight_input,
+            duration_input,
+            distance_input,
+            // An AMRAP entry in the routine is logged as an AMRAP set
+            set_type: planned.reps.is_none().then_some(SetType::Amrap),
+            rpe_input: String::new(),
+            rir_input: String::new(),
+            notes_input: String::new(),
+            focused_field: AddWorkoutField::Reps,
+            error_message: None,
+            progression_suggestion: self.progression_suggestion_for(&def),
+            resolved_exercise: Some(def),
+            all_exercise_identifiers: self.get_all_exercise_identifiers(),
+            exercise_suggestions: Vec::new(),
+            suggestion_list_state: ListState::default(),
+        };
+        self.log_pending_planned_set = Some((self.log_viewed_date, planned));
+        Ok(())
+    }
+
     // Helper to populate workout fields based on resolved exercise and last workout
     fn populate_workout_inputs_from_def_and_last_workout(
         &self,
@@ -125,48 +224,53 @@ impl App {
         }
     }
 
-    fn populate_workout_inputs_from_def_and_workout(
+    fn workout_inputs_from_def_and_workout(
         &self,
         def: &ExerciseDefinition,
         workout: &Workout, // The specific workout being edited
-        sets_input: &mut String,
-        reps_input: &mut String,
-        weight_input: &mut String,
-        duration_input: &mut String,
-        distance_input: &mut String,
-        notes_input: &mut String,
-    ) {
-        *sets_input = workout.sets.map_or("1".to_string(), |v| v.to_string());
-        *reps_input = workout.reps.map_or(String::new(), |v| v.to_string());
-        *duration_input = workout
-            .duration_minutes
-            .map_or(String::new(), |v| v.to_string());
-        *notes_input = workout.notes.clone().unwrap_or_default();
+    ) -> EditWorkoutInputs {
+        // Tags get their own fields, only the free text goes back into Notes
+        let (set_meta, notes_text) = SetMeta::parse_notes(workout.notes.as_deref());
 
         // Weight logic (same as before, but applied to the specific workout's weight)
-        if def.type_ == ExerciseType::BodyWeight {
+        let weight_input = if def.type_ == ExerciseType::BodyWeight {
             let bodyweight_used = self.service.config.bodyweight.unwrap_or(0.0);
             let added_weight = workout.weight.map_or(0.0, |w| w - bodyweight_used).max(0.0);
-            *weight_input = if added_weight > 0.0 {
+            if added_weight > 0.0 {
                 format!("{:.1}", added_weight)
             } else {
                 String::new()
-            };
+            }
         } else {
-            *weight_input = workout
+            workout
                 .weight
-                .map_or(String::new(), |v| format!("{:.1}", v));
-        }
+                .map_or(String::new(), |v| format!("{:.1}", v))
+        };
 
         // Distance Logic (same as before)
-        if let Some(dist_km) = workout.distance {
-            let display_dist = match self.service.config.units {
-                Units::Metric => dist_km,
-                Units::Imperial => dist_km * 0.621371,
-            };
-            *distance_input = format!("{:.1}", display_dist);
-        } else {
-            *distance_input = String::new();
+        let distance_input = match workout.distance {
+            Some(dist_km) => {
+                let display_dist = match self.service.config.units {
+                    Units::Metric => dist_km,
+                    Units::Imperial => dist_km * 0.621371,
+                };
+                format!("{:.1}", display_dist)
+            }
+            None => String::new(),
+        };
+
+        EditWorkoutInputs {
+            sets_input: workout.sets.map_or("1".to_string(), |v| v.to_string()),
+            reps_input: workout.reps.map_or(String::new(), |v| v.to_string()),
+            weight_input,
+            duration_input: workout
+                .duration_minutes
+                .map_or(String::new(), |v| v.to_string()),
+            distance_input,
+            set_type: set_meta.set_type,
+            rpe_input: set_meta.rpe.map_or(String::new(), |v| v.to_string()),
+            rir_input: set_meta.rir.map_or(String::new(), |v| v.to_string()),
+            notes_input: notes_text,
         }
     }
 
@@ -181,55 +285,37 @@ impl App {
             None => return Ok(()), // Index out of bounds (shouldn't happen)
         };
 
-        let mut sets_input = "1".to_string();
-        let mut reps_input = String::new();
-        let mut weight_input = String::new();
-        let mut duration_input = String::new();
-        let mut distance_input = String::new();
-        let mut notes_input = String::new();
-        let mut resolved_exercise = None;
-
-        // Get definition and *this specific workout's* data for fields
-        // We pass the workout_id here to potentially load *that* specific workout if needed,
-        // but populate_workout_inputs currently uses the *last* workout for hints.
-        // We will override with the actual data below.
-        match self.get_data_for_workout_modal(
+        // Get definition and fill the fields from *this specific workout's* data
+        let (inputs, def) = match self.get_data_for_workout_modal(
             &workout_to_edit.exercise_name,
             Some(workout_to_edit.id as u64),
         ) {
             Ok((def, _)) => {
                 // We don't need the last_workout here, we have the specific one
-                // Populate directly from the workout being edited
-                self.populate_workout_inputs_from_def_and_workout(
-                    &def,
-                    &workout_to_edit, // Use the specific workout
-                    &mut sets_input,
-                    &mut reps_input,
-                    &mut weight_input,
-                    &mut duration_input,
-                    &mut distance_input,
-                    &mut notes_input,
-                );
-                resolved_exercise = Some(def.clone());
+                let inputs = self.workout_inputs_from_def_and_workout(&def, &workout_to_edit);
+                (inputs, def)
             }
             Err(e) => {
                 self.set_error(format!("Error getting exercise details: {}", e));
                 return Ok(()); // Don't open modal if we can't get details
             }
-        }
+        };
 
         self.active_modal = ActiveModal::EditWorkout {
             workout_id: workout_to_edit.id as u64,
             exercise_name: workout_to_edit.exercise_name.clone(), // Store for display
-            sets_input,
-            reps_input,
-            weight_input,
-            duration_input,
-            distance_input,
-            notes_input,
+            sets_input: inputs.sets_input,
+            reps_input: inputs.reps_input,
+            weight_input: inputs.weight_input,
+            duration_input: inputs.duration_input,
+            distance_input: inputs.distance_input,
+            set_type: inputs.set_type,
+            rpe_input: inputs.rpe_input,
+            rir_input: inputs.rir_input,
+            notes_input: inputs.notes_input,
             focused_field: AddWorkoutField::Sets, // Start focus on Sets (exercise not editable)
             error_message: None,
-            resolved_exercise,
+            resolved_exercise: Some(def),
         };
 
         Ok(())
@@ -261,16 +347,9 @@ impl App {
              ref mut suggestion_list_state,
              .. // ignore other fields
          } = self.active_modal {
-            let input_lower = exercise_input.to_lowercase();
-            if input_lower.is_empty() {
-                *exercise_suggestions = Vec::new(); // Clear suggestions if input is empty
-            } else {
-                *exercise_suggestions = all_exercise_identifiers
-                    .iter()
-                    .filter(|identifier| identifier.to_lowercase().starts_with(&input_lower))
-                    .take(5).cloned() // Limit suggestions to a reasonable number (e.g., 5)
-                    .collect();
-            }
+            // Empty input gives no suggestions
+            *exercise_suggestions =
+                rank_suggestions(exercise_input, all_exercise_identifiers, Utc::now().date_naive());
              // Reset selection when suggestions change
             suggestion_list_state.select(if exercise_suggestions.is_empty() { None } else { Some(0) });
          }
@@ -287,6 +366,7 @@ impl App {
             error_message: None,
             log_duration: true,
             log_distance: true,
+            editing: None,
         };
         Ok(())
     }
@@ -339,4 +419,17 @@ impl App {
         let last_workout = self.get_last_or_specific_workout(&def.name, workout_id_for_context);
         Ok((def, last_workout))
     }
+
+    /// Timestamp for a set logged on the day shown in the Log tab: now for today,
+    /// noon for back-filled days.
+    pub(crate) fn timestamp_for_viewed_date(&self) -> Result<DateTime<Utc>, AppInputError> {
+        if Utc::now().date_naive() == self.log_viewed_date {
+            return Ok(Utc::now());
+        }
+        let naive = self
+            .log_viewed_date
+            .and_hms_opt(12, 0, 0)
+            .ok_or_else(|| AppInputError::InvalidDate("Invalid date".to_string()))?;
+        Ok(Utc.from_utc_datetime(&naive))
+    }
 }
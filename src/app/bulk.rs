@@ -0,0 +1,339 @@
+// src/app/bulk.rs
+//! Marking several sets in the Log tab and acting on them together: delete, copy or move
+//! to another date, or adjust weight/reps.
+use super::journal::Change;
+use super::state::{ActiveModal, App, BulkDateAction, BulkEditField};
+use super::AppInputError;
+use chrono::{NaiveDate, Utc};
+use std::collections::HashSet;
+use task_athlete_lib::{Workout, WorkoutFilters};
+
+/// A bulk edit of one field: `+2.5` / `-5` adjust it, a plain number replaces it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Adjustment {
+    Add(f64),
+    Set(f64),
+}
+
+impl Adjustment {
+    /// Empty input means "leave the field alone".
+    pub fn parse(input: &str) -> Result<Option<Adjustment>, AppInputError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Ok(None);
+        }
+        let invalid = || AppInputError::InvalidNumber(format!("'{}' (use +2.5, -5 or 60)", input));
+        let adjustment = if let Some(rest) = input.strip_prefix('+') {
+            Adjustment::Add(rest.trim().parse().map_err(|_| invalid())?)
+        } else if let Some(rest) = input.strip_prefix('-') {
+            Adjustment::Add(-rest.trim().parse::<f64>().map_err(|_| invalid())?)
+        } else {
+            Adjustment::Set(input.parse().map_err(|_| invalid())?)
+        };
+        Ok(Some(adjustment))
+    }
+
+    /// Applied to a missing value, an adjustment starts from zero. Never goes below zero.
+    pub fn apply(self, value: Option<f64>) -> f64 {
+        match self {
+            Adjustment::Add(delta) => (value.unwrap_or(0.0) + delta).max(0.0),
+            Adjustment::Set(new_value) => new_value.max(0.0),
+        }
+    }
+}
+
+impl App {
+    /// Ids of the marked sets, including the rows covered by visual marking.
+    pub fn log_marked_set_ids(&self) -> HashSet<i64> {
+        let mut marked = self.log_marked_sets.clone();
+        if let (Some(anchor), Some(cursor)) =
+            (self.log_visual_anchor, self.log_set_table_state.selected())
+        {
+            let range = anchor.min(cursor)..=anchor.max(cursor);
+            marked.extend(
+                range.filter_map(|i| self.log_sets_for_selected_exercise.get(i).map(|w| w.id)),
+            );
+        }
+        marked
+    }
+
+    pub fn toggle_log_set_mark(&mut self) {
+        let selected = self
+            .log_set_table_state
+            .selected()
+            .and_then(|i| self.log_sets_for_selected_exercise.get(i));
+        if let Some(id) = selected.map(|w| w.id) {
+            if !self.log_marked_sets.remove(&id) {
+                self.log_marked_sets.insert(id);
+            }
+        }
+    }
+
+    /// `v` starts marking every row the cursor passes over, `v` again keeps those marks.
+    pub fn toggle_log_visual_mode(&mut self) {
+        if self.log_visual_anchor.is_some() {
+            self.commit_log_visual_range();
+        } else {
+            self.log_visual_anchor = self.log_set_table_state.selected();
+        }
+    }
+
+    /// Turns the visual range into ordinary marks, e.g. before the set list changes.
+    pub(crate) fn commit_log_visual_range(&mut self) {
+        self.log_marked_sets = self.log_marked_set_ids();
+        self.log_visual_anchor = None;
+    }
+
+    pub fn clear_log_marks(&mut self) {
+        self.log_marked_sets.clear();
+        self.log_visual_anchor = None;
+    }
+
+    /// The marked sets of the viewed day in logged order, or the selected set if none are.
+    fn marked_or_selected_workouts(&self) -> Vec<Workout> {
+        let marked = self.log_marked_set_ids();
+        if marked.is_empty() {
+            return self
+                .log_set_table_state
+                .selected()
+                .and_then(|i| self.log_sets_for_selected_exercise.get(i))
+                .cloned()
+                .into_iter()
+                .collect();
+        }
+        let filters = WorkoutFilters {
+            date: Some(self.log_viewed_date),
+            ..Default::default()
+        };
+        let mut workouts: Vec<Workout> = self
+            .service
+            .list_workouts(&filters)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|w| marked.contains(&w.id))
+            .collect();
+        workouts.sort_by_key(|w| w.timestamp);
+        workouts
+    }
+
+    pub fn open_bulk_delete_modal(&mut self) {
+        let workouts = self.marked_or_selected_workouts();
+        if !workouts.is_empty() {
+            self.active_modal = ActiveModal::ConfirmDeleteWorkouts { workouts };
+        }
+    }
+
+    pub fn open_bulk_date_modal(&mut self, action: BulkDateAction) {
+        let workouts = self.marked_or_selected_workouts();
+        if workouts.is_empty() {
+            return;
+        }
+        // Copying is mostly "do that session again today"
+        let default_date = if self.log_viewed_date == Utc::now().date_naive() {
+            String::new()
+        } else {
+            "today".to_string()
+        };
+        self.active_modal = ActiveModal::BulkDate {
+            action,
+            workouts,
+            date_input: default_date,
+            error_message: None,
+        };
+    }
+
+    pub fn open_bulk_edit_modal(&mut self) {
+        let workouts = self.marked_or_selected_workouts();
+        if workouts.is_empty() {
+            return;
+        }
+        self.active_modal = ActiveModal::BulkEdit {
+            workouts,
+            weight_input: String::new(),
+            reps_input: String::new(),
+            focused_field: BulkEditField::Weight,
+            error_message: None,
+        };
+    }
+
+    pub(crate) fn bulk_delete_workouts(
+        &mut self,
+        workouts: Vec<Workout>,
+    ) -> Result<(), AppInputError> {
+        let ids: Vec<i64> = workouts.iter().map(|w| w.id).collect();
+        self.service
+            .delete_workouts(&ids)
+            .map_err(|e| AppInputError::DbError(format!("Error deleting sets: {}", e)))?;
+        let count = workouts.len();
+        self.record_change(
+            format!("delete {} sets", count),
+            workouts.into_iter().map(Change::DeleteWorkout).collect(),
+        );
+        self.clear_log_marks();
+        self.set_info(format!("Deleted {} sets", count));
+        Ok(())
+    }
+
+    /// Copies or moves the sets to `date`, keeping their time of day.
+    pub(crate) fn bulk_copy_or_move_workouts(
+        &mut self,
+        action: BulkDateAction,
+        workouts: &[Workout],
+        date: NaiveDate,
+    ) -> Result<(), AppInputError> {
+        let mut changes = Vec::new();
+        for workout in workouts {
+            let mut target = workout.clone();
+            target.timestamp = date.and_time(workout.timestamp.time()).and_utc();
+            let result = match action {
+                BulkDateAction::Copy => self.recreate_workout(&target).map(|new_id| {
+                    target.id = new_id;
+                    Change::AddWorkout(target)
+                }),
+                BulkDateAction::Move => self.edit_params_for(workout, &target).and_then(|params| {
+                    self.service.edit_workout(params)?;
+                    let moved = self.fetch_workout(workout.id, &workout.exercise_name, date);
+                    Ok(Change::EditWorkout {
+                        before: workout.clone(),
+                        after: moved.unwrap_or(target),
+                    })
+                }),
+            };
+            match result {
+                Ok(change) => changes.push(change),
+                Err(e) => {
+                    // Keep what already happened undoable before reporting the failure
+                    self.record_bulk_change(action, changes, date);
+                    return Err(AppInputError::DbError(format!("Error saving set: {}", e)));
+                }
+            }
+        }
+        let count = changes.len();
+        self.record_bulk_change(action, changes, date);
+        self.clear_log_marks();
+        let verb = match action {
+            BulkDateAction::Copy => "Copied",
+            BulkDateAction::Move => "Moved",
+        };
+        self.set_info(format!(
+            "{} {} sets to {}",
+            verb,
+            count,
+            date.format("%Y-%m-%d")
+        ));
+        Ok(())
+    }
+
+    fn record_bulk_change(
+        &mut self,
+        action: BulkDateAction,
+        changes: Vec<Change>,
+        date: NaiveDate,
+    ) {
+        let verb = match action {
+            BulkDateAction::Copy => "copy",
+            BulkDateAction::Move => "move",
+        };
+        self.record_change(
+            format!(
+                "{} {} sets to {}",
+                verb,
+                changes.len(),
+                date.format("%Y-%m-%d")
+            ),
+            changes,
+        );
+    }
+
+    pub(crate) fn bulk_edit_workouts(
+        &mut self,
+        workouts: &[Workout],
+        weight: Option<Adjustment>,
+        reps: Option<Adjustment>,
+    ) -> Result<(), AppInputError> {
+        if weight.is_none() && reps.is_none() {
+            return Err(AppInputError::InputEmpty);
+        }
+        let mut changes = Vec::new();
+        let mut result = Ok(());
+        for workout in workouts {
+            let mut target = workout.clone();
+            if let Some(adjustment) = weight {
+                target.weight = Some(adjustment.apply(workout.weight));
+            }
+            if let Some(adjustment) = reps {
+                target.reps = Some(adjustment.apply(workout.reps.map(|r| r as f64)).round() as i64);
+            }
+            let date = workout.timestamp.date_naive();
+            let saved = self
+                .edit_params_for(workout, &target)
+                .and_then(|params| self.service.edit_workout(params));
+            if let Err(e) = saved {
+                result = Err(AppInputError::DbError(format!("Error editing set: {}", e)));
+                break;
+            }
+            let after = self
+                .fetch_workout(workout.id, &workout.exercise_name, date)
+                .unwrap_or(target);
+            changes.push(Change::EditWorkout {
+                before: workout.clone(),
+                after,
+            });
+        }
+        let count = changes.len();
+        self.record_change(format!("edit {} sets", count), changes);
+        result?;
+        self.clear_log_marks();
+        self.set_info(format!("Updated {} sets", count));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_adjustments_and_replacements() {
+        assert_eq!(
+            Adjustment::parse("+2.5").unwrap(),
+            Some(Adjustment::Add(2.5))
+        );
+        assert_eq!(
+            Adjustment::parse(" - 5 ").unwrap(),
+            Some(Adjustment::Add(-5.0))
+        );
+        assert_eq!(
+            Adjustment::parse("60").unwrap(),
+            Some(Adjustment::Set(60.0))
+        );
+    }
+
+    #[test]
+    fn empty_input_leaves_the_field_alone() {
+        assert_eq!(Adjustment::parse("").unwrap(), None);
+        assert_eq!(Adjustment::parse("   ").unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_input_that_is_not_a_number() {
+        for input in ["abc", "+", "-x", "5kg"] {
+            assert!(
+                matches!(
+                    Adjustment::parse(input),
+                    Err(AppInputError::InvalidNumber(_))
+                ),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn applies_from_zero_and_never_goes_negative() {
+        assert_eq!(Adjustment::Add(2.5).apply(Some(100.0)), 102.5);
+        assert_eq!(Adjustment::Add(5.0).apply(None), 5.0);
+        assert_eq!(Adjustment::Add(-10.0).apply(Some(5.0)), 0.0);
+        assert_eq!(Adjustment::Set(60.0).apply(Some(100.0)), 60.0);
+        assert_eq!(Adjustment::Set(-1.0).apply(None), 0.0);
+    }
+}
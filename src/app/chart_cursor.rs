@@ -0,0 +1,71 @@
+// src/app/chart_cursor.rs
+//! Dates on the Graphs and Bodyweight charts, and the cursor that steps along a chart's
+//! points to read one date and value. Chart x values are days since the chart's anchor,
+//! the date of its first point.
+use super::state::App;
+use chrono::{Duration, NaiveDate};
+
+/// The date at `x` days past the chart's anchor.
+pub fn chart_date(anchor: NaiveDate, x: f64) -> NaiveDate {
+    anchor + Duration::days(x.round() as i64)
+}
+
+/// Keeps a cursor on a point after the points changed, or drops it when there are none.
+pub(crate) fn clamp_cursor(cursor: Option<usize>, len: usize) -> Option<usize> {
+    cursor.filter(|_| len > 0).map(|i| i.min(len - 1))
+}
+
+/// The cursor after a step; the first step starts at the newest point.
+fn step_cursor(cursor: Option<usize>, len: usize, forward: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match (cursor, forward) {
+        (None, _) => len - 1,
+        (Some(i), true) => (i + 1).min(len - 1),
+        (Some(i), false) => i.saturating_sub(1),
+    })
+}
+
+impl App {
+    /// Length of the series the Graphs cursor steps along, the one drawn on top.
+    fn graph_cursor_len(&self) -> usize {
+        self.graph_series
+            .last()
+            .map_or(0, |series| series.points.len())
+    }
+
+    pub fn graphs_toggle_cursor(&mut self) {
+        self.graph_cursor = match self.graph_cursor {
+            Some(_) => None,
+            None => step_cursor(None, self.graph_cursor_len(), true),
+        };
+    }
+
+    pub fn graphs_move_cursor(&mut self, forward: bool) {
+        self.graph_cursor = step_cursor(self.graph_cursor, self.graph_cursor_len(), forward);
+    }
+
+    pub fn bw_toggle_cursor(&mut self) {
+        self.bw_graph_cursor = match self.bw_graph_cursor {
+            Some(_) => None,
+            None => step_cursor(None, self.bw_graph_data.len(), true),
+        };
+    }
+
+    pub fn bw_move_cursor(&mut self, forward: bool) {
+        self.bw_graph_cursor = step_cursor(self.bw_graph_cursor, self.bw_graph_data.len(), forward);
+    }
+
+    /// The Graphs cursor's point and its date.
+    pub fn graph_cursor_point(&self) -> Option<(NaiveDate, (f64, f64))> {
+        let point = *self.graph_series.last()?.points.get(self.graph_cursor?)?;
+        Some((chart_date(self.graph_first_date?, point.0), point))
+    }
+
+    /// The Bodyweight cursor's point and its date.
+    pub fn bw_cursor_point(&self) -> Option<(NaiveDate, (f64, f64))> {
+        let point = *self.bw_graph_data.get(self.bw_graph_cursor?)?;
+        Some((chart_date(self.bw_graph_first_date?, point.0), point))
+    }
+}
@@ -0,0 +1,124 @@
+// src/app/copy_day.rs
+//! Logging a past day's workouts again, e.g. to repeat last week's session.
+use super::journal::Change;
+use super::state::{ActiveModal, ActiveTab, App, CopyDayField, CopyDaySet, LogFocus};
+use super::utils::{parse_option_to_input, parse_optional_float};
+use super::AppInputError;
+use chrono::{NaiveDate, Utc};
+use task_athlete_lib::{Workout, WorkoutFilters};
+
+impl App {
+    /// Opens the review of `source_date`'s sets. They go to the Log tab's viewed date, or
+    /// to today when that's the day being copied. Today itself has nowhere to go.
+    pub fn open_copy_day_modal(&mut self, source_date: NaiveDate) {
+        let today = Utc::now().date_naive();
+        if source_date == today {
+            self.set_info("Go to a past day to copy it to today".to_string());
+            return;
+        }
+        let filters = WorkoutFilters {
+            date: Some(source_date),
+            ..Default::default()
+        };
+        let mut workouts: Vec<Workout> = match self.service.list_workouts(&filters) {
+            Ok(workouts) => workouts,
+            Err(e) => {
+                self.set_error(format!("Error fetching workouts: {}", e));
+                return;
+            }
+        };
+        if workouts.is_empty() {
+            self.set_info(format!(
+                "Nothing logged on {}",
+                source_date.format("%Y-%m-%d")
+            ));
+            return;
+        }
+        workouts.sort_by_key(|w| w.timestamp);
+
+        let target_date = if self.log_viewed_date == source_date {
+            today
+        } else {
+            self.log_viewed_date
+        };
+        self.active_modal = ActiveModal::CopyDay {
+            source_date,
+            sets: workouts
+                .into_iter()
+                .map(|workout| CopyDaySet {
+                    weight_input: parse_option_to_input(workout.weight),
+                    workout,
+                    include: true,
+                })
+                .collect(),
+            date_input: target_date.format("%Y-%m-%d").to_string(),
+            selected: 0,
+            focused_field: CopyDayField::Sets,
+            error_message: None,
+        };
+    }
+
+    /// Logs the included sets on `target_date` at their original time of day, then shows
+    /// that day in the Log tab. The copy is undone as a whole.
+    pub(crate) fn copy_day_workouts(
+        &mut self,
+        source_date: NaiveDate,
+        sets: &[CopyDaySet],
+        target_date: NaiveDate,
+    ) -> Result<(), AppInputError> {
+        if target_date == source_date {
+            return Err(AppInputError::SameDate(
+                source_date.format("%Y-%m-%d").to_string(),
+            ));
+        }
+        // Validate every weight before anything is logged
+        let mut targets = Vec::new();
+        for set in sets.iter().filter(|s| s.include) {
+            let mut target = set.workout.clone();
+            target.weight = parse_optional_float(&set.weight_input)?;
+            target.timestamp = target_date.and_time(set.workout.timestamp.time()).and_utc();
+            targets.push(target);
+        }
+        if targets.is_empty() {
+            return Err(AppInputError::SelectionRequired);
+        }
+
+        let mut changes = Vec::new();
+        let mut result = Ok(());
+        for mut target in targets {
+            match self.recreate_workout(&target) {
+                Ok(new_id) => {
+                    target.id = new_id;
+                    changes.push(Change::AddWorkout(target));
+                }
+                Err(e) => {
+                    result = Err(AppInputError::DbError(format!("Error logging set: {}", e)));
+                    break;
+                }
+            }
+        }
+        let count = changes.len();
+        self.record_change(
+            format!(
+                "copy {} sets from {}",
+                count,
+                source_date.format("%Y-%m-%d")
+            ),
+            changes,
+        );
+        result?;
+
+        self.active_tab = ActiveTab::Log;
+        self.log_viewed_date = target_date;
+        self.log_focus = LogFocus::ExerciseList;
+        self.log_exercise_list_state.select(Some(0));
+        self.log_set_table_state.select(Some(0));
+        self.set_info(format!(
+            "Copied {} sets from {} to {}",
+            count,
+            source_date.format("%Y-%m-%d"),
+            target_date.format("%Y-%m-%d")
+        ));
+        Ok(())
+    }
+}
@@ -1,8 +1,14 @@
-use super::state::App;
+use super::chart_cursor::clamp_cursor;
+use super::navigation_helpers::ensure_selection_is_valid;
+use super::set_meta::{notes_for_display, SetMeta};
+use super::settings::VolumeSettings;
+use super::state::{App, CalendarView, GraphSeries, LogFocus};
+use super::trends::Trends;
+use super::workout_filter::WorkoutQuery;
 use anyhow::Result;
 use chrono::{Datelike, Duration, NaiveDate, Utc};
 // Import Line, Span
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use task_athlete_lib::Units;
 use task_athlete_lib::{DbError, GraphType as LibGraphType, Workout, WorkoutFilters};
 
@@ -17,9 +23,24 @@ impl App {
             super::state::ActiveTab::History => self.refresh_history_data(),
             super::state::ActiveTab::Graphs => {} // TODO
             super::state::ActiveTab::Bodyweight => self.refresh_bodyweight_data(),
+            super::state::ActiveTab::Calendar => self.refresh_calendar_data(),
+            super::state::ActiveTab::Exercises => self.refresh_exercises_data(),
         }
     }
 
+    // Refresh every tab, e.g. after a sync changed data behind our back
+    pub(crate) fn refresh_all_data(&mut self) {
+        self.invalidate_calendar_volumes();
+        self.refresh_log_data();
+        self.refresh_history_data();
+        self.refresh_bodyweight_data();
+        self.refresh_calendar_data();
+        self.refresh_exercises_data();
+        // Exercises may have been added remotely, so reload the graph list unconditionally
+        self.graph_exercises_all.clear();
+        self.refresh_graphs_tab_data();
+    }
+
     // --- Log Tab Data ---
     pub(crate) fn refresh_log_data(&mut self) {
         // Make crate-public if needed by other app modules
@@ -36,6 +57,9 @@ impl App {
                 unique_names.sort_unstable();
                 unique_names.dedup();
                 self.log_exercises_today = unique_names;
+                // Marks only live on the viewed day and go with deleted sets
+                self.log_marked_sets
+                    .retain(|id| workouts.iter().any(|w| w.id == *id));
 
                 if self.log_exercise_list_state.selected().unwrap_or(0)
                     >= self.log_exercises_today.len()
@@ -61,6 +85,13 @@ impl App {
                 }
             }
         }
+
+        // Planned sets are per day, so the pane (and its focus) can vanish on date change
+        let planned_len = self.planned_sets_for_viewed_date().len();
+        ensure_selection_is_valid(&mut self.log_planned_list_state, planned_len);
+        if planned_len == 0 && self.log_focus == LogFocus::Planned {
+            self.log_focus = LogFocus::ExerciseList;
+        }
     }
 
     // Make crate-public
@@ -131,10 +162,15 @@ impl App {
 
     // Make crate-public
     pub(crate) fn update_bw_graph_data(&mut self) {
+        self.bw_projection = self.bw_goal_projection(); // From the latest weigh-ins, whatever the range
+        self.bw_projection_line.clear();
         if self.bw_history.is_empty() {
             self.bw_graph_data.clear();
             self.bw_graph_x_bounds = [0.0, 1.0];
             self.bw_graph_y_bounds = [0.0, 1.0];
+            self.bw_graph_first_date = None;
+            self.bw_graph_cursor = None;
+            self.refresh_bw_trends();
             return;
         }
 
@@ -169,14 +205,13 @@ impl App {
 
         if filtered_data.is_empty() {
             self.bw_graph_data.clear();
+            self.bw_graph_first_date = None;
+            self.bw_graph_cursor = None;
+            self.refresh_bw_trends();
             return;
         }
-        let first_day_epoch = filtered_data
-            .first()
-            .unwrap()
-            .1
-            .date_naive()
-            .num_days_from_ce();
+        let first_date = filtered_data.first().unwrap().1.date_naive();
+        let first_day_epoch = first_date.num_days_from_ce();
         self.bw_graph_data = filtered_data
             .iter()
             .map(|(_, date, weight)| {
@@ -207,8 +242,12 @@ impl App {
         let y_max = self.bw_target.map_or(max_weight, |t| t.max(max_weight));
         let y_padding = ((y_max - y_min) * 0.1).max(1.0);
         self.bw_graph_y_bounds = [(y_min - y_padding).max(0.0), y_max + y_padding];
+        self.bw_graph_first_date = Some(first_date);
+        self.bw_graph_cursor = clamp_cursor(self.bw_graph_cursor, self.bw_graph_data.len());
+        self.update_bw_projection_line();
+        self.refresh_bw_trends();
     }
-    fn refresh_graphs_tab_data(&mut self) {
+    pub(crate) fn refresh_graphs_tab_data(&mut self) {
         // Load all exercise names if not already loaded
         if self.graph_exercises_all.is_empty() {
             match self.service.list_exercises(None, None) {
@@ -225,118 +264,299 @@ impl App {
             }
         }
 
-        // Update graph data *only if* an exercise and type are selected
-        if self.graph_selected_exercise.is_some() && self.graph_selected_type.is_some() {
-            self.update_graph_data();
+        // Pinned series are graphed even while nothing is selected
+        self.update_graph_data();
+    }
+
+    // Daily volume of an exercise within the graph date filters, in display units
+    fn weighted_volume_by_day(&self, exercise_name: &str) -> Result<Vec<(NaiveDate, f64)>> {
+        let filters = WorkoutFilters {
+            exercise_name: Some(exercise_name),
+            ..Default::default()
+        };
+        let factor = match self.service.config.units {
+            Units::Metric => 1.0,
+            Units::Imperial => 2.20462,
+        };
+        let mut by_day: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+        for workout in self.service.list_workouts(&filters)? {
+            let date = workout.timestamp.date_naive();
+            if self
+                .graph_start_date_filter
+                .is_some_and(|start| date < start)
+                || self.graph_end_date_filter.is_some_and(|end| date > end)
+            {
+                continue;
+            }
+            *by_day.entry(date).or_default() +=
+                workout_volume(&workout, &self.settings.volume) * factor;
+        }
+        Ok(by_day.into_iter().collect())
+    }
+
+    // A series' daily values within the graph date filters
+    fn graph_series_data(
+        &self,
+        exercise_name: &str,
+        graph_type: LibGraphType,
+    ) -> Result<Vec<(NaiveDate, f64)>> {
+        // Volume is summed here so set-type weights apply; the rest comes from the library
+        if graph_type == LibGraphType::WorkoutVolume {
+            self.weighted_volume_by_day(exercise_name)
         } else {
-            // Clear graph if no selection
-            self.clear_graph_data();
+            self.service.get_data_for_graph(
+                exercise_name,
+                graph_type,
+                self.graph_start_date_filter,
+                self.graph_end_date_filter,
+            )
         }
     }
 
-    // Updates the graph data based on current selections
+    // Updates the graph data based on the pinned series and the current selection
     pub(crate) fn update_graph_data(&mut self) {
-        let mut should_clear = false;
-        if let (Some(ex_name), Some(graph_type)) = (
-            self.graph_selected_exercise.as_ref(), // Take as ref if ex_name is &str
-            self.graph_selected_type,
-        ) {
-            // Pass the date filters to get_data_for_graph
-            match self.service.get_data_for_graph(
-                ex_name, // Assuming ex_name is &str or can be dereferenced
-                graph_type,
-                self.graph_start_date_filter, // Use existing or add these fields
-                self.graph_end_date_filter,   // to your TUI state struct
-            ) {
-                Ok(raw_data) if !raw_data.is_empty() => {
-                    // raw_data is Vec<(NaiveDate, f64)>
-
-                    // Determine the actual first and last dates from the dataset
-                    // unwrap() is safe here because raw_data is not empty.
-                    let actual_first_date = raw_data.first().unwrap().0;
-                    let actual_last_date = raw_data.last().unwrap().0;
-
-                    // (Optional) Store the first date if you need to map relative f64 days back to NaiveDate
-                    // for display purposes (e.g., axis labels, tooltips).
-                    // self.graph_actual_first_date = Some(actual_first_date);
-
-                    // Transform Vec<(NaiveDate, f64)> to Vec<(f64, f64)> for plotting
-                    // where the f64 x-value is days relative to the actual_first_date.
-                    let plot_points: Vec<(f64, f64)> = raw_data
-                        .iter()
-                        .map(|(date, value)| {
-                            let relative_day = (*date - actual_first_date).num_days() as f64;
-                            (relative_day, *value)
-                        })
-                        .collect();
-
-                    self.graph_data_points = plot_points;
-
-                    // Calculate X bounds based on relative days
-                    let first_day_f64 = 0.0; // The first data point is now at x = 0.0
-                    let mut last_day_f64 = (actual_last_date - actual_first_date).num_days() as f64;
-
-                    // Ensure graph_x_bounds have some width, especially if all data is on the same relative day (0.0)
-                    if last_day_f64 < first_day_f64 + 0.1 {
-                        // If last_day_f64 is effectively 0.0
-                        last_day_f64 = first_day_f64 + 1.0; // Create a window of at least 1.0 unit
-                    }
-                    self.graph_x_bounds = [first_day_f64, last_day_f64];
-
-                    // Calculate Y bounds (this logic remains largely the same)
-                    let min_y = self
-                        .graph_data_points
-                        .iter()
-                        .map(|(_, y)| *y)
-                        .fold(f64::INFINITY, f64::min);
-                    let max_y = self
-                        .graph_data_points
-                        .iter()
-                        .map(|(_, y)| *y)
-                        .fold(f64::NEG_INFINITY, f64::max);
-
-                    // Handle cases like single data point or all y-values being the same for y_padding
-                    let y_range = max_y - min_y;
-                    let y_padding = if y_range.abs() < f64::EPSILON {
-                        // If min_y is very close to max_y
-                        (max_y.abs() * 0.1).max(1.0) // 10% of the value, or at least 1.0
-                    } else {
-                        (y_range * 0.1).max(1.0) // 10% of range, or at least 1.0
-                    };
+        let selection = self
+            .graph_selected_exercise
+            .clone()
+            .zip(self.graph_selected_type);
+        let mut wanted = self.graph_pinned.clone();
+        if let Some(selected) = selection.clone() {
+            if !wanted.contains(&selected) {
+                wanted.push(selected); // Drawn last, on top of the pinned series
+            }
+        }
 
-                    self.graph_y_bounds = [(min_y - y_padding).max(0.0), max_y + y_padding];
+        let mut loaded = Vec::new();
+        for (exercise, graph_type) in wanted {
+            match self.graph_series_data(&exercise, graph_type) {
+                Ok(raw_data) if !raw_data.is_empty() => {
+                    loaded.push((exercise, graph_type, raw_data))
                 }
                 Ok(_) => {
-                    // Empty data returned (raw_data was empty)
-                    should_clear = true;
-                    self.set_error(format!(
-                        "No data found for '{}' - {}",
-                        ex_name,
-                        graph_type_to_string(graph_type) // Ensure this helper is available
-                    ));
-                }
-                Err(e) => {
-                    should_clear = true;
-                    self.set_error(format!("Error loading graph data: {}", e));
+                    // Pinned series without data in the range just drop out of the chart
+                    if selection.as_ref() == Some(&(exercise.clone(), graph_type)) {
+                        self.set_error(format!(
+                            "No data found for '{}' - {}",
+                            exercise,
+                            graph_type_to_string(graph_type)
+                        ));
+                    }
                 }
+                Err(e) => self.set_error(format!("Error loading graph data: {}", e)),
             }
-        } else {
-            should_clear = true;
         }
-
-        if should_clear {
+        if loaded.is_empty() {
             self.clear_graph_data();
-            // Optional: also clear actual_first_date if you added it
-            // self.graph_actual_first_date = None;
+            return;
+        }
+
+        // Every series shares the x axis: days since the earliest point of any of them.
+        // unwrap() is safe here because empty series were left out above.
+        let actual_first_date = loaded.iter().map(|(_, _, data)| data[0].0).min().unwrap();
+        let actual_last_date = loaded
+            .iter()
+            .map(|(_, _, data)| data.last().unwrap().0)
+            .max()
+            .unwrap();
+
+        // Values measured in different things can't share a scale, so each becomes a
+        // percentage of its own best in the range
+        let units = self.service.config.units;
+        let first_title = graph_value_title(loaded[0].1, units);
+        self.graph_normalised = loaded
+            .iter()
+            .any(|(_, graph_type, _)| graph_value_title(*graph_type, units) != first_title);
+
+        self.graph_series = loaded
+            .into_iter()
+            .map(|(exercise, graph_type, raw_data)| {
+                let max = raw_data
+                    .iter()
+                    .map(|(_, value)| *value)
+                    .fold(f64::NEG_INFINITY, f64::max);
+                let scale = if self.graph_normalised && max > 0.0 {
+                    100.0 / max
+                } else {
+                    1.0
+                };
+                let points = raw_data
+                    .iter()
+                    .map(|(date, value)| {
+                        let relative_day = (*date - actual_first_date).num_days() as f64;
+                        (relative_day, *value * scale)
+                    })
+                    .collect();
+                GraphSeries {
+                    exercise,
+                    graph_type,
+                    points,
+                    max,
+                }
+            })
+            .collect();
+
+        // Calculate X bounds based on relative days
+        let first_day_f64 = 0.0; // The first data point is now at x = 0.0
+        let mut last_day_f64 = (actual_last_date - actual_first_date).num_days() as f64;
+
+        // Ensure graph_x_bounds have some width, especially if all data is on the same relative day (0.0)
+        if last_day_f64 < first_day_f64 + 0.1 {
+            last_day_f64 = first_day_f64 + 1.0; // Create a window of at least 1.0 unit
         }
+        self.graph_x_bounds = [first_day_f64, last_day_f64];
+
+        // Calculate Y bounds over every series
+        let all_y = || {
+            self.graph_series
+                .iter()
+                .flat_map(|series| series.points.iter().map(|(_, y)| *y))
+        };
+        let min_y = all_y().fold(f64::INFINITY, f64::min);
+        let max_y = all_y().fold(f64::NEG_INFINITY, f64::max);
+
+        // Handle cases like single data point or all y-values being the same for y_padding
+        let y_range = max_y - min_y;
+        let y_padding = if y_range.abs() < f64::EPSILON {
+            // If min_y is very close to max_y
+            (max_y.abs() * 0.1).max(1.0) // 10% of the value, or at least 1.0
+        } else {
+            (y_range * 0.1).max(1.0) // 10% of range, or at least 1.0
+        };
+
+        self.graph_y_bounds = [(min_y - y_padding).max(0.0), max_y + y_padding];
+        self.graph_first_date = Some(actual_first_date);
+        self.graph_cursor = clamp_cursor(
+            self.graph_cursor,
+            self.graph_series
+                .last()
+                .map_or(0, |series| series.points.len()),
+        );
+        self.refresh_graph_trends();
     }
 
     // Helper to clear graph state
     fn clear_graph_data(&mut self) {
-        self.graph_data_points.clear();
+        self.graph_series.clear();
+        self.graph_normalised = false;
+        self.graph_trends = Trends::default();
+        self.graph_first_date = None;
+        self.graph_cursor = None;
         self.graph_x_bounds = [0.0, 1.0];
         self.graph_y_bounds = [0.0, 1.0];
     }
+    // --- Calendar Tab Data ---
+    fn refresh_calendar_data(&mut self) {
+        match self.calendar_view {
+            CalendarView::CalendarView => self.refresh_calendar_month_data(),
+            CalendarView::ListView => self.refresh_calendar_list_data(),
+        }
+    }
+
+    // Computes the training volume of every trained day in the month being viewed. This runs
+    // every tick, so the volumes are kept until the month changes or the data does
+    fn refresh_calendar_month_data(&mut self) {
+        let viewed = self.calendar_selected_date;
+        let month = (viewed.year(), viewed.month());
+        if self.calendar_volumes_month == Some(month) {
+            return;
+        }
+        let exercised_dates = match self.service.get_all_dates_with_exercise() {
+            Ok(dates) => dates,
+            Err(e) => {
+                self.set_error(format!("Error fetching calendar data: {}", e));
+                self.calendar_day_volumes.clear();
+                return;
+            }
+        };
+
+        let mut volumes = HashMap::new();
+        for date in exercised_dates
+            .into_iter()
+            .filter(|d| d.year() == viewed.year() && d.month() == viewed.month())
+        {
+            let filters = WorkoutFilters {
+                date: Some(date),
+                ..Default::default()
+            };
+            match self.service.list_workouts(&filters) {
+                Ok(workouts) => {
+                    let volume_settings = &self.settings.volume;
+                    volumes.insert(
+                        date,
+                        workouts
+                            .iter()
+                            .map(|w| workout_volume(w, volume_settings))
+                            .sum(),
+                    );
+                }
+                Err(e) => {
+                    // Show what was found and try the month again on the next tick
+                    self.set_error(format!("Error fetching workouts for {}: {}", date, e));
+                    self.calendar_day_volumes = volumes;
+                    return;
+                }
+            }
+        }
+        self.calendar_day_volumes = volumes;
+        self.calendar_volumes_month = Some(month);
+    }
+
+    /// Makes the calendar recompute its day volumes, after workouts were changed.
+    pub(crate) fn invalidate_calendar_volumes(&mut self) {
+        self.calendar_volumes_month = None;
+    }
+
+    /// Parses the typed calendar filter and, if valid, makes it the applied one.
+    pub fn apply_calendar_filter(&mut self) {
+        match WorkoutQuery::parse(&self.calendar_filter_input) {
+            Ok(query) => {
+                self.calendar_filter_query = query;
+                self.calendar_filter_applied = self.calendar_filter_input.trim().to_string();
+                self.calendar_list_state.select(None); // Reselect from the top of the new results
+                self.refresh_calendar_list_data();
+            }
+            Err(e) => self.set_error(e.to_string()),
+        }
+    }
+
+    fn refresh_calendar_list_data(&mut self) {
+        let query = &self.calendar_filter_query;
+        // Narrow the fetch with whatever the service can filter on, the rest is done below
+        let resolved_exercise = query.exercise.as_deref().and_then(|ex| {
+            self.service
+                .resolve_exercise_identifier(ex)
+                .ok()
+                .flatten()
+                .map(|def| def.name)
+        });
+        let filters = WorkoutFilters {
+            exercise_name: resolved_exercise.as_deref(),
+            muscle: query.muscle.as_deref(),
+            date: query.on,
+            ..Default::default()
+        };
+        match self.service.list_workouts(&filters) {
+            Ok(mut workouts) => {
+                let units = self.service.config.units;
+                workouts.retain(|w| query.matches(w, units, resolved_exercise.is_some()));
+                workouts.sort_by_key(|w| std::cmp::Reverse(w.timestamp)); // Most recent first
+                self.calendar_workouts_filtered = workouts;
+
+                let len = self.calendar_workouts_filtered.len();
+                if len == 0 {
+                    self.calendar_list_state.select(None);
+                } else if !matches!(self.calendar_list_state.selected(), Some(i) if i < len) {
+                    self.calendar_list_state.select(Some(0));
+                }
+            }
+            Err(e) => {
+                self.set_error(format!("Error fetching workouts: {}", e));
+                self.calendar_workouts_filtered.clear();
+                self.calendar_list_state.select(None);
+            }
+        }
+    }
+
     // --- History Tab Data ---
     fn refresh_history_data(&mut self) {
         // Fetch *all* workouts (might be inefficient for very large histories)
@@ -420,6 +640,17 @@ pub fn log_change_date(app: &mut App, days: i64) {
     }
 }
 
+// Switches to the Log tab showing the given date (used by History and Calendar)
+pub fn log_jump_to_date(app: &mut App, date: NaiveDate) {
+    app.active_tab = super::state::ActiveTab::Log;
+    app.log_viewed_date = date;
+    // Reset log focus/selection for the new date
+    app.log_focus = LogFocus::ExerciseList;
+    app.log_exercise_list_state.select(Some(0));
+    app.log_set_table_state.select(Some(0));
+    // Log data will refresh automatically in the main loop
+}
+
 pub fn log_set_previous_exercised_date(app: &mut App) -> Result<()> {
     let exercised_dates = app.service.get_all_dates_with_exercise()?;
     let current_date = app.log_viewed_date;
@@ -456,6 +687,22 @@ pub fn graph_type_to_string(graph_type: LibGraphType) -> String {
     }
 }
 
+/// What a graph type measures and in which unit, e.g. "Weight (kg)". Series with the
+/// same title can share a y axis.
+pub fn graph_value_title(graph_type: LibGraphType, units: Units) -> String {
+    let weight_unit = if units == Units::Metric { "kg" } else { "lbs" };
+    match graph_type {
+        LibGraphType::Estimated1RM | LibGraphType::MaxWeight => format!("Weight ({})", weight_unit),
+        LibGraphType::MaxReps | LibGraphType::WorkoutReps => "Reps".to_string(),
+        LibGraphType::WorkoutVolume => format!("Volume ({})", weight_unit), // Volume units depends on weight unit
+        LibGraphType::WorkoutDuration => "Duration (min)".to_string(),
+        LibGraphType::WorkoutDistance => format!(
+            "Distance ({})",
+            if units == Units::Metric { "km" } else { "mi" }
+        ),
+    }
+}
+
 pub fn log_set_next_exercised_date(app: &mut App) -> Result<()> {
     let exercised_dates = app.service.get_all_dates_with_exercise()?;
     let current_date = app.log_viewed_date;
@@ -515,11 +762,26 @@ pub fn format_set_line(workout: &Workout, units: Units) -> String {
         };
         parts.push(format!("{:.1} {}", display_dist, unit_str));
     }
-    if let Some(notes) = &workout.notes {
-        if !notes.trim().is_empty() {
-            parts.push(format!("({})", notes.trim()));
-        }
+    let notes = notes_for_display(workout.notes.as_deref());
+    if !notes.is_empty() {
+        parts.push(format!("({})", notes));
     }
 
     parts.join(" x ") // Join parts with " x " or choose another separator
 }
+
+/// Training volume of a single entry: sets x reps x effective weight (kg), scaled by
+/// the configured weight of its set type (warm-ups count for nothing by default).
+/// Entries without reps or weight (e.g. cardio) contribute nothing.
+pub fn workout_volume(workout: &Workout, volume_settings: &VolumeSettings) -> f64 {
+    match (workout.reps, workout.calculate_effective_weight()) {
+        (Some(reps), Some(weight)) => {
+            let (set_meta, _) = SetMeta::parse_notes(workout.notes.as_deref());
+            workout.sets.unwrap_or(1) as f64
+                * reps as f64
+                * weight
+                * volume_settings.factor_for(set_meta.set_type)
+        }
+        _ => 0.0,
+    }
+}
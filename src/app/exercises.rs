@@ -0,0 +1,262 @@
+// src/app/exercises.rs
+//! Exercises tab: browse, search, edit and delete exercise definitions.
+use super::fuzzy::fuzzy_match;
+use super::state::{ActiveModal, AddExerciseField, AliasManagerField, App};
+use chrono::NaiveDate;
+use ratatui::widgets::ListState;
+use std::collections::HashMap;
+use task_athlete_lib::{ExerciseDefinition, ExerciseType, WorkoutFilters};
+
+const ALIAS_MATCH_PENALTY: i64 = 2; // Prefer a hit on the name over one on an alias
+const MUSCLE_MATCH_PENALTY: i64 = 10;
+
+/// How much an exercise has been logged, shown next to the list.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExerciseUsage {
+    pub workout_count: usize,
+    pub last_logged: Option<NaiveDate>,
+}
+
+pub fn exercise_type_label(type_: ExerciseType) -> &'static str {
+    match type_ {
+        ExerciseType::Resistance => "Resistance",
+        ExerciseType::Cardio => "Cardio",
+        ExerciseType::BodyWeight => "BodyWeight",
+    }
+}
+
+/// `weight, reps` - the fields an exercise logs.
+pub fn log_flags_label(def: &ExerciseDefinition) -> String {
+    let flags = [
+        (def.log_weight, "weight"),
+        (def.log_reps, "reps"),
+        (def.log_duration, "duration"),
+        (def.log_distance, "distance"),
+    ];
+    let logged: Vec<&str> = flags
+        .iter()
+        .filter(|(on, _)| *on)
+        .map(|(_, label)| *label)
+        .collect();
+    if logged.is_empty() {
+        "-".to_string()
+    } else {
+        logged.join(", ")
+    }
+}
+
+/// Best score of the query against the name, the aliases and then the muscles.
+fn search_score(query: &str, def: &ExerciseDefinition, aliases: &[String]) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let name = fuzzy_match(query, &def.name).map(|m| m.score);
+    let alias = aliases
+        .iter()
+        .filter_map(|a| fuzzy_match(query, a))
+        .map(|m| m.score - ALIAS_MATCH_PENALTY)
+        .max();
+    let muscles = def
+        .muscles
+        .as_deref()
+        .and_then(|m| fuzzy_match(query, m))
+        .map(|m| m.score - MUSCLE_MATCH_PENALTY);
+    name.into_iter().chain(alias).chain(muscles).max()
+}
+
+impl App {
+    pub(crate) fn refresh_exercises_data(&mut self) {
+        match self.service.list_exercises(None, None) {
+            Ok(exercises) => self.exercises_all = exercises,
+            Err(e) => {
+                self.set_error(format!("Error loading exercises: {}", e));
+                return;
+            }
+        }
+
+        // Aliases arrive as alias -> canonical name, the tab wants them per exercise
+        let mut aliases: HashMap<String, Vec<String>> = HashMap::new();
+        for (alias, canonical) in self.service.list_aliases().unwrap_or_default() {
+            aliases.entry(canonical).or_default().push(alias);
+        }
+        for list in aliases.values_mut() {
+            list.sort_unstable_by_key(|a| a.to_lowercase());
+        }
+        self.exercises_aliases = aliases;
+
+        self.apply_exercise_search();
+        self.exercises_selected_usage = self
+            .selected_exercise_definition()
+            .map(|def| def.name.clone())
+            .map(|name| self.exercise_usage(&name));
+    }
+
+    /// Filters and ranks `exercises_all` by the search box, best match first.
+    fn apply_exercise_search(&mut self) {
+        let query = self.exercises_search_input.trim();
+        let no_aliases = Vec::new();
+        let mut ranked: Vec<(i64, &ExerciseDefinition)> = self
+            .exercises_all
+            .iter()
+            .filter_map(|def| {
+                let aliases = self.exercises_aliases.get(&def.name).unwrap_or(&no_aliases);
+                search_score(query, def, aliases).map(|score| (score, def))
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then_with(|| a.1.name.to_lowercase().cmp(&b.1.name.to_lowercase()))
+        });
+        self.exercises_filtered = ranked.into_iter().map(|(_, def)| def.clone()).collect();
+
+        let len = self.exercises_filtered.len();
+        match self.exercises_table_state.selected() {
+            _ if len == 0 => self.exercises_table_state.select(None),
+            Some(i) if i >= len => self.exercises_table_state.select(Some(len - 1)),
+            None => self.exercises_table_state.select(Some(0)),
+            _ => {}
+        }
+    }
+
+    pub fn selected_exercise_definition(&self) -> Option<&ExerciseDefinition> {
+        self.exercises_table_state
+            .selected()
+            .and_then(|i| self.exercises_filtered.get(i))
+    }
+
+    fn exercise_usage(&self, exercise_name: &str) -> ExerciseUsage {
+        let filters = WorkoutFilters {
+            exercise_name: Some(exercise_name),
+            ..Default::default()
+        };
+        let workouts = self.service.list_workouts(&filters).unwrap_or_default();
+        ExerciseUsage {
+            workout_count: workouts.len(),
+            last_logged: workouts.iter().map(|w| w.timestamp.date_naive()).max(),
+        }
+    }
+
+    /// Opens the Create Exercise modal pre-filled with the selected definition.
+    pub fn open_edit_exercise_modal(&mut self) {
+        let Some(def) = self.selected_exercise_definition().cloned() else {
+            return;
+        };
+        self.active_modal = ActiveModal::CreateExercise {
+            name_input: def.name.clone(),
+            muscles_input: def.muscles.clone().unwrap_or_default(),
+            selected_type: def.type_,
+            focused_field: AddExerciseField::Name,
+            error_message: None,
+            log_weight: def.log_weight,
+            log_reps: def.log_reps,
+            log_duration: def.log_duration,
+            log_distance: def.log_distance,
+            editing: Some(def.name),
+        };
+    }
+
+    pub fn open_alias_manager_modal(&mut self) {
+        let Some(name) = self.selected_exercise_definition().map(|d| d.name.clone()) else {
+            return;
+        };
+        self.active_modal = ActiveModal::AliasManager {
+            exercise_name: name,
+            alias_input: String::new(),
+            list_state: ListState::default(),
+            focused_field: AliasManagerField::Input,
+            error_message: None,
+        };
+    }
+
+    pub fn open_delete_exercise_confirmation_modal(&mut self) {
+        let Some(name) = self.selected_exercise_definition().map(|d| d.name.clone()) else {
+            return;
+        };
+        let workout_count = self.exercise_usage(&name).workout_count;
+        self.active_modal = ActiveModal::ConfirmDeleteExercise {
+            exercise_name: name,
+            workout_count,
+        };
+    }
+
+    pub fn delete_exercise(&mut self, exercise_name: &str) {
+        match self
+            .service
+            .delete_exercise(&vec![exercise_name.to_string()])
+        {
+            Ok(_) => {
+                if self.graph_selected_exercise.as_deref() == Some(exercise_name) {
+                    self.graph_selected_exercise = None;
+                }
+                self.reload_exercise_lists();
+                self.set_info(format!("Deleted exercise '{}'", exercise_name));
+            }
+            Err(e) => self.set_error(format!("Error deleting exercise: {}", e)),
+        }
+    }
+
+    /// The library moves logged workouts and aliases over on rename. This does the same for
+    /// what the TUI keys by exercise name: per-exercise settings, routines, planned sets and
+    /// the undo history.
+    pub(crate) fn rename_exercise_references(&mut self, old_name: &str, new_name: &str) {
+        let settings = &mut self.settings;
+        let mut settings_changed = false;
+        if let Some(seconds) = settings.rest_timer.per_exercise.remove(old_name) {
+            settings
+                .rest_timer
+                .per_exercise
+                .insert(new_name.to_string(), seconds);
+            settings_changed = true;
+        }
+        if let Some(scheme) = settings.progression.per_exercise.remove(old_name) {
+            settings
+                .progression
+                .per_exercise
+                .insert(new_name.to_string(), scheme);
+            settings_changed = true;
+        }
+        if settings_changed {
+            if let Err(e) = self.settings.save() {
+                self.set_error(format!("Failed to save settings: {}", e));
+            }
+        }
+
+        let mut routines_changed = false;
+        for entry in self
+            .routines
+            .routines
+            .iter_mut()
+            .flat_map(|r| r.entries.iter_mut())
+            .filter(|e| e.exercise == old_name)
+        {
+            entry.exercise = new_name.to_string();
+            routines_changed = true;
+        }
+        if routines_changed {
+            if let Err(e) = self.routines.save() {
+                self.set_error(format!("Failed to save routines: {}", e));
+            }
+        }
+
+        for planned in self
+            .log_planned_sets
+            .values_mut()
+            .flatten()
+            .filter(|p| p.exercise_name == old_name)
+        {
+            planned.exercise_name = new_name.to_string();
+        }
+        self.journal.rename_exercise(old_name, new_name);
+        if self.graph_selected_exercise.as_deref() == Some(old_name) {
+            self.graph_selected_exercise = Some(new_name.to_string());
+        }
+    }
+
+    /// Reloads the exercise lists other tabs cache, after a definition was added or changed.
+    pub(crate) fn reload_exercise_lists(&mut self) {
+        self.invalidate_calendar_volumes(); // Deleting an exercise deletes its sets
+        self.graph_exercises_all.clear();
+        self.refresh_graphs_tab_data();
+        self.refresh_exercises_data();
+    }
+}
@@ -0,0 +1,107 @@
+// src/app/fuzzy.rs
+//! Fuzzy subsequence matching for search boxes: "bp" finds "Bench Press".
+
+const MATCH_SCORE: i64 = 1;
+const WORD_START_BONUS: i64 = 8; // Matched the first letter of a word
+const CONSECUTIVE_BONUS: i64 = 5; // Matched right after the previous match
+const PREFIX_BONUS: i64 = 4; // Matched the very first character
+const MAX_GAP_PENALTY: i64 = 3; // Per gap between matches
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    pub positions: Vec<usize>, // Char indices in the candidate, for highlighting
+}
+
+/// Matches every query character, in order and ignoring case, somewhere in `candidate`.
+/// Whitespace in the query is ignored. Returns `None` if some character can't be found.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let chars: Vec<char> = candidate.chars().collect();
+
+    let mut positions = Vec::with_capacity(query.len());
+    let mut score = 0;
+    let mut query_index = 0;
+    for (i, c) in chars.iter().enumerate() {
+        let Some(&wanted) = query.get(query_index) else {
+            break;
+        };
+        if !c.to_lowercase().eq(std::iter::once(wanted)) {
+            continue;
+        }
+
+        score += MATCH_SCORE;
+        if i == 0 {
+            score += PREFIX_BONUS;
+        }
+        if is_word_start(&chars, i) {
+            score += WORD_START_BONUS;
+        }
+        match positions.last() {
+            Some(&prev) if prev + 1 == i => score += CONSECUTIVE_BONUS,
+            Some(&prev) => score -= ((i - prev - 1) as i64).min(MAX_GAP_PENALTY),
+            None => {}
+        }
+        positions.push(i);
+        query_index += 1;
+    }
+
+    if query_index < query.len() {
+        return None;
+    }
+    // Prefer shorter candidates when everything else is equal
+    score -= (chars.len().saturating_sub(query.len()) / 4) as i64;
+    Some(FuzzyMatch { score, positions })
+}
+
+fn is_word_start(chars: &[char], i: usize) -> bool {
+    match i.checked_sub(1).map(|p| chars[p]) {
+        None => true,
+        Some(prev) => !prev.is_alphanumeric() || (prev.is_lowercase() && chars[i].is_uppercase()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_word_starts_ignoring_case_and_spaces() {
+        let m = fuzzy_match("b P", "Bench Press").unwrap();
+        assert_eq!(m.positions, vec![0, 6]);
+    }
+
+    #[test]
+    fn query_with_no_match_is_none() {
+        assert_eq!(fuzzy_match("xyz", "Bench Press"), None);
+        assert_eq!(fuzzy_match("sb", "Bench Squat"), None); // Out of order
+        assert_eq!(fuzzy_match("squat", ""), None);
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert!(fuzzy_match("", "Squat").is_some());
+        assert!(fuzzy_match("  ", "Squat").is_some());
+    }
+
+    #[test]
+    fn word_starts_and_runs_beat_scattered_letters() {
+        let initials = fuzzy_match("bp", "Bench Press").unwrap();
+        let scattered = fuzzy_match("bp", "Barbell Hip Thrust").unwrap();
+        assert!(initials.score > scattered.score);
+
+        let prefix = fuzzy_match("squ", "Squat").unwrap();
+        let inside = fuzzy_match("squ", "Goblet Squat").unwrap();
+        assert!(prefix.score > inside.score);
+    }
+
+    #[test]
+    fn camel_case_humps_are_word_starts() {
+        let m = fuzzy_match("dl", "DeadLift").unwrap();
+        assert_eq!(m.positions, vec![0, 4]);
+    }
+}
@@ -0,0 +1,170 @@
+// src/app/goal.rs
+//! Whether the target bodyweight is getting closer: the trend of the last few weeks of
+//! weigh-ins, and the date it reaches the target at that rate.
+use super::state::App;
+use super::trends::linear_regression;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+const RECENT_DAYS: i64 = 28; // Weigh-ins the rate is taken from, counted back from the latest
+const REACHED_MARGIN: f64 = 0.1; // kg either side of the target that counts as there
+const FLAT_RATE_PER_WEEK: f64 = 0.05; // kg, slower than this is no change
+const MIN_PROJECTION_DAYS: f64 = 28.0; // How far past the latest weigh-in the chart may always reach
+const DASH_STEPS: usize = 48; // Points along the projection line, drawn two on, two off
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GoalProjection {
+    Reached,
+    OnTrack { date: NaiveDate, rate_per_week: f64 },
+    MovingAway { rate_per_week: f64 },
+    NoChange,
+}
+
+/// The projection towards `target` from weigh-ins (id, time, kg), newest first. None
+/// with fewer than two recent weigh-ins on different days. Rates are in kg per week.
+fn project_goal(history: &[(i64, DateTime<Utc>, f64)], target: f64) -> Option<GoalProjection> {
+    let (_, latest_ts, latest) = *history.first()?;
+    let latest_date = latest_ts.date_naive();
+    if (latest - target).abs() <= REACHED_MARGIN {
+        return Some(GoalProjection::Reached);
+    }
+
+    let recent: Vec<(f64, f64)> = history
+        .iter()
+        .map(|(_, ts, weight)| ((ts.date_naive() - latest_date).num_days(), *weight))
+        .take_while(|(days, _)| *days > -RECENT_DAYS)
+        .map(|(days, weight)| (days as f64, weight))
+        .collect();
+    let rate_per_day = linear_regression(&recent)?.slope_per_day;
+    let rate_per_week = rate_per_day * 7.0;
+    let remaining = target - latest;
+    Some(if rate_per_week.abs() < FLAT_RATE_PER_WEEK {
+        GoalProjection::NoChange
+    } else if remaining.signum() != rate_per_day.signum() {
+        GoalProjection::MovingAway { rate_per_week }
+    } else {
+        let days = (remaining / rate_per_day).ceil() as i64;
+        GoalProjection::OnTrack {
+            date: latest_date + Duration::days(days),
+            rate_per_week,
+        }
+    })
+}
+
+impl App {
+    /// The projection for the current target, None without a target.
+    pub(crate) fn bw_goal_projection(&self) -> Option<GoalProjection> {
+        project_goal(&self.bw_history, self.bw_target?)
+    }
+
+    /// Dashes from the latest weigh-in towards the target at the projected rate. They
+    /// reach the target, or stop when the chart would be stretched to twice its span (or
+    /// four weeks further), and the chart's x bounds grow to fit them.
+    pub(crate) fn update_bw_projection_line(&mut self) {
+        self.bw_projection_line.clear();
+        let (
+            Some(GoalProjection::OnTrack { date, .. }),
+            Some(target),
+            Some(&(last_x, latest)),
+            Some(anchor),
+        ) = (
+            self.bw_projection,
+            self.bw_target,
+            self.bw_graph_data.last(), // The latest weigh-in, every range ends with it
+            self.bw_graph_first_date,
+        )
+        else {
+            return;
+        };
+        let goal_x = (date - anchor).num_days() as f64;
+        let span = (last_x - self.bw_graph_x_bounds[0]).max(MIN_PROJECTION_DAYS);
+        let end_x = goal_x.min(last_x + span);
+        let slope = (target - latest) / (goal_x - last_x);
+
+        self.bw_projection_line = (0..=DASH_STEPS)
+            .filter(|i| i % 4 < 2)
+            .map(|i| {
+                let x = last_x + (end_x - last_x) * i as f64 / DASH_STEPS as f64;
+                (x, latest + slope * (x - last_x))
+            })
+            .collect();
+        self.bw_graph_x_bounds[1] = self.bw_graph_x_bounds[1].max(end_x);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// Weigh-ins as (days after June 1st, kg), oldest first like they're logged.
+    fn history(weigh_ins: &[(i64, f64)]) -> Vec<(i64, DateTime<Utc>, f64)> {
+        let start = Utc.with_ymd_and_hms(2024, 6, 1, 7, 0, 0).unwrap();
+        weigh_ins
+            .iter()
+            .rev() // Newest first, as the Bodyweight tab keeps them
+            .map(|&(day, weight)| (day, start + Duration::days(day), weight))
+            .collect()
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, day).unwrap()
+    }
+
+    #[test]
+    fn no_weigh_ins_give_no_projection() {
+        assert_eq!(project_goal(&[], 75.0), None);
+        assert_eq!(project_goal(&history(&[(0, 80.0)]), 75.0), None);
+    }
+
+    #[test]
+    fn close_to_the_target_is_reached() {
+        let weigh_ins = history(&[(0, 80.0), (7, 75.05)]);
+        assert_eq!(
+            project_goal(&weigh_ins, 75.0),
+            Some(GoalProjection::Reached)
+        );
+    }
+
+    #[test]
+    fn projects_the_date_at_the_recent_rate() {
+        // Losing 1 kg a week, 2 kg to go
+        let weigh_ins = history(&[(0, 80.0), (7, 79.0), (14, 78.0)]);
+        let Some(GoalProjection::OnTrack {
+            date: reached,
+            rate_per_week,
+        }) = project_goal(&weigh_ins, 76.0)
+        else {
+            panic!("expected a projection");
+        };
+        assert_eq!(reached, date(29));
+        assert!((rate_per_week + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn gaining_while_the_target_is_lower_moves_away() {
+        let weigh_ins = history(&[(0, 80.0), (14, 81.0)]);
+        assert!(matches!(
+            project_goal(&weigh_ins, 75.0),
+            Some(GoalProjection::MovingAway { rate_per_week }) if rate_per_week > 0.0
+        ));
+    }
+
+    #[test]
+    fn a_flat_trend_is_no_change() {
+        let weigh_ins = history(&[(0, 80.0), (14, 80.02)]);
+        assert_eq!(
+            project_goal(&weigh_ins, 75.0),
+            Some(GoalProjection::NoChange)
+        );
+    }
+
+    #[test]
+    fn only_the_last_four_weeks_count() {
+        // A big drop long ago doesn't hide that the weight has since gone up
+        let weigh_ins = history(&[(0, 90.0), (30, 80.0), (44, 81.0)]);
+        assert!(matches!(
+            project_goal(&weigh_ins, 75.0),
+            Some(GoalProjection::MovingAway { .. })
+        ));
+    }
+}
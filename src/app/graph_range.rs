@@ -0,0 +1,123 @@
+// src/app/graph_range.rs
+//! The dates the Graphs tab chart covers: a preset counted back from today, cycled with
+//! `r`, or custom dates typed into the range modal. Either way the range ends up in
+//! `graph_start_date_filter`/`graph_end_date_filter`, which the graph queries use.
+use super::state::{ActiveModal, App, GraphRangeField};
+use super::utils::parse_modal_date;
+use super::AppInputError;
+use chrono::{Datelike, Duration, Months, NaiveDate, Utc};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphRange {
+    FourWeeks,
+    ThreeMonths,
+    SixMonths,
+    YearToDate,
+    All,
+    Custom, // Dates from the range modal
+}
+
+impl GraphRange {
+    /// The preset after this one. A custom range starts over at the shortest preset.
+    pub fn next(self) -> Self {
+        match self {
+            GraphRange::FourWeeks => GraphRange::ThreeMonths,
+            GraphRange::ThreeMonths => GraphRange::SixMonths,
+            GraphRange::SixMonths => GraphRange::YearToDate,
+            GraphRange::YearToDate => GraphRange::All,
+            GraphRange::All | GraphRange::Custom => GraphRange::FourWeeks,
+        }
+    }
+
+    /// First day of a preset ending today, None when it has no lower bound.
+    fn start_date(self, today: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            GraphRange::FourWeeks => Some(today - Duration::weeks(4)),
+            GraphRange::ThreeMonths => today.checked_sub_months(Months::new(3)),
+            GraphRange::SixMonths => today.checked_sub_months(Months::new(6)),
+            GraphRange::YearToDate => NaiveDate::from_ymd_opt(today.year(), 1, 1),
+            GraphRange::All | GraphRange::Custom => None,
+        }
+    }
+}
+
+/// An empty field leaves that end of the range open.
+fn parse_range_date(input: &str) -> Result<Option<NaiveDate>, AppInputError> {
+    if input.trim().is_empty() {
+        Ok(None)
+    } else {
+        parse_modal_date(input).map(Some)
+    }
+}
+
+impl App {
+    /// Moves the Graphs tab to the next preset range and redraws the chart.
+    pub fn graphs_cycle_range(&mut self) {
+        let range = self.graph_range.next();
+        self.graph_range = range;
+        self.graph_start_date_filter = range.start_date(Utc::now().date_naive());
+        self.graph_end_date_filter = None;
+        self.update_graph_data();
+    }
+
+    /// Opens the custom range modal with the dates of the current range filled in.
+    pub fn open_graph_range_modal(&mut self) {
+        let format = |date: Option<NaiveDate>| {
+            date.map_or(String::new(), |d| d.format("%Y-%m-%d").to_string())
+        };
+        self.active_modal = ActiveModal::GraphRange {
+            start_input: format(self.graph_start_date_filter),
+            end_input: format(self.graph_end_date_filter),
+            focused_field: GraphRangeField::Start,
+            error_message: None,
+        };
+    }
+
+    /// Graphs only the days between the typed dates, both included.
+    pub(crate) fn set_custom_graph_range(
+        &mut self,
+        start_input: &str,
+        end_input: &str,
+    ) -> Result<(), AppInputError> {
+        let start = parse_range_date(start_input)?;
+        let end = parse_range_date(end_input)?;
+        if let (Some(start), Some(end)) = (start, end) {
+            if start > end {
+                return Err(AppInputError::InvalidDateRange(format!(
+                    "{} is after {}",
+                    start.format("%Y-%m-%d"),
+                    end.format("%Y-%m-%d")
+                )));
+            }
+        }
+        self.graph_range = if start.is_none() && end.is_none() {
+            GraphRange::All
+        } else {
+            GraphRange::Custom
+        };
+        self.graph_start_date_filter = start;
+        self.graph_end_date_filter = end;
+        self.update_graph_data();
+        Ok(())
+    }
+
+    /// The range as shown in the chart title, e.g. "3M" or "2024-01-01 to 2024-03-31".
+    pub fn graph_range_label(&self) -> String {
+        let format = |date: NaiveDate| date.format("%Y-%m-%d");
+        match self.graph_range {
+            GraphRange::FourWeeks => "4W".to_string(),
+            GraphRange::ThreeMonths => "3M".to_string(),
+            GraphRange::SixMonths => "6M".to_string(),
+            GraphRange::YearToDate => "YTD".to_string(),
+            GraphRange::All => "All".to_string(),
+            GraphRange::Custom => {
+                match (self.graph_start_date_filter, self.graph_end_date_filter) {
+                    (Some(start), Some(end)) => format!("{} to {}", format(start), format(end)),
+                    (Some(start), None) => format!("since {}", format(start)),
+                    (None, Some(end)) => format!("until {}", format(end)),
+                    (None, None) => "All".to_string(),
+                }
+            }
+        }
+    }
+}
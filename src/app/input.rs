@@ -1,19 +1,33 @@
 // src/app/input.rs
+use super::keymap::{Action, KeyContext};
+use super::navigation_helpers::{list_next, list_previous};
+use super::trends::Overlay;
 use super::{
-    data::{log_change_date, log_set_next_exercised_date, log_set_previous_exercised_date},
+    data::{
+        graph_type_to_string, log_change_date, log_jump_to_date, log_set_next_exercised_date,
+        log_set_previous_exercised_date,
+    },
     modals::{
-        handle_add_workout_modal_input, handle_confirm_delete_body_weigth_input,
-        handle_confirm_delete_modal_input, handle_create_exercise_modal_input,
-        handle_edit_workout_modal_input, handle_log_bodyweight_modal_input, handle_pb_modal_input,
+        handle_add_workout_modal_input, handle_alias_manager_modal_input,
+        handle_bulk_date_modal_input, handle_bulk_edit_modal_input,
+        handle_confirm_delete_body_weigth_input, handle_confirm_delete_exercise_input,
+        handle_confirm_delete_modal_input, handle_confirm_delete_workouts_input,
+        handle_copy_day_modal_input, handle_create_exercise_modal_input,
+        handle_create_routine_modal_input, handle_edit_workout_modal_input,
+        handle_graph_range_modal_input, handle_log_bodyweight_modal_input, handle_pb_modal_input,
+        handle_routines_modal_input, handle_session_summary_modal_input,
         handle_set_target_weight_modal_input,
     },
     navigation::{
-        bw_table_next, bw_table_previous, history_list_next, history_list_previous, log_list_next,
+        bw_table_next, bw_table_previous, calendar_change_day, calendar_change_month,
+        calendar_table_next, calendar_table_previous, exercises_table_next,
+        exercises_table_previous, history_list_next, history_list_previous, log_list_next,
         log_list_previous, log_table_next, log_table_previous,
     },
     state::{
-        ActiveModal, ActiveTab, App, BodyweightFocus, GraphsFocus, HistoryFocus,
-        LogBodyweightField, LogFocus, SetTargetWeightField,
+        ActiveModal, ActiveTab, App, BodyweightFocus, BulkDateAction, CalendarFocus, CalendarView,
+        ExercisesFocus, GraphsFocus, HistoryFocus, LogBodyweightField, LogFocus,
+        SetTargetWeightField,
     },
 };
 use anyhow::Result;
@@ -27,14 +41,30 @@ impl App {
             return self.handle_modal_input(key); // Call modal handler method
         }
 
+        // A focused text input takes every key, so typing 'q' or 'S' doesn't quit or sync
+        if self.is_tab_text_input_focused() {
+            return match self.active_tab {
+                ActiveTab::Exercises => self.handle_exercises_input(key),
+                _ => self.handle_calendar_input(key),
+            };
+        }
+
         // Global keys
-        match key.code {
-            KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Char('?') => self.active_modal = ActiveModal::Help,
-            KeyCode::F(1) => self.active_tab = ActiveTab::Log,
-            KeyCode::F(2) => self.active_tab = ActiveTab::History,
-            KeyCode::F(3) => self.active_tab = ActiveTab::Graphs,
-            KeyCode::F(4) => self.active_tab = ActiveTab::Bodyweight,
+        match self.keymap.action(KeyContext::Global, key) {
+            Some(Action::Quit) => self.should_quit = true,
+            Some(Action::Help) => {
+                self.help_scroll = 0;
+                self.active_modal = ActiveModal::Help;
+            }
+            Some(Action::Sync) => self.start_sync(),
+            Some(Action::Redo) => self.redo_last_change(),
+            Some(Action::Undo) => self.undo_last_change(),
+            Some(Action::ShowLogTab) => self.active_tab = ActiveTab::Log,
+            Some(Action::ShowHistoryTab) => self.active_tab = ActiveTab::History,
+            Some(Action::ShowGraphsTab) => self.active_tab = ActiveTab::Graphs,
+            Some(Action::ShowBodyweightTab) => self.active_tab = ActiveTab::Bodyweight,
+            Some(Action::ShowCalendarTab) => self.active_tab = ActiveTab::Calendar,
+            Some(Action::ShowExercisesTab) => self.active_tab = ActiveTab::Exercises,
             _ => {
                 // Delegate to tab-specific handler
                 match self.active_tab {
@@ -42,6 +72,8 @@ impl App {
                     ActiveTab::History => self.handle_history_input(key)?,
                     ActiveTab::Graphs => self.handle_graphs_input(key)?,
                     ActiveTab::Bodyweight => self.handle_bodyweight_input(key)?,
+                    ActiveTab::Calendar => self.handle_calendar_input(key)?,
+                    ActiveTab::Exercises => self.handle_exercises_input(key)?,
                 }
             }
         }
@@ -51,10 +83,22 @@ impl App {
     fn handle_modal_input(&mut self, key: KeyEvent) -> Result<()> {
         match self.active_modal {
             ActiveModal::Help => {
+                if self.keymap.action(KeyContext::Global, key) == Some(Action::Help) {
+                    self.active_modal = ActiveModal::None;
+                    return Ok(());
+                }
                 match key.code {
-                    KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter | KeyCode::Char('?') => {
+                    KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => {
                         self.active_modal = ActiveModal::None;
                     }
+                    KeyCode::Char('j') | KeyCode::Down => {
+                        self.help_scroll = self.help_scroll.saturating_add(1)
+                    }
+                    KeyCode::Char('k') | KeyCode::Up => {
+                        self.help_scroll = self.help_scroll.saturating_sub(1)
+                    }
+                    KeyCode::PageDown => self.help_scroll = self.help_scroll.saturating_add(10),
+                    KeyCode::PageUp => self.help_scroll = self.help_scroll.saturating_sub(10),
                     _ => {} // Ignore other keys in help
                 }
             }
@@ -64,12 +108,26 @@ impl App {
             ActiveModal::CreateExercise { .. } => handle_create_exercise_modal_input(self, key)?,
             ActiveModal::EditWorkout { .. } => handle_edit_workout_modal_input(self, key)?,
             ActiveModal::PersonalBest { .. } => handle_pb_modal_input(self, key)?,
+            ActiveModal::SessionSummary { .. } => handle_session_summary_modal_input(self, key)?,
+            ActiveModal::Routines { .. } => handle_routines_modal_input(self, key)?,
+            ActiveModal::CreateRoutine { .. } => handle_create_routine_modal_input(self, key)?,
             ActiveModal::ConfirmDeleteWorkout { .. } => {
                 handle_confirm_delete_modal_input(self, key)?;
             }
             ActiveModal::ConfirmDeleteBodyWeight { .. } => {
                 handle_confirm_delete_body_weigth_input(self, key);
             }
+            ActiveModal::ConfirmDeleteExercise { .. } => {
+                handle_confirm_delete_exercise_input(self, key)?;
+            }
+            ActiveModal::AliasManager { .. } => handle_alias_manager_modal_input(self, key)?,
+            ActiveModal::ConfirmDeleteWorkouts { .. } => {
+                handle_confirm_delete_workouts_input(self, key)?
+            }
+            ActiveModal::BulkDate { .. } => handle_bulk_date_modal_input(self, key)?,
+            ActiveModal::BulkEdit { .. } => handle_bulk_edit_modal_input(self, key)?,
+            ActiveModal::CopyDay { .. } => handle_copy_day_modal_input(self, key)?,
+            ActiveModal::GraphRange { .. } => handle_graph_range_modal_input(self, key)?,
             _ => {
                 if key.code == KeyCode::Esc {
                     self.active_modal = ActiveModal::None;
@@ -80,14 +138,47 @@ impl App {
     }
 
     fn handle_log_input(&mut self, key: KeyEvent) -> Result<()> {
+        // Rest timer, session and date keys work from every pane
+        if let Some(action) = self.keymap.action(KeyContext::Log, key) {
+            // The visual range is rows of the sets table, so pin it down before they change
+            if matches!(
+                action,
+                Action::PreviousDay
+                    | Action::NextDay
+                    | Action::PreviousLoggedDay
+                    | Action::NextLoggedDay
+            ) {
+                self.commit_log_visual_range();
+            }
+            match action {
+                Action::PreviousDay => log_change_date(self, -1),
+                Action::NextDay => log_change_date(self, 1),
+                Action::PreviousLoggedDay => log_set_previous_exercised_date(self)?,
+                Action::NextLoggedDay => log_set_next_exercised_date(self)?,
+                Action::ToggleSession => self.toggle_session(),
+                Action::Routines => self.open_routines_modal(),
+                Action::CopyDay => self.open_copy_day_modal(self.log_viewed_date),
+                Action::RestPause => self.rest_timer_toggle_pause(),
+                Action::RestLonger => self.rest_timer_adjust(true),
+                Action::RestShorter => self.rest_timer_adjust(false),
+                Action::RestSkip => self.rest_timer_skip(),
+                Action::RestSaveDefault => self.rest_timer_save_as_default(),
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        let Some(action) = self.keymap.action(self.log_focus_context(), key) else {
+            return Ok(());
+        };
         match self.log_focus {
-            LogFocus::ExerciseList => match key.code {
-                KeyCode::Char('k') | KeyCode::Up => log_list_previous(self),
-                KeyCode::Char('j') | KeyCode::Down => log_list_next(self),
-                KeyCode::Tab => self.log_focus = LogFocus::SetList,
-                KeyCode::Char('a') => self.open_add_workout_modal()?,
-                KeyCode::Char('c') => self.open_create_exercise_modal()?, // NEW: Open create modal
-                KeyCode::Char('g') => {
+            LogFocus::ExerciseList => match action {
+                Action::Up => log_list_previous(self),
+                Action::Down => log_list_next(self),
+                Action::SwitchFocus => self.log_focus = LogFocus::SetList,
+                Action::AddWorkout => self.open_add_workout_modal()?,
+                Action::CreateExercise => self.open_create_exercise_modal()?, // NEW: Open create modal
+                Action::ShowGraphs => {
                     // Navigate to Graphs tab with selected exercise
                     if let Some(selected_index) = self.log_exercise_list_state.selected() {
                         if let Some(selected_exercise_name) =
@@ -118,36 +209,80 @@ impl App {
                         }
                     }
                 }
-                KeyCode::Char('h') | KeyCode::Left => log_change_date(self, -1),
-                KeyCode::Char('l') | KeyCode::Right => log_change_date(self, 1),
-                KeyCode::Char('H') => log_set_previous_exercised_date(self)?,
-                KeyCode::Char('L') => log_set_next_exercised_date(self)?,
-                _ => {}
-            },
-            LogFocus::SetList => match key.code {
-                KeyCode::Char('k') | KeyCode::Up => log_table_previous(self),
-                KeyCode::Char('j') | KeyCode::Down => log_table_next(self),
-                KeyCode::Tab => self.log_focus = LogFocus::ExerciseList,
-                KeyCode::Char('e') | KeyCode::Enter => self.open_edit_workout_modal()?, // EDIT
-                KeyCode::Char('d') | KeyCode::Delete => {
-                    self.open_delete_confirmation_modal();
-                } // DELETE
-                KeyCode::Char('h') | KeyCode::Left => log_change_date(self, -1),
-                KeyCode::Char('l') | KeyCode::Right => log_change_date(self, 1),
-                KeyCode::Char('H') => log_set_previous_exercised_date(self)?,
-                KeyCode::Char('L') => log_set_next_exercised_date(self)?,
                 _ => {}
             },
+            LogFocus::SetList => {
+                if action == Action::SwitchFocus {
+                    self.commit_log_visual_range();
+                }
+                let has_marks = !self.log_marked_set_ids().is_empty();
+                match action {
+                    Action::Up => log_table_previous(self),
+                    Action::Down => log_table_next(self),
+                    Action::SwitchFocus if !self.planned_sets_for_viewed_date().is_empty() => {
+                        self.log_focus = LogFocus::Planned
+                    }
+                    Action::SwitchFocus => self.log_focus = LogFocus::ExerciseList,
+                    Action::Edit => self.open_edit_workout_modal()?, // EDIT
+                    Action::Delete if has_marks => self.open_bulk_delete_modal(),
+                    Action::Delete => {
+                        self.open_delete_confirmation_modal();
+                    } // DELETE
+                    Action::MarkSet => {
+                        self.toggle_log_set_mark();
+                        log_table_next(self);
+                    }
+                    Action::VisualMark => self.toggle_log_visual_mode(),
+                    Action::CopySets => self.open_bulk_date_modal(BulkDateAction::Copy),
+                    Action::MoveSets => self.open_bulk_date_modal(BulkDateAction::Move),
+                    Action::BulkEdit => self.open_bulk_edit_modal(),
+                    Action::ClearMarks => self.clear_log_marks(),
+                    _ => {}
+                }
+            }
+            LogFocus::Planned => {
+                let planned_len = self.planned_sets_for_viewed_date().len();
+                match action {
+                    Action::Up => list_previous(&mut self.log_planned_list_state, planned_len),
+                    Action::Down => list_next(&mut self.log_planned_list_state, planned_len),
+                    Action::SwitchFocus => self.log_focus = LogFocus::ExerciseList,
+                    Action::LogPlannedSet => self.open_add_workout_modal_for_planned_set()?,
+                    Action::Delete => {
+                        // Skip a planned set without logging it
+                        if let Some(planned) = self
+                            .log_planned_list_state
+                            .selected()
+                            .and_then(|i| self.planned_sets_for_viewed_date().get(i))
+                            .cloned()
+                        {
+                            self.remove_planned_set(self.log_viewed_date, &planned);
+                        }
+                    }
+                    _ => {}
+                }
+            }
         }
         Ok(())
     }
 
-    fn handle_history_input(&mut self, _key: KeyEvent) -> Result<()> {
+    /// Keymap context of the focused Log tab pane.
+    pub fn log_focus_context(&self) -> KeyContext {
+        match self.log_focus {
+            LogFocus::ExerciseList => KeyContext::LogExercises,
+            LogFocus::SetList => KeyContext::LogSets,
+            LogFocus::Planned => KeyContext::LogPlanned,
+        }
+    }
+
+    fn handle_history_input(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(action) = self.keymap.action(KeyContext::History, key) else {
+            return Ok(());
+        };
         match self.history_focus {
-            HistoryFocus::DayList => match _key.code {
-                KeyCode::Char('k') | KeyCode::Up => history_list_previous(self),
-                KeyCode::Char('j') | KeyCode::Down => history_list_next(self),
-                KeyCode::Char('l') => {
+            HistoryFocus::DayList => match action {
+                Action::Up => history_list_previous(self),
+                Action::Down => history_list_next(self),
+                Action::OpenInLog => {
                     // Navigate to Log tab for selected date
                     if let Some(selected_index) = self.history_list_state.selected() {
                         if let Some((date, _)) = self.history_data.get(selected_index) {
@@ -161,6 +296,15 @@ impl App {
                         }
                     }
                 }
+                Action::CopyDay => {
+                    if let Some((date, _)) = self
+                        .history_list_state
+                        .selected()
+                        .and_then(|i| self.history_data.get(i))
+                    {
+                        self.open_copy_day_modal(*date);
+                    }
+                }
                 _ => {}
             },
         }
@@ -168,38 +312,39 @@ impl App {
     }
 
     fn handle_graphs_input(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(action) = self.keymap.action(KeyContext::Graphs, key) else {
+            return Ok(());
+        };
+        // These act on the chart whichever list has focus
+        match action {
+            Action::CycleGraphRange => self.graphs_cycle_range(),
+            Action::CustomGraphRange => self.open_graph_range_modal(),
+            Action::PinSeries => self.graphs_toggle_pin(),
+            Action::ClearPins => self.graphs_clear_pins(),
+            Action::ToggleAverage => self.graphs_toggle_overlay(Overlay::Average),
+            Action::ToggleTrendLine => self.graphs_toggle_overlay(Overlay::Regression),
+            Action::ToggleCursor => self.graphs_toggle_cursor(),
+            Action::CursorPrevious => self.graphs_move_cursor(false),
+            Action::CursorNext => self.graphs_move_cursor(true),
+            _ => self.handle_graphs_list_input(action)?,
+        }
+        Ok(())
+    }
+
+    fn handle_graphs_list_input(&mut self, action: Action) -> Result<()> {
         match self.graph_focus {
-            GraphsFocus::ExerciseList => match key.code {
-                KeyCode::Char('k') | KeyCode::Up => graphs_exercise_list_previous(self),
-                KeyCode::Char('j') | KeyCode::Down => graphs_exercise_list_next(self)?,
-                KeyCode::Tab => self.graph_focus = GraphsFocus::GraphTypeList,
-                KeyCode::Enter => {
-                    // Set selected exercise, trigger data update, move focus
-                    if let Some(index) = self.graph_exercise_list_state.selected() {
-                        if let Some(name) = self.graph_exercises_all.get(index) {
-                            self.graph_selected_exercise = Some(name.clone());
-                            self.update_graph_data(); // Update based on new selection
-                            self.graph_focus = GraphsFocus::GraphTypeList;
-                        }
-                    }
-                }
+            GraphsFocus::ExerciseList => match action {
+                Action::Up => graphs_exercise_list_previous(self),
+                Action::Down => graphs_exercise_list_next(self)?,
+                Action::SwitchFocus => self.graph_focus = GraphsFocus::GraphTypeList,
+                Action::Select => self.graphs_select_exercise(),
                 _ => {}
             },
-            GraphsFocus::GraphTypeList => match key.code {
-                KeyCode::Char('k') | KeyCode::Up => graphs_type_list_previous(self),
-                KeyCode::Char('j') | KeyCode::Down => graphs_type_list_next(self),
-                KeyCode::Tab => self.graph_focus = GraphsFocus::ExerciseList, // Cycle back
-                KeyCode::Enter => {
-                    // Set selected type, trigger data update, move focus (optional)
-                    if let Some(index) = self.graph_type_list_state.selected() {
-                        if let Some(graph_type) = self.graph_types_available.get(index) {
-                            self.graph_selected_type = Some(*graph_type);
-                            self.update_graph_data(); // Update based on new type
-                                                      // Optionally move focus to graph display, or leave it here
-                                                      // self.graph_focus = GraphsFocus::GraphDisplay;
-                        }
-                    }
-                }
+            GraphsFocus::GraphTypeList => match action {
+                Action::Up => graphs_type_list_previous(self),
+                Action::Down => graphs_type_list_next(self),
+                Action::SwitchFocus => self.graph_focus = GraphsFocus::ExerciseList, // Cycle back
+                Action::Select => self.graphs_select_type(),
                 _ => {}
             },
             GraphsFocus::History => {}
@@ -207,9 +352,65 @@ impl App {
         Ok(())
     }
 
+    /// Graphs the highlighted exercise and moves on to picking the graph type.
+    pub(crate) fn graphs_select_exercise(&mut self) {
+        if let Some(index) = self.graph_exercise_list_state.selected() {
+            if let Some(name) = self.graph_exercises_all.get(index) {
+                self.graph_selected_exercise = Some(name.clone());
+                self.update_graph_data(); // Update based on new selection
+                self.graph_focus = GraphsFocus::GraphTypeList;
+            }
+        }
+    }
+
+    /// Graphs the highlighted graph type.
+    pub(crate) fn graphs_select_type(&mut self) {
+        if let Some(index) = self.graph_type_list_state.selected() {
+            if let Some(graph_type) = self.graph_types_available.get(index) {
+                self.graph_selected_type = Some(*graph_type);
+                self.update_graph_data(); // Update based on new type
+            }
+        }
+    }
+
+    /// Keeps the graphed series on the chart while others are picked, or takes it off again.
+    pub(crate) fn graphs_toggle_pin(&mut self) {
+        let Some(series) = self
+            .graph_selected_exercise
+            .clone()
+            .zip(self.graph_selected_type)
+        else {
+            self.set_error("Select an exercise and graph type to pin".to_string());
+            return;
+        };
+        let label = format!("{} - {}", series.0, graph_type_to_string(series.1));
+        if let Some(index) = self
+            .graph_pinned
+            .iter()
+            .position(|pinned| *pinned == series)
+        {
+            self.graph_pinned.remove(index);
+            self.set_info(format!("Unpinned {}", label));
+        } else {
+            self.graph_pinned.push(series);
+            self.set_info(format!("Pinned {}", label));
+        }
+        self.update_graph_data();
+    }
+
+    pub(crate) fn graphs_clear_pins(&mut self) {
+        if !self.graph_pinned.is_empty() {
+            self.graph_pinned.clear();
+            self.update_graph_data();
+        }
+    }
+
     fn handle_bodyweight_input(&mut self, key: KeyEvent) -> Result<()> {
-        match key.code {
-            KeyCode::Char('l') => {
+        let Some(action) = self.keymap.action(KeyContext::Bodyweight, key) else {
+            return Ok(());
+        };
+        match action {
+            Action::LogBodyweight => {
                 self.active_modal = ActiveModal::LogBodyweight {
                     weight_input: String::new(),
                     date_input: "today".to_string(),
@@ -217,7 +418,7 @@ impl App {
                     error_message: None,
                 };
             }
-            KeyCode::Char('t') => {
+            Action::SetTargetBodyweight => {
                 self.active_modal = ActiveModal::SetTargetWeight {
                     weight_input: self
                         .bw_target
@@ -226,24 +427,30 @@ impl App {
                     error_message: None,
                 };
             }
-            KeyCode::Char('r') => self.bw_cycle_graph_range(), // Keep cycle logic here for now
+            Action::CycleGraphRange => self.bw_cycle_graph_range(), // Keep cycle logic here for now
+            Action::ToggleAverage => self.bw_toggle_overlay(Overlay::Average),
+            Action::ToggleTrendLine => self.bw_toggle_overlay(Overlay::Regression),
+            Action::ToggleSmoothedTrend => self.bw_toggle_overlay(Overlay::Smoothed),
+            Action::ToggleCursor => self.bw_toggle_cursor(),
+            Action::CursorPrevious => self.bw_move_cursor(false),
+            Action::CursorNext => self.bw_move_cursor(true),
             _ => match self.bw_focus {
-                BodyweightFocus::History => match key.code {
-                    KeyCode::Char('k') | KeyCode::Up => bw_table_previous(self),
-                    KeyCode::Char('j') | KeyCode::Down => bw_table_next(self),
-                    KeyCode::Char('d') => {
+                BodyweightFocus::History => match action {
+                    Action::Up => bw_table_previous(self),
+                    Action::Down => bw_table_next(self),
+                    Action::Delete => {
                         self.open_delete_bodyweight_confirmation_modal();
                     }
-                    KeyCode::Tab => self.bw_focus = BodyweightFocus::Actions,
+                    Action::SwitchFocus => self.bw_focus = BodyweightFocus::Actions,
                     _ => {}
                 },
                 BodyweightFocus::Actions => {
-                    if key.code == KeyCode::Tab {
+                    if action == Action::SwitchFocus {
                         self.bw_focus = BodyweightFocus::History
                     }
                 }
                 BodyweightFocus::Graph => {
-                    if key.code == KeyCode::Tab {
+                    if action == Action::SwitchFocus {
                         self.bw_focus = BodyweightFocus::Actions
                     }
                 }
@@ -251,6 +458,119 @@ impl App {
         }
         Ok(())
     }
+
+    fn is_tab_text_input_focused(&self) -> bool {
+        match self.active_tab {
+            ActiveTab::Calendar => {
+                self.calendar_view == CalendarView::ListView
+                    && self.calendar_focus == CalendarFocus::FilterInput
+            }
+            ActiveTab::Exercises => self.exercises_focus == ExercisesFocus::SearchInput,
+            _ => false,
+        }
+    }
+
+    fn handle_exercises_input(&mut self, key: KeyEvent) -> Result<()> {
+        match self.exercises_focus {
+            // The list is re-filtered on every refresh, so typing searches as you go
+            ExercisesFocus::SearchInput => match key.code {
+                KeyCode::Char(c) => {
+                    self.exercises_search_input.push(c);
+                    self.exercises_table_state.select(Some(0)); // Jump to the best match
+                }
+                KeyCode::Backspace => {
+                    self.exercises_search_input.pop();
+                    self.exercises_table_state.select(Some(0));
+                }
+                KeyCode::Esc => {
+                    self.exercises_search_input.clear();
+                    self.exercises_focus = ExercisesFocus::List;
+                }
+                KeyCode::Enter | KeyCode::Tab | KeyCode::Down => {
+                    self.exercises_focus = ExercisesFocus::List
+                }
+                _ => {}
+            },
+            ExercisesFocus::List => match self.keymap.action(KeyContext::Exercises, key) {
+                Some(Action::Up) => exercises_table_previous(self),
+                Some(Action::Down) => exercises_table_next(self),
+                Some(Action::Search) => self.exercises_focus = ExercisesFocus::SearchInput,
+                Some(Action::ClearSearch) => self.exercises_search_input.clear(),
+                Some(Action::Edit) => self.open_edit_exercise_modal(),
+                Some(Action::Delete) => self.open_delete_exercise_confirmation_modal(),
+                Some(Action::CreateExercise) => self.open_create_exercise_modal()?,
+                Some(Action::ManageAliases) => self.open_alias_manager_modal(),
+                _ => {}
+            },
+        }
+        Ok(())
+    }
+
+    fn handle_calendar_input(&mut self, key: KeyEvent) -> Result<()> {
+        if !self.is_tab_text_input_focused()
+            && self.keymap.action(KeyContext::Calendar, key) == Some(Action::ToggleCalendarView)
+        {
+            self.calendar_view = match self.calendar_view {
+                CalendarView::CalendarView => CalendarView::ListView,
+                CalendarView::ListView => CalendarView::CalendarView,
+            };
+            return Ok(());
+        }
+
+        match self.calendar_view {
+            CalendarView::CalendarView => match self.keymap.action(KeyContext::CalendarGrid, key) {
+                Some(Action::PreviousDay) => calendar_change_day(self, -1),
+                Some(Action::NextDay) => calendar_change_day(self, 1),
+                Some(Action::PreviousWeek) => calendar_change_day(self, -7),
+                Some(Action::NextWeek) => calendar_change_day(self, 7),
+                Some(Action::PreviousMonth) => calendar_change_month(self, -1),
+                Some(Action::NextMonth) => calendar_change_month(self, 1),
+                Some(Action::Today) => {
+                    self.calendar_selected_date = chrono::Utc::now().date_naive()
+                }
+                Some(Action::OpenInLog) => log_jump_to_date(self, self.calendar_selected_date),
+                _ => {}
+            },
+            CalendarView::ListView => match self.calendar_focus {
+                CalendarFocus::FilterInput => match key.code {
+                    KeyCode::Char(c) => self.calendar_filter_input.push(c),
+                    KeyCode::Backspace => {
+                        self.calendar_filter_input.pop();
+                    }
+                    KeyCode::Enter => {
+                        self.apply_calendar_filter();
+                        self.calendar_focus = CalendarFocus::WorkoutList;
+                    }
+                    KeyCode::Esc => {
+                        // Discard edits, keep the filter that is currently applied
+                        self.calendar_filter_input = self.calendar_filter_applied.clone();
+                        self.calendar_focus = CalendarFocus::WorkoutList;
+                    }
+                    KeyCode::Tab => self.calendar_focus = CalendarFocus::WorkoutList,
+                    _ => {}
+                },
+                CalendarFocus::WorkoutList => {
+                    match self.keymap.action(KeyContext::CalendarList, key) {
+                        Some(Action::Up) => calendar_table_previous(self),
+                        Some(Action::Down) => calendar_table_next(self),
+                        Some(Action::Filter) => self.calendar_focus = CalendarFocus::FilterInput,
+                        Some(Action::OpenInLog) => {
+                            if let Some(workout) = self
+                                .calendar_list_state
+                                .selected()
+                                .and_then(|i| self.calendar_workouts_filtered.get(i))
+                            {
+                                let date = workout.timestamp.date_naive();
+                                log_jump_to_date(self, date);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            },
+        }
+        Ok(())
+    }
 }
 
 pub fn graphs_exercise_list_next(app: &mut App) -> Result<()> {
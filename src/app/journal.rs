@@ -0,0 +1,382 @@
+// src/app/journal.rs
+//! Undo/redo for data changes. Each change keeps enough of the affected row to put it back.
+//! Rows re-created by an undo get new ids from the database, which are patched into the
+//! rest of the journal so later undos and redos still find them.
+use super::state::App;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use task_athlete_lib::{
+    AddWorkoutParams, EditWorkoutParams, ExerciseDefinition, ExerciseType, Units, Workout,
+    WorkoutFilters,
+};
+
+const MAX_JOURNAL_ENTRIES: usize = 100;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BodyweightEntry {
+    pub id: i64,
+    pub timestamp: DateTime<Utc>,
+    pub weight: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    AddWorkout(Workout),
+    EditWorkout {
+        before: Workout,
+        after: Workout,
+    },
+    DeleteWorkout(Workout),
+    AddBodyweight(BodyweightEntry),
+    DeleteBodyweight(BodyweightEntry),
+    SetTargetBodyweight {
+        before: Option<f64>,
+        after: Option<f64>,
+    },
+}
+
+impl Change {
+    /// The change that takes this one back.
+    fn inverse(&self) -> Change {
+        match self {
+            Change::AddWorkout(w) => Change::DeleteWorkout(w.clone()),
+            Change::DeleteWorkout(w) => Change::AddWorkout(w.clone()),
+            Change::EditWorkout { before, after } => Change::EditWorkout {
+                before: after.clone(),
+                after: before.clone(),
+            },
+            Change::AddBodyweight(b) => Change::DeleteBodyweight(b.clone()),
+            Change::DeleteBodyweight(b) => Change::AddBodyweight(b.clone()),
+            Change::SetTargetBodyweight { before, after } => Change::SetTargetBodyweight {
+                before: *after,
+                after: *before,
+            },
+        }
+    }
+
+    fn workouts_mut(&mut self) -> Vec<&mut Workout> {
+        match self {
+            Change::AddWorkout(w) | Change::DeleteWorkout(w) => vec![w],
+            Change::EditWorkout { before, after } => vec![before, after],
+            _ => Vec::new(),
+        }
+    }
+
+    fn remap(&mut self, recreated: Recreated) {
+        match recreated {
+            Recreated::Workout { old_id, new_id } => self.remap_workout_id(old_id, new_id),
+            Recreated::Bodyweight { old_id, new_id } => self.remap_bodyweight_id(old_id, new_id),
+        }
+    }
+
+    fn remap_workout_id(&mut self, old_id: i64, new_id: i64) {
+        for workout in self.workouts_mut().into_iter().filter(|w| w.id == old_id) {
+            workout.id = new_id;
+        }
+    }
+
+    fn remap_bodyweight_id(&mut self, old_id: i64, new_id: i64) {
+        if let Change::AddBodyweight(b) | Change::DeleteBodyweight(b) = self {
+            if b.id == old_id {
+                b.id = new_id;
+            }
+        }
+    }
+}
+
+/// A row that got a new id when it was re-created.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Recreated {
+    Workout { old_id: i64, new_id: i64 },
+    Bodyweight { old_id: i64, new_id: i64 },
+}
+
+/// One user action, undone and redone as a whole (a warm-up ramp is several sets).
+#[derive(Clone, Debug, PartialEq)]
+pub struct JournalEntry {
+    pub description: String, // "add Squat set", shown as "Undid: add Squat set"
+    pub changes: Vec<Change>,
+}
+
+impl JournalEntry {
+    /// Moves the changes from `at` on into an entry of their own, if there are any.
+    fn split_off(&mut self, at: usize) -> Option<JournalEntry> {
+        (at < self.changes.len()).then(|| JournalEntry {
+            description: self.description.clone(),
+            changes: self.changes.split_off(at),
+        })
+    }
+
+    fn remap(&mut self, recreated: Recreated) {
+        for change in &mut self.changes {
+            change.remap(recreated);
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Journal {
+    undo: Vec<JournalEntry>,
+    redo: Vec<JournalEntry>,
+}
+
+impl Journal {
+    fn record(&mut self, entry: JournalEntry) {
+        self.redo.clear(); // A new change forks history, the undone branch is gone
+        self.undo.push(entry);
+        if self.undo.len() > MAX_JOURNAL_ENTRIES {
+            self.undo.remove(0);
+        }
+    }
+
+    fn remap(&mut self, recreated: Recreated) {
+        for entry in self.undo.iter_mut().chain(self.redo.iter_mut()) {
+            entry.remap(recreated);
+        }
+    }
+
+    /// Points sets of a renamed exercise at its new name, so undo and redo can resolve it.
+    pub(crate) fn rename_exercise(&mut self, old_name: &str, new_name: &str) {
+        for workout in self
+            .undo
+            .iter_mut()
+            .chain(self.redo.iter_mut())
+            .flat_map(|entry| entry.changes.iter_mut())
+            .flat_map(Change::workouts_mut)
+            .filter(|w| w.exercise_name == old_name)
+        {
+            workout.exercise_name = new_name.to_string();
+        }
+    }
+}
+
+/// True if going from `from` to `to` empties a field. Editing can only set values, so
+/// such a change is made by replacing the row instead.
+fn clears_a_field(from: &Workout, to: &Workout) -> bool {
+    (from.sets.is_some() && to.sets.is_none())
+        || (from.reps.is_some() && to.reps.is_none())
+        || (from.weight.is_some() && to.weight.is_none())
+        || (from.duration_minutes.is_some() && to.duration_minutes.is_none())
+        || (from.distance.is_some() && to.distance.is_none())
+        || (from.notes.is_some() && to.notes.is_none())
+}
+
+impl App {
+    /// Records a change made through the TUI so it can be undone with `u`.
+    pub(crate) fn record_change(&mut self, description: impl Into<String>, changes: Vec<Change>) {
+        if changes.is_empty() {
+            return;
+        }
+        self.invalidate_calendar_volumes();
+        self.track_session_changes(&changes, false);
+        self.journal.record(JournalEntry {
+            description: description.into(),
+            changes,
+        });
+    }
+
+    /// Reads a workout back from the database, e.g. to journal it right after it was saved.
+    pub(crate) fn fetch_workout(
+        &self,
+        id: i64,
+        exercise_name: &str,
+        date: NaiveDate,
+    ) -> Option<Workout> {
+        let filters = WorkoutFilters {
+            exercise_name: Some(exercise_name),
+            date: Some(date),
+            ..Default::default()
+        };
+        self.service
+            .list_workouts(&filters)
+            .ok()?
+            .into_iter()
+            .find(|w| w.id == id)
+    }
+
+    pub fn undo_last_change(&mut self) {
+        let Some(mut entry) = self.journal.undo.pop() else {
+            self.set_info("Nothing to undo".to_string());
+            return;
+        };
+        // Later changes may depend on earlier ones, so take them back last to first
+        for index in (0..entry.changes.len()).rev() {
+            let inverse = entry.changes[index].inverse();
+            if !self.apply_journal_change(&inverse, &mut entry) {
+                // What was taken back can be redone, the rest is still in place to undo
+                let undone = entry.split_off(index + 1);
+                self.journal.undo.push(entry);
+                self.journal.redo.extend(undone);
+                return;
+            }
+        }
+        self.set_info(format!("Undid: {}", entry.description));
+        self.journal.redo.push(entry);
+    }
+
+    pub fn redo_last_change(&mut self) {
+        let Some(mut entry) = self.journal.redo.pop() else {
+            self.set_info("Nothing to redo".to_string());
+            return;
+        };
+        for index in 0..entry.changes.len() {
+            let change = entry.changes[index].clone();
+            if !self.apply_journal_change(&change, &mut entry) {
+                let not_redone = entry.split_off(index);
+                if !entry.changes.is_empty() {
+                    self.journal.undo.push(entry);
+                }
+                self.journal.redo.extend(not_redone);
+                return;
+            }
+        }
+        self.set_info(format!("Redid: {}", entry.description));
+        self.journal.undo.push(entry);
+    }
+
+    /// Applies one change and patches any new row id into `entry` and the journal.
+    /// On failure the caller splits the entry into the changes applied and the ones left.
+    fn apply_journal_change(&mut self, change: &Change, entry: &mut JournalEntry) -> bool {
+        self.invalidate_calendar_volumes();
+        match self.apply_change(change) {
+            Ok(Some(recreated)) => {
+                entry.remap(recreated);
+                self.journal.remap(recreated);
+                self.remap_session_set(recreated);
+                let mut applied = change.clone();
+                applied.remap(recreated);
+                self.track_session_changes(&[applied], true);
+                true
+            }
+            Ok(None) => {
+                self.track_session_changes(std::slice::from_ref(change), true);
+                true
+            }
+            Err(e) => {
+                self.set_error(format!("Couldn't restore '{}': {}", entry.description, e));
+                false
+            }
+        }
+    }
+
+    fn apply_change(&mut self, change: &Change) -> Result<Option<Recreated>> {
+        match change {
+            Change::AddWorkout(workout) => {
+                let new_id = self.recreate_workout(workout)?;
+                Ok(Some(Recreated::Workout {
+                    old_id: workout.id,
+                    new_id,
+                }))
+            }
+            Change::DeleteWorkout(workout) => {
+                self.service.delete_workouts(&vec![workout.id])?;
+                Ok(None)
+            }
+            Change::EditWorkout { before, after } if clears_a_field(before, after) => {
+                self.service.delete_workouts(&vec![before.id])?;
+                let new_id = self.recreate_workout(after)?;
+                Ok(Some(Recreated::Workout {
+                    old_id: before.id,
+                    new_id,
+                }))
+            }
+            Change::EditWorkout { before, after } => {
+                let params = self.edit_params_for(before, after)?;
+                self.service.edit_workout(params)?;
+                Ok(None)
+            }
+            Change::AddBodyweight(entry) => {
+                let new_id = self
+                    .service
+                    .add_bodyweight_entry(entry.timestamp, entry.weight)?;
+                Ok(Some(Recreated::Bodyweight {
+                    old_id: entry.id,
+                    new_id,
+                }))
+            }
+            Change::DeleteBodyweight(entry) => {
+                self.service.delete_bodyweight(entry.id)?;
+                Ok(None)
+            }
+            Change::SetTargetBodyweight { after, .. } => {
+                self.service.set_target_bodyweight(*after)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Puts `workout` in place of the row with its id, for edits that empty a field, and
+    /// patches the new row's id into the journal.
+    pub(crate) fn replace_workout(&mut self, workout: &Workout) -> Result<()> {
+        self.service.delete_workouts(&vec![workout.id])?;
+        let new_id = self.recreate_workout(workout)?;
+        let recreated = Recreated::Workout {
+            old_id: workout.id,
+            new_id,
+        };
+        self.journal.remap(recreated);
+        self.remap_session_set(recreated);
+        Ok(())
+    }
+
+    /// Adds the workout back with its original time and values, returning its new id.
+    pub(crate) fn recreate_workout(&mut self, workout: &Workout) -> Result<i64> {
+        let def = self.definition_of(workout)?;
+        let params = AddWorkoutParams {
+            exercise_identifier: &def.name,
+            date: workout.timestamp,
+            sets: workout.sets,
+            reps: workout.reps,
+            weight: workout.weight,
+            duration: workout.duration_minutes,
+            distance: self.distance_for_input(workout.distance),
+            notes: workout.notes.clone(),
+            // The stored weight already includes the bodyweight it was logged with
+            bodyweight_to_use: (def.type_ == ExerciseType::BodyWeight).then_some(0.0),
+        };
+        let (new_id, _pb_info) = self.service.add_workout(params)?;
+        Ok(new_id)
+    }
+
+    /// Edit parameters that turn `current` into `target`, given the way the Edit Workout
+    /// modal submits values: added weight only for bodyweight exercises, display distance.
+    pub(crate) fn edit_params_for(
+        &self,
+        current: &Workout,
+        target: &Workout,
+    ) -> Result<EditWorkoutParams> {
+        let def = self.definition_of(target)?;
+        let new_weight = if def.type_ == ExerciseType::BodyWeight {
+            let bodyweight = self.service.config.bodyweight.unwrap_or(0.0);
+            target.weight.map(|w| (w - bodyweight).max(0.0))
+        } else {
+            target.weight
+        };
+        Ok(EditWorkoutParams {
+            id: current.id,
+            new_sets: target.sets,
+            new_reps: target.reps,
+            new_weight,
+            new_duration: target.duration_minutes,
+            new_distance_arg: self.distance_for_input(target.distance),
+            new_notes: target.notes.clone(),
+            new_date: (current.timestamp.date_naive() != target.timestamp.date_naive())
+                .then(|| target.timestamp.date_naive()),
+            ..Default::default()
+        })
+    }
+
+    fn definition_of(&self, workout: &Workout) -> Result<ExerciseDefinition> {
+        self.service
+            .resolve_exercise_identifier(&workout.exercise_name)?
+            .ok_or_else(|| anyhow!("exercise '{}' no longer exists", workout.exercise_name))
+    }
+
+    /// Distances are stored in km but entered in the configured units.
+    fn distance_for_input(&self, distance_km: Option<f64>) -> Option<f64> {
+        match self.service.config.units {
+            Units::Metric => distance_km,
+            Units::Imperial => distance_km.map(|km| km * 0.621371),
+        }
+    }
+}
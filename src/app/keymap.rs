@@ -0,0 +1,1404 @@
+// src/app/keymap.rs
+//! Keybindings of the tabs and of the modal shortcuts, per context, with optional
+//! overrides from `keymap.toml` next to the TUI settings file. The help modal and status
+//! bar are built from the active keymap. Typing and field navigation (Enter, Esc, Tab,
+//! arrows) in modals and text inputs keep their fixed keys.
+use super::settings::TuiSettings;
+use anyhow::{bail, Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+const KEYMAP_FILE_NAME: &str = "keymap.toml";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    // Global
+    Quit,
+    Help,
+    Sync,
+    Undo,
+    Redo,
+    ShowLogTab,
+    ShowHistoryTab,
+    ShowGraphsTab,
+    ShowBodyweightTab,
+    ShowCalendarTab,
+    ShowExercisesTab,
+    // Shared by lists and tables
+    Up,
+    Down,
+    SwitchFocus,
+    Select,
+    Edit,
+    Delete,
+    // Log tab
+    PreviousDay,
+    NextDay,
+    PreviousLoggedDay,
+    NextLoggedDay,
+    ToggleSession,
+    Routines,
+    CopyDay,
+    RestPause,
+    RestLonger,
+    RestShorter,
+    RestSkip,
+    RestSaveDefault,
+    AddWorkout,
+    CreateExercise,
+    ShowGraphs,
+    MarkSet,
+    VisualMark,
+    CopySets,
+    MoveSets,
+    BulkEdit,
+    ClearMarks,
+    LogPlannedSet,
+    // History and Calendar tabs
+    OpenInLog,
+    ToggleCalendarView,
+    PreviousWeek,
+    NextWeek,
+    PreviousMonth,
+    NextMonth,
+    Today,
+    Filter,
+    // Bodyweight tab
+    LogBodyweight,
+    SetTargetBodyweight,
+    CycleGraphRange,
+    CustomGraphRange, // Graphs tab only
+    PinSeries,
+    ClearPins,
+    // Graphs and Bodyweight tabs
+    ToggleAverage,
+    ToggleTrendLine,
+    ToggleSmoothedTrend, // Bodyweight tab only
+    ToggleCursor,
+    CursorPrevious,
+    CursorNext,
+    // Exercises tab
+    Search,
+    ClearSearch,
+    ManageAliases,
+    // Modals
+    AcceptSuggestion,
+    TogglePlates,
+    LogWarmups,
+    NewRoutine,
+    AddAlias,
+    Close,
+    Confirm,
+    Cancel,
+}
+
+impl Action {
+    pub fn is_rest_timer(self) -> bool {
+        matches!(
+            self,
+            Action::RestPause
+                | Action::RestLonger
+                | Action::RestShorter
+                | Action::RestSkip
+                | Action::RestSaveDefault
+        )
+    }
+}
+
+/// An action as it appears in one context, with its default keys.
+#[derive(Debug)]
+pub struct ActionSpec {
+    pub action: Action,
+    pub name: &'static str, // Key in keymap.toml
+    pub default_keys: &'static [&'static str],
+    pub help: &'static str,
+    pub hint: Option<&'static str>, // Status bar label, None leaves it out of the bar
+}
+
+const fn spec(
+    action: Action,
+    name: &'static str,
+    default_keys: &'static [&'static str],
+    help: &'static str,
+    hint: Option<&'static str>,
+) -> ActionSpec {
+    ActionSpec {
+        action,
+        name,
+        default_keys,
+        help,
+        hint,
+    }
+}
+
+const UP: ActionSpec = spec(Action::Up, "up", &["k", "Up"], "Navigate Up", None);
+const DOWN: ActionSpec = spec(Action::Down, "down", &["j", "Down"], "Navigate Down", None);
+
+const GLOBAL_SPECS: &[ActionSpec] = &[
+    spec(
+        Action::Quit,
+        "quit",
+        &["q"],
+        "Quit Application",
+        Some("Quit"),
+    ),
+    spec(
+        Action::Help,
+        "help",
+        &["?"],
+        "Show/Hide This Help",
+        Some("Help"),
+    ),
+    spec(
+        Action::Sync,
+        "sync",
+        &["S"],
+        "Sync With Server (runs in background)",
+        None,
+    ),
+    spec(
+        Action::Undo,
+        "undo",
+        &["u"],
+        "Undo Last Change (sets, bodyweight entries, target weight only)",
+        Some("Undo"),
+    ),
+    spec(
+        Action::Redo,
+        "redo",
+        &["Ctrl+r"],
+        "Redo Last Undone Change",
+        None,
+    ),
+    spec(
+        Action::ShowLogTab,
+        "log_tab",
+        &["F1"],
+        "Switch to Log Tab",
+        None,
+    ),
+    spec(
+        Action::ShowHistoryTab,
+        "history_tab",
+        &["F2"],
+        "Switch to History Tab",
+        None,
+    ),
+    spec(
+        Action::ShowGraphsTab,
+        "graphs_tab",
+        &["F3"],
+        "Switch to Graphs Tab",
+        None,
+    ),
+    spec(
+        Action::ShowBodyweightTab,
+        "bodyweight_tab",
+        &["F4"],
+        "Switch to Bodyweight Tab",
+        None,
+    ),
+    spec(
+        Action::ShowCalendarTab,
+        "calendar_tab",
+        &["F5"],
+        "Switch to Calendar Tab",
+        None,
+    ),
+    spec(
+        Action::ShowExercisesTab,
+        "exercises_tab",
+        &["F6"],
+        "Switch to Exercises Tab",
+        None,
+    ),
+];
+
+const LOG_SPECS: &[ActionSpec] = &[
+    spec(
+        Action::PreviousDay,
+        "previous_day",
+        &["h", "Left"],
+        "View Previous Day",
+        None,
+    ),
+    spec(
+        Action::NextDay,
+        "next_day",
+        &["l", "Right"],
+        "View Next Day",
+        None,
+    ),
+    spec(
+        Action::PreviousLoggedDay,
+        "previous_logged_day",
+        &["H"],
+        "Jump to Previous Day With Workouts",
+        None,
+    ),
+    spec(
+        Action::NextLoggedDay,
+        "next_logged_day",
+        &["L"],
+        "Jump to Next Day With Workouts",
+        None,
+    ),
+    spec(
+        Action::ToggleSession,
+        "session",
+        &["s"],
+        "Start/Finish Workout Session",
+        Some("Session"),
+    ),
+    spec(
+        Action::Routines,
+        "routines",
+        &["r"],
+        "Open Routines",
+        Some("Routines"),
+    ),
+    spec(
+        Action::CopyDay,
+        "copy_day",
+        &["C"],
+        "Copy the Viewed Day's Workouts",
+        Some("Copy Day"),
+    ),
+    spec(
+        Action::RestPause,
+        "rest_pause",
+        &["p"],
+        "Pause/Resume Rest Timer",
+        Some("Pause"),
+    ),
+    spec(
+        Action::RestLonger,
+        "rest_longer",
+        &["+", "="],
+        "Add 15s of Rest",
+        Some("+15s"),
+    ),
+    spec(
+        Action::RestShorter,
+        "rest_shorter",
+        &["-"],
+        "Remove 15s of Rest",
+        Some("-15s"),
+    ),
+    spec(
+        Action::RestSkip,
+        "rest_skip",
+        &["x"],
+        "Skip Rest Timer",
+        Some("Skip"),
+    ),
+    spec(
+        Action::RestSaveDefault,
+        "rest_save_default",
+        &["T"],
+        "Save Current Rest Length for the Exercise",
+        Some("Save as Default"),
+    ),
+];
+
+const LOG_EXERCISES_SPECS: &[ActionSpec] = &[
+    UP,
+    DOWN,
+    spec(
+        Action::SwitchFocus,
+        "switch_focus",
+        &["Tab"],
+        "Focus the Sets Table",
+        Some("Focus"),
+    ),
+    spec(
+        Action::AddWorkout,
+        "add_workout",
+        &["a"],
+        "Add New Workout Entry (for viewed day)",
+        Some("Add"),
+    ),
+    spec(
+        Action::CreateExercise,
+        "create_exercise",
+        &["c"],
+        "Create New Exercise Definition",
+        Some("Create Exercise"),
+    ),
+    spec(
+        Action::ShowGraphs,
+        "graphs",
+        &["g"],
+        "Go to Graphs for Selected Exercise",
+        Some("Graphs"),
+    ),
+];
+
+const LOG_SETS_SPECS: &[ActionSpec] = &[
+    UP,
+    DOWN,
+    spec(
+        Action::SwitchFocus,
+        "switch_focus",
+        &["Tab"],
+        "Focus the Planned Sets (if any) or Exercise List",
+        None,
+    ),
+    spec(
+        Action::Edit,
+        "edit",
+        &["e", "Enter"],
+        "Edit Selected Set",
+        Some("Edit"),
+    ),
+    spec(
+        Action::Delete,
+        "delete",
+        &["d", "Delete"],
+        "Delete Selected or Marked Sets",
+        Some("Delete"),
+    ),
+    spec(
+        Action::MarkSet,
+        "mark",
+        &["Space"],
+        "Mark/Unmark Set",
+        Some("Mark"),
+    ),
+    spec(
+        Action::VisualMark,
+        "visual_mark",
+        &["v"],
+        "Mark a Range of Sets",
+        Some("Visual"),
+    ),
+    spec(
+        Action::CopySets,
+        "copy",
+        &["c"],
+        "Copy Marked Sets to a Date",
+        Some("Copy"),
+    ),
+    spec(
+        Action::MoveSets,
+        "move",
+        &["m"],
+        "Move Marked Sets to a Date",
+        Some("Move"),
+    ),
+    spec(
+        Action::BulkEdit,
+        "bulk_edit",
+        &["b"],
+        "Edit Weight/Reps of Marked Sets",
+        Some("Bulk Edit"),
+    ),
+    spec(
+        Action::ClearMarks,
+        "clear_marks",
+        &["Esc"],
+        "Clear Marks",
+        Some("Clear Marks"),
+    ),
+];
+
+const LOG_PLANNED_SPECS: &[ActionSpec] = &[
+    UP,
+    DOWN,
+    spec(
+        Action::SwitchFocus,
+        "switch_focus",
+        &["Tab"],
+        "Focus the Exercise List",
+        Some("Focus"),
+    ),
+    spec(
+        Action::LogPlannedSet,
+        "log_planned",
+        &["Enter"],
+        "Log Selected Planned Set (prefills Add Workout)",
+        Some("Log Planned Set"),
+    ),
+    spec(
+        Action::Delete,
+        "delete",
+        &["d", "Delete"],
+        "Drop Selected Planned Set",
+        Some("Drop"),
+    ),
+];
+
+const HISTORY_SPECS: &[ActionSpec] = &[
+    UP,
+    DOWN,
+    spec(
+        Action::OpenInLog,
+        "open_in_log",
+        &["l", "Enter"],
+        "Open Selected Day in Log Tab",
+        Some("Open in Log"),
+    ),
+    spec(
+        Action::CopyDay,
+        "copy_day",
+        &["C"],
+        "Copy the Selected Day's Workouts",
+        Some("Copy Day"),
+    ),
+];
+
+const GRAPHS_SPECS: &[ActionSpec] = &[
+    UP,
+    DOWN,
+    spec(
+        Action::SwitchFocus,
+        "switch_focus",
+        &["Tab"],
+        "Switch Between Exercise and Graph Type Lists",
+        Some("Focus"),
+    ),
+    spec(
+        Action::Select,
+        "select",
+        &["Enter"],
+        "Select Exercise/Graph Type",
+        Some("Select"),
+    ),
+    spec(
+        Action::CycleGraphRange,
+        "range",
+        &["r"],
+        "Cycle Graph Date Range (4W > 3M > 6M > YTD > All)",
+        Some("Range"),
+    ),
+    spec(
+        Action::CustomGraphRange,
+        "custom_range",
+        &["R"],
+        "Graph a Custom Date Range",
+        Some("Dates"),
+    ),
+    spec(
+        Action::PinSeries,
+        "pin",
+        &["p"],
+        "Pin/Unpin the Graphed Series to Compare It With Others",
+        Some("Pin"),
+    ),
+    spec(
+        Action::ClearPins,
+        "clear_pins",
+        &["P"],
+        "Unpin All Series",
+        None,
+    ),
+    spec(
+        Action::ToggleAverage,
+        "average",
+        &["a"],
+        "Show/Hide 7-Day Moving Average",
+        Some("Avg"),
+    ),
+    spec(
+        Action::ToggleTrendLine,
+        "trend_line",
+        &["T"],
+        "Show/Hide Trend Line and Its Slope",
+        Some("Trend"),
+    ),
+    spec(
+        Action::ToggleCursor,
+        "cursor",
+        &["c"],
+        "Start/Stop Reading Chart Points (Cursor Mode)",
+        Some("Cursor"),
+    ),
+    spec(
+        Action::CursorPrevious,
+        "cursor_previous",
+        &["Left"],
+        "Move the Chart Cursor to the Previous Point",
+        None,
+    ),
+    spec(
+        Action::CursorNext,
+        "cursor_next",
+        &["Right"],
+        "Move the Chart Cursor to the Next Point",
+        None,
+    ),
+];
+
+const BODYWEIGHT_SPECS: &[ActionSpec] = &[
+    UP,
+    DOWN,
+    spec(
+        Action::SwitchFocus,
+        "switch_focus",
+        &["Tab"],
+        "Cycle Focus",
+        None,
+    ),
+    spec(
+        Action::LogBodyweight,
+        "log",
+        &["l"],
+        "Log New Bodyweight Entry",
+        Some("Log"),
+    ),
+    spec(
+        Action::SetTargetBodyweight,
+        "target",
+        &["t"],
+        "Set/Clear Target Bodyweight",
+        Some("Target"),
+    ),
+    spec(
+        Action::CycleGraphRange,
+        "range",
+        &["r"],
+        "Cycle Graph Time Range (1M > 3M > 6M > 1Y > All)",
+        Some("Range"),
+    ),
+    spec(
+        Action::ToggleAverage,
+        "average",
+        &["a"],
+        "Show/Hide 7-Day Moving Average",
+        None,
+    ),
+    spec(
+        Action::ToggleTrendLine,
+        "trend_line",
+        &["T"],
+        "Show/Hide Trend Line and Its Slope",
+        None,
+    ),
+    spec(
+        Action::ToggleSmoothedTrend,
+        "smoothed_trend",
+        &["e"],
+        "Show/Hide Smoothed Weight Trend (evens out daily swings)",
+        Some("Trend"),
+    ),
+    spec(
+        Action::ToggleCursor,
+        "cursor",
+        &["c"],
+        "Start/Stop Reading Chart Points (Cursor Mode)",
+        Some("Cursor"),
+    ),
+    spec(
+        Action::CursorPrevious,
+        "cursor_previous",
+        &["Left"],
+        "Move the Chart Cursor to the Previous Point",
+        None,
+    ),
+    spec(
+        Action::CursorNext,
+        "cursor_next",
+        &["Right"],
+        "Move the Chart Cursor to the Next Point",
+        None,
+    ),
+    spec(
+        Action::Delete,
+        "delete",
+        &["d"],
+        "Delete Selected Entry",
+        Some("Delete"),
+    ),
+];
+
+const CALENDAR_SPECS: &[ActionSpec] = &[spec(
+    Action::ToggleCalendarView,
+    "toggle_view",
+    &["v"],
+    "Switch Between Month Grid and Workout List",
+    Some("Switch View"),
+)];
+
+const CALENDAR_GRID_SPECS: &[ActionSpec] = &[
+    spec(
+        Action::PreviousDay,
+        "previous_day",
+        &["h", "Left"],
+        "Previous Day",
+        None,
+    ),
+    spec(
+        Action::NextDay,
+        "next_day",
+        &["l", "Right"],
+        "Next Day",
+        None,
+    ),
+    spec(
+        Action::PreviousWeek,
+        "previous_week",
+        &["k", "Up"],
+        "Previous Week",
+        None,
+    ),
+    spec(
+        Action::NextWeek,
+        "next_week",
+        &["j", "Down"],
+        "Next Week",
+        None,
+    ),
+    spec(
+        Action::PreviousMonth,
+        "previous_month",
+        &["H", "PageUp"],
+        "Previous Month",
+        None,
+    ),
+    spec(
+        Action::NextMonth,
+        "next_month",
+        &["L", "PageDown"],
+        "Next Month",
+        None,
+    ),
+    spec(
+        Action::Today,
+        "today",
+        &["t"],
+        "Jump to Today",
+        Some("Today"),
+    ),
+    spec(
+        Action::OpenInLog,
+        "open_in_log",
+        &["Enter"],
+        "Open Selected Day in Log Tab",
+        Some("Open in Log"),
+    ),
+];
+
+const CALENDAR_LIST_SPECS: &[ActionSpec] = &[
+    UP,
+    DOWN,
+    spec(
+        Action::Filter,
+        "filter",
+        &["Tab", "/"],
+        "Edit Filter (e.g. ex:squat muscle:legs after:2025-01-01 weight>100)",
+        Some("Filter"),
+    ),
+    spec(
+        Action::OpenInLog,
+        "open_in_log",
+        &["Enter"],
+        "Open Selected Workout's Day in Log Tab",
+        Some("Open in Log"),
+    ),
+];
+
+const EXERCISES_SPECS: &[ActionSpec] = &[
+    UP,
+    DOWN,
+    spec(
+        Action::Search,
+        "search",
+        &["/", "Tab"],
+        "Fuzzy Search (name, alias or muscle)",
+        Some("Search"),
+    ),
+    spec(
+        Action::ClearSearch,
+        "clear_search",
+        &["Esc"],
+        "Clear Search",
+        Some("Clear Search"),
+    ),
+    spec(
+        Action::Edit,
+        "edit",
+        &["e", "Enter"],
+        "Edit Selected Exercise (renaming keeps its history)",
+        Some("Edit"),
+    ),
+    spec(
+        Action::Delete,
+        "delete",
+        &["d", "Delete"],
+        "Delete Selected Exercise",
+        Some("Delete"),
+    ),
+    spec(
+        Action::CreateExercise,
+        "create",
+        &["c"],
+        "Create New Exercise Definition",
+        Some("Create"),
+    ),
+    spec(
+        Action::ManageAliases,
+        "aliases",
+        &["a"],
+        "Manage Aliases of Selected Exercise",
+        Some("Aliases"),
+    ),
+];
+
+const WORKOUT_MODAL_SPECS: &[ActionSpec] = &[
+    spec(
+        Action::AcceptSuggestion,
+        "accept_suggestion",
+        &["Ctrl+a"],
+        "Accept Suggested Next Weight/Reps (Add Workout)",
+        Some("Use Suggestion"),
+    ),
+    spec(
+        Action::TogglePlates,
+        "plates",
+        &["Ctrl+p"],
+        "Show/Hide Plate Calculator",
+        Some("Plates"),
+    ),
+    spec(
+        Action::LogWarmups,
+        "warmups",
+        &["Ctrl+w"],
+        "Log Warm-up Sets up to the Typed Weight (Add Workout)",
+        Some("Warm-ups"),
+    ),
+];
+
+const ROUTINES_SPECS: &[ActionSpec] = &[
+    UP,
+    DOWN,
+    spec(
+        Action::Select,
+        "apply",
+        &["Enter"],
+        "Apply Selected Routine to the Viewed Day",
+        Some("Apply to Day"),
+    ),
+    spec(
+        Action::NewRoutine,
+        "new",
+        &["n"],
+        "Create New Routine",
+        Some("New"),
+    ),
+    spec(
+        Action::Delete,
+        "delete",
+        &["d", "Delete"],
+        "Delete Selected Routine",
+        Some("Delete"),
+    ),
+    spec(
+        Action::Close,
+        "close",
+        &["Esc", "q"],
+        "Close Routines",
+        Some("Close"),
+    ),
+];
+
+const ALIASES_SPECS: &[ActionSpec] = &[
+    UP,
+    DOWN,
+    spec(
+        Action::Delete,
+        "delete",
+        &["d", "Delete"],
+        "Delete Selected Alias",
+        Some("Delete"),
+    ),
+    spec(
+        Action::AddAlias,
+        "add",
+        &["a", "Tab"],
+        "Type a New Alias",
+        Some("Add"),
+    ),
+    spec(
+        Action::Close,
+        "close",
+        &["Esc", "q"],
+        "Close Alias Manager",
+        Some("Close"),
+    ),
+];
+
+const CONFIRM_SPECS: &[ActionSpec] = &[
+    spec(
+        Action::Confirm,
+        "yes",
+        &["y", "Y", "Enter"],
+        "Delete",
+        Some("Yes"),
+    ),
+    spec(
+        Action::Cancel,
+        "no",
+        &["n", "N", "Esc", "Backspace"],
+        "Keep",
+        Some("No"),
+    ),
+];
+
+/// Where a key is pressed. A tab's keys apply to all of its panes, and global keys to
+/// every tab, so a key bound in a parent context never reaches the child. Modals take
+/// every key, so their contexts have no parents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeyContext {
+    Global,
+    Log,
+    LogExercises,
+    LogSets,
+    LogPlanned,
+    History,
+    Graphs,
+    Bodyweight,
+    Calendar,
+    CalendarGrid,
+    CalendarList,
+    Exercises,
+    WorkoutModal,
+    Routines,
+    Aliases,
+    Confirm,
+}
+
+impl KeyContext {
+    pub const ALL: [KeyContext; 16] = [
+        KeyContext::Global,
+        KeyContext::Log,
+        KeyContext::LogExercises,
+        KeyContext::LogSets,
+        KeyContext::LogPlanned,
+        KeyContext::History,
+        KeyContext::Graphs,
+        KeyContext::Bodyweight,
+        KeyContext::Calendar,
+        KeyContext::CalendarGrid,
+        KeyContext::CalendarList,
+        KeyContext::Exercises,
+        KeyContext::WorkoutModal,
+        KeyContext::Routines,
+        KeyContext::Aliases,
+        KeyContext::Confirm,
+    ];
+
+    /// Table name in keymap.toml.
+    pub fn key(self) -> &'static str {
+        match self {
+            KeyContext::Global => "global",
+            KeyContext::Log => "log",
+            KeyContext::LogExercises => "log_exercises",
+            KeyContext::LogSets => "log_sets",
+            KeyContext::LogPlanned => "log_planned",
+            KeyContext::History => "history",
+            KeyContext::Graphs => "graphs",
+            KeyContext::Bodyweight => "bodyweight",
+            KeyContext::Calendar => "calendar",
+            KeyContext::CalendarGrid => "calendar_grid",
+            KeyContext::CalendarList => "calendar_list",
+            KeyContext::Exercises => "exercises",
+            KeyContext::WorkoutModal => "workout_modal",
+            KeyContext::Routines => "routines",
+            KeyContext::Aliases => "aliases",
+            KeyContext::Confirm => "confirm",
+        }
+    }
+
+    /// Section title in the help modal.
+    pub fn title(self) -> &'static str {
+        match self {
+            KeyContext::Global => "Global",
+            KeyContext::Log => "Log Tab (F1)",
+            KeyContext::LogExercises => "Log Tab: Exercise List",
+            KeyContext::LogSets => "Log Tab: Sets Table",
+            KeyContext::LogPlanned => "Log Tab: Planned Sets",
+            KeyContext::History => "History Tab (F2)",
+            KeyContext::Graphs => "Graphs Tab (F3)",
+            KeyContext::Bodyweight => "Bodyweight Tab (F4)",
+            KeyContext::Calendar => "Calendar Tab (F5)",
+            KeyContext::CalendarGrid => "Calendar Tab: Month Grid",
+            KeyContext::CalendarList => "Calendar Tab: Workout List",
+            KeyContext::Exercises => "Exercises Tab (F6)",
+            KeyContext::WorkoutModal => "Add/Edit Workout Modal",
+            KeyContext::Routines => "Routines Modal",
+            KeyContext::Aliases => "Alias Manager: Alias List",
+            KeyContext::Confirm => "Delete Confirmations",
+        }
+    }
+
+    fn parents(self) -> &'static [KeyContext] {
+        match self {
+            KeyContext::Global
+            | KeyContext::WorkoutModal
+            | KeyContext::Routines
+            | KeyContext::Aliases
+            | KeyContext::Confirm => &[],
+            KeyContext::LogExercises | KeyContext::LogSets | KeyContext::LogPlanned => {
+                &[KeyContext::Global, KeyContext::Log]
+            }
+            KeyContext::CalendarGrid | KeyContext::CalendarList => {
+                &[KeyContext::Global, KeyContext::Calendar]
+            }
+            _ => &[KeyContext::Global],
+        }
+    }
+
+    fn specs(self) -> &'static [ActionSpec] {
+        match self {
+            KeyContext::Global => GLOBAL_SPECS,
+            KeyContext::Log => LOG_SPECS,
+            KeyContext::LogExercises => LOG_EXERCISES_SPECS,
+            KeyContext::LogSets => LOG_SETS_SPECS,
+            KeyContext::LogPlanned => LOG_PLANNED_SPECS,
+            KeyContext::History => HISTORY_SPECS,
+            KeyContext::Graphs => GRAPHS_SPECS,
+            KeyContext::Bodyweight => BODYWEIGHT_SPECS,
+            KeyContext::Calendar => CALENDAR_SPECS,
+            KeyContext::CalendarGrid => CALENDAR_GRID_SPECS,
+            KeyContext::CalendarList => CALENDAR_LIST_SPECS,
+            KeyContext::Exercises => EXERCISES_SPECS,
+            KeyContext::WorkoutModal => WORKOUT_MODAL_SPECS,
+            KeyContext::Routines => ROUTINES_SPECS,
+            KeyContext::Aliases => ALIASES_SPECS,
+            KeyContext::Confirm => CONFIRM_SPECS,
+        }
+    }
+}
+
+/// A key with the modifiers that matter for it (Shift is part of the character).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers, // Only CONTROL and ALT
+}
+
+impl KeyChord {
+    /// Parses `q`, `Ctrl+r`, `Alt+x`, `F1`, `Enter`, `Space`, `PageUp`...
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = input.trim();
+        loop {
+            let lower = rest.to_lowercase();
+            if lower.starts_with("ctrl+") && rest.len() > 5 {
+                modifiers |= KeyModifiers::CONTROL;
+                rest = &rest[5..];
+            } else if lower.starts_with("alt+") && rest.len() > 4 {
+                modifiers |= KeyModifiers::ALT;
+                rest = &rest[4..];
+            } else {
+                break;
+            }
+        }
+
+        let mut chars = rest.chars();
+        let code = match (chars.next(), chars.next()) {
+            // Terminals report Ctrl+R as Ctrl+r
+            (Some(c), None) if modifiers.is_empty() => KeyCode::Char(c),
+            (Some(c), None) => KeyCode::Char(c.to_ascii_lowercase()),
+            _ => match rest.to_lowercase().as_str() {
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "enter" => KeyCode::Enter,
+                "tab" => KeyCode::Tab,
+                "backtab" | "shift+tab" => KeyCode::BackTab,
+                "space" => KeyCode::Char(' '),
+                "esc" | "escape" => KeyCode::Esc,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" | "ins" => KeyCode::Insert,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" | "pgup" => KeyCode::PageUp,
+                "pagedown" | "pgdn" => KeyCode::PageDown,
+                name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                    Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                    _ => bail!("unknown key '{}'", input),
+                },
+            },
+        };
+        Ok(Self { code, modifiers })
+    }
+
+    /// The event this key sends, so a click can stand in for the key.
+    pub fn key_event(self) -> KeyEvent {
+        KeyEvent::new(self.code, self.modifiers)
+    }
+
+    /// Whether the key only goes with Ctrl/Alt or is an F key, so it can't be typed text.
+    fn is_shortcut(&self) -> bool {
+        !self.modifiers.is_empty() || matches!(self.code, KeyCode::F(_))
+    }
+
+    fn matches(&self, key: KeyEvent) -> bool {
+        key.code == self.code
+            && key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT) == self.modifiers
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) if !self.modifiers.is_empty() => {
+                write!(f, "{}", c.to_ascii_uppercase())
+            }
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::BackTab => write!(f, "Shift+Tab"),
+            KeyCode::Delete => write!(f, "Del"),
+            KeyCode::Insert => write!(f, "Ins"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            code => write!(f, "{:?}", code), // Enter, Tab, Esc, Backspace, Home, End
+        }
+    }
+}
+
+/// The keys bound to one action of a context.
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub spec: &'static ActionSpec,
+    pub keys: Vec<KeyChord>,
+}
+
+impl Binding {
+    /// `d / Del`, or empty if the action is unbound.
+    pub fn keys_label(&self) -> String {
+        self.keys
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+/// keymap.toml: `[context] action = "key"` or `action = ["key", ...]`, `[]` unbinds.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeyContext, Vec<Binding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = KeyContext::ALL
+            .iter()
+            .map(|&context| {
+                let bindings = context
+                    .specs()
+                    .iter()
+                    .map(|spec| Binding {
+                        spec,
+                        keys: spec
+                            .default_keys
+                            .iter()
+                            .map(|k| KeyChord::parse(k).expect("default keys are valid"))
+                            .collect(),
+                    })
+                    .collect();
+                (context, bindings)
+            })
+            .collect();
+        Self { bindings }
+    }
+}
+
+impl Keymap {
+    fn path() -> Option<PathBuf> {
+        TuiSettings::config_dir().map(|dir| dir.join(KEYMAP_FILE_NAME))
+    }
+
+    /// Loads the overrides in the keymap file on top of the defaults. A missing file means
+    /// the defaults; unknown names, bad keys and conflicting bindings are errors.
+    pub fn load() -> Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let overrides: HashMap<String, HashMap<String, KeyList>> = toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Self::with_overrides(overrides).with_context(|| format!("Invalid {}", path.display()))
+    }
+
+    fn with_overrides(overrides: HashMap<String, HashMap<String, KeyList>>) -> Result<Self> {
+        let mut keymap = Self::default();
+        for (context_name, actions) in overrides {
+            let Some(context) = KeyContext::ALL.iter().find(|c| c.key() == context_name) else {
+                bail!("unknown section [{}]", context_name);
+            };
+            let bindings = keymap.bindings.entry(*context).or_default();
+            for (action_name, keys) in actions {
+                let Some(binding) = bindings.iter_mut().find(|b| b.spec.name == action_name) else {
+                    bail!("unknown action '{}' in [{}]", action_name, context_name);
+                };
+                let keys = match keys {
+                    KeyList::One(key) => vec![key],
+                    KeyList::Many(keys) => keys,
+                };
+                binding.keys = keys
+                    .iter()
+                    .map(|k| KeyChord::parse(k))
+                    .collect::<Result<_>>()
+                    .with_context(|| format!("in [{}] {}", context_name, action_name))?;
+            }
+        }
+        keymap.check_conflicts()?;
+        Ok(keymap)
+    }
+
+    /// A key may do one thing per context, and mustn't be shadowed by a parent context.
+    /// The workout modal's shortcuts work while typing, so they need Ctrl/Alt or an F key.
+    fn check_conflicts(&self) -> Result<()> {
+        let mut conflicts = Vec::new();
+        for context in KeyContext::ALL {
+            let mut seen: HashMap<KeyChord, &str> = HashMap::new();
+            for binding in self.bindings(context) {
+                for key in &binding.keys {
+                    if context == KeyContext::WorkoutModal && !key.is_shortcut() {
+                        conflicts.push(format!(
+                            "'{}' for {} in [{}] would be typed into the fields, use Ctrl+ or Alt+",
+                            key,
+                            binding.spec.name,
+                            context.key()
+                        ));
+                    }
+                    if let Some(other) = seen.insert(*key, binding.spec.name) {
+                        conflicts.push(format!(
+                            "'{}' is bound to both {} and {} in [{}]",
+                            key,
+                            other,
+                            binding.spec.name,
+                            context.key()
+                        ));
+                    }
+                    for parent in context.parents() {
+                        if let Some(shadowing) = self.binding_for_key(*parent, key) {
+                            conflicts.push(format!(
+                                "'{}' for {} in [{}] is taken by {} in [{}]",
+                                key,
+                                binding.spec.name,
+                                context.key(),
+                                shadowing.spec.name,
+                                parent.key()
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        if !conflicts.is_empty() {
+            bail!("{}", conflicts.join("; "));
+        }
+        Ok(())
+    }
+
+    fn binding_for_key(&self, context: KeyContext, key: &KeyChord) -> Option<&Binding> {
+        self.bindings(context)
+            .iter()
+            .find(|binding| binding.keys.contains(key))
+    }
+
+    pub fn bindings(&self, context: KeyContext) -> &[Binding] {
+        self.bindings.get(&context).map_or(&[], Vec::as_slice)
+    }
+
+    /// The action `key` triggers in `context` itself (parents are checked by the caller).
+    pub fn action(&self, context: KeyContext, key: KeyEvent) -> Option<Action> {
+        self.bindings(context)
+            .iter()
+            .find(|binding| binding.keys.iter().any(|k| k.matches(key)))
+            .map(|binding| binding.spec.action)
+    }
+
+    /// "[key] Hint" for the bound actions of `context` that have a hint and pass `shown`.
+    pub fn hints(&self, context: KeyContext, shown: impl Fn(Action) -> bool) -> Vec<String> {
+        self.bindings(context)
+            .iter()
+            .filter(|binding| shown(binding.spec.action))
+            .filter_map(|binding| {
+                let key = binding.keys.first()?;
+                Some(format!("[{}] {}", key, binding.spec.hint?))
+            })
+            .collect()
+    }
+
+    /// First key of `action` in `context`, for hints like "[a] Add".
+    pub fn first_key(&self, context: KeyContext, action: Action) -> Option<KeyChord> {
+        self.bindings(context)
+            .iter()
+            .find(|binding| binding.spec.action == action)
+            .and_then(|binding| binding.keys.first().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(code: KeyCode, modifiers: KeyModifiers) -> KeyChord {
+        KeyChord { code, modifiers }
+    }
+
+    fn overrides(context: &str, action: &str, keys: &[&str]) -> Keymap {
+        let keys = KeyList::Many(keys.iter().map(ToString::to_string).collect());
+        let actions = HashMap::from([(action.to_string(), keys)]);
+        Keymap::with_overrides(HashMap::from([(context.to_string(), actions)])).unwrap()
+    }
+
+    fn override_error(context: &str, action: &str, key: &str) -> String {
+        let actions = HashMap::from([(action.to_string(), KeyList::One(key.to_string()))]);
+        let err =
+            Keymap::with_overrides(HashMap::from([(context.to_string(), actions)])).unwrap_err();
+        format!("{:#}", err)
+    }
+
+    #[test]
+    fn parses_keys_with_modifiers() {
+        let none = KeyModifiers::NONE;
+        assert_eq!(
+            KeyChord::parse("q").unwrap(),
+            chord(KeyCode::Char('q'), none)
+        );
+        assert_eq!(
+            KeyChord::parse("Q").unwrap(),
+            chord(KeyCode::Char('Q'), none)
+        );
+        assert_eq!(
+            KeyChord::parse("Ctrl+R").unwrap(),
+            chord(KeyCode::Char('r'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            KeyChord::parse("ctrl+alt+x").unwrap(),
+            chord(
+                KeyCode::Char('x'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT
+            )
+        );
+        assert_eq!(KeyChord::parse(" F5 ").unwrap(), chord(KeyCode::F(5), none));
+        assert_eq!(
+            KeyChord::parse("Space").unwrap(),
+            chord(KeyCode::Char(' '), none)
+        );
+        assert_eq!(
+            KeyChord::parse("pgdn").unwrap(),
+            chord(KeyCode::PageDown, none)
+        );
+        assert_eq!(
+            KeyChord::parse("+").unwrap(),
+            chord(KeyCode::Char('+'), none)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        for input in ["", "F13", "Ctrl+", "Hyper+a", "Enterr"] {
+            assert!(KeyChord::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn displays_keys_the_way_they_parse() {
+        for input in [
+            "q",
+            "Ctrl+R",
+            "Alt+X",
+            "F1",
+            "Space",
+            "Enter",
+            "PgUp",
+            "Shift+Tab",
+        ] {
+            let chord = KeyChord::parse(input).unwrap();
+            assert_eq!(
+                KeyChord::parse(&chord.to_string()).unwrap(),
+                chord,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn default_keymap_has_no_conflicts() {
+        Keymap::default().check_conflicts().unwrap();
+    }
+
+    #[test]
+    fn overrides_replace_the_default_keys() {
+        let keymap = overrides("global", "quit", &["Ctrl+q"]);
+        let ctrl_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        let q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert_eq!(
+            keymap.action(KeyContext::Global, ctrl_q),
+            Some(Action::Quit)
+        );
+        assert_eq!(keymap.action(KeyContext::Global, q), None);
+
+        let keymap = overrides("log", "routines", &[]);
+        assert_eq!(keymap.first_key(KeyContext::Log, Action::Routines), None);
+    }
+
+    #[test]
+    fn binding_taken_by_a_parent_context_is_a_conflict() {
+        // `u` is Undo in [global], which every tab pane sits under
+        let err = override_error("log_sets", "edit", "u");
+        assert!(err.contains("taken by undo in [global]"), "{err}");
+    }
+
+    #[test]
+    fn key_bound_twice_in_a_context_is_a_conflict() {
+        let err = override_error("exercises", "search", "d");
+        assert!(err.contains("bound to both"), "{err}");
+    }
+
+    #[test]
+    fn modal_keys_dont_conflict_with_the_tabs() {
+        // Modals take every key, so `q` can answer No while it quits elsewhere
+        let keymap = overrides("confirm", "no", &["q"]);
+        let q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert_eq!(keymap.action(KeyContext::Confirm, q), Some(Action::Cancel));
+    }
+
+    #[test]
+    fn workout_modal_shortcuts_need_a_modifier() {
+        let err = override_error("workout_modal", "plates", "p");
+        assert!(err.contains("would be typed"), "{err}");
+        overrides("workout_modal", "plates", &["Alt+p", "F9"]);
+    }
+
+    #[test]
+    fn unknown_sections_and_actions_are_errors() {
+        assert!(override_error("nope", "quit", "q").contains("unknown section"));
+        assert!(override_error("global", "nope", "q").contains("unknown action"));
+    }
+}
@@ -2,13 +2,36 @@ use thiserror::Error;
 
 // Declare the modules within the app directory
 pub mod actions;
+pub mod bulk;
+pub mod chart_cursor;
+pub mod copy_day;
 pub mod data;
+pub mod exercises;
+pub mod fuzzy;
+pub mod goal;
+pub mod graph_range;
 pub mod input;
+pub mod journal;
+pub mod keymap;
 pub mod modals;
+pub mod mouse;
 pub mod navigation;
 pub mod navigation_helpers;
+pub mod plates;
+pub mod progression;
+pub mod rest_timer;
+pub mod routines;
+pub mod session;
+pub mod set_meta;
+pub mod settings;
 pub mod state;
+pub mod suggestions;
+pub mod sync_actions;
+pub mod theme;
+pub mod trends;
 pub mod utils;
+pub mod warmup;
+pub mod workout_filter;
 
 // Re-export the main App struct and other necessary types for convenience
 pub use state::{
@@ -32,4 +55,16 @@ pub enum AppInputError {
     DbError(String),
     #[error("Exercise name cannot be empty.")] // NEW specific error
     ExerciseNameEmpty,
+    #[error("Invalid filter term: {0}")]
+    InvalidFilter(String),
+    #[error("Invalid routine entry: '{0}'. Use e.g. 'bench 3x5 @60'.")]
+    InvalidRoutine(String),
+    #[error("'{alias}' is already {taken_by}.")]
+    AliasConflict { alias: String, taken_by: String },
+    #[error("Those sets are already on {0}. Pick another date.")]
+    SameDate(String),
+    #[error("Invalid date range: {0}.")]
+    InvalidDateRange(String),
+    #[error("Can't log warm-ups: {0}.")]
+    WarmupUnavailable(String),
 }
@@ -1,12 +1,15 @@
 // src/app/modals/add_workout.rs
 // ... other imports ...
-use super::input_helpers::{get_next_focusable_field, NavigationDirection};
+use super::input_helpers::{edit_set_meta_field, get_next_focusable_field, NavigationDirection};
+use crate::app::journal::Change;
+use crate::app::keymap::{Action, KeyContext};
+use crate::app::set_meta::SetMeta;
 use crate::app::state::{ActiveModal, AddWorkoutField, App, WorkoutLogFlags};
 use crate::app::utils::parse_option_to_input;
 use crate::app::utils::{modify_numeric_input, parse_optional_float, parse_optional_int};
 use crate::app::AppInputError;
 use anyhow::Result;
-use chrono::{TimeZone, Utc};
+use chrono::Utc;
 use crossterm::event::{KeyCode, KeyEvent};
 use task_athlete_lib::{AddWorkoutParams, DbError, ExerciseDefinition, ExerciseType};
 
@@ -19,21 +22,16 @@ fn submit_add_workout(app: &mut App, modal_state: &ActiveModal) -> Result<bool,
         weight_input,
         duration_input,
         distance_input,
+        set_type,
+        rpe_input,
+        rir_input,
         notes_input,
         resolved_exercise,
         ..
     } = modal_state
     {
         let mut workout_parameters = AddWorkoutParams::default();
-        workout_parameters.date = if Utc::now().date_naive() == app.log_viewed_date {
-            Utc::now()
-        } else {
-            let naive = app
-                .log_viewed_date
-                .and_hms_opt(12, 0, 0)
-                .ok_or_else(|| AppInputError::InvalidDate("Invalid date".to_string()))?;
-            Utc.from_utc_datetime(&naive)
-        };
+        workout_parameters.date = app.timestamp_for_viewed_date()?;
 
         let exercise_def = resolved_exercise.as_ref().ok_or_else(|| {
             AppInputError::DbError("Exercise not resolved. Select a valid exercise.".to_string())
@@ -67,11 +65,12 @@ fn submit_add_workout(app: &mut App, modal_state: &ActiveModal) -> Result<bool,
             None
         };
 
-        workout_parameters.notes = if notes_input.trim().is_empty() {
-            None
+        let set_meta = if flags.log_effort {
+            SetMeta::from_inputs(*set_type, rpe_input, rir_input)?
         } else {
-            Some(notes_input.trim().to_string())
+            SetMeta::from_inputs(*set_type, "", "")?
         };
+        workout_parameters.notes = set_meta.encode_notes(notes_input);
 
         workout_parameters.bodyweight_to_use = if exercise_def.type_ == ExerciseType::BodyWeight {
             app.service.config.bodyweight
@@ -79,9 +78,26 @@ fn submit_add_workout(app: &mut App, modal_state: &ActiveModal) -> Result<bool,
             None
         };
         let ex_identifier = workout_parameters.exercise_identifier;
+        let timestamp = workout_parameters.date;
 
         match app.service.add_workout(workout_parameters) {
-            Ok((_workout_id, pb_info)) => {
+            Ok((workout_id, pb_info)) => {
+                if let Some(workout) =
+                    app.fetch_workout(workout_id, ex_identifier, timestamp.date_naive())
+                {
+                    if let Some(pb) = pb_info.clone().filter(|pb| pb.any_pb()) {
+                        app.record_session_pb(ex_identifier, pb);
+                    }
+                    app.record_change(
+                        format!("add {} set", ex_identifier),
+                        vec![Change::AddWorkout(workout)],
+                    );
+                }
+                app.complete_pending_planned_set();
+                // Only time rest for sets logged live, not when back-filling another day
+                if app.log_viewed_date == Utc::now().date_naive() {
+                    app.start_rest_timer(ex_identifier);
+                }
                 let mut pb_modal_opened = false;
                 if let Some(pb) = pb_info {
                     if pb.any_pb() {
@@ -114,6 +130,23 @@ fn submit_add_workout(app: &mut App, modal_state: &ActiveModal) -> Result<bool,
 // --- Input Handling ---
 
 pub fn handle_add_workout_modal_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    // Shortcuts work from any field, so they're checked before text input swallows the key
+    match app.keymap.action(KeyContext::WorkoutModal, key) {
+        Some(Action::AcceptSuggestion) => {
+            app.accept_progression_suggestion();
+            return Ok(());
+        }
+        Some(Action::TogglePlates) => {
+            app.toggle_plate_calculator();
+            return Ok(());
+        }
+        Some(Action::LogWarmups) => {
+            app.log_warmup_sets();
+            return Ok(());
+        }
+        _ => {}
+    }
+
     let mut submission_result: Result<bool, AppInputError> = Ok(false); // Store PB modal flag
     let mut should_submit = false;
     let mut needs_suggestion_update = false;
@@ -146,6 +179,9 @@ pub fn handle_add_workout_modal_input(app: &mut App, key: KeyEvent) -> Result<()
         ref mut weight_input,
         ref mut duration_input,
         ref mut distance_input,
+        ref mut set_type,
+        ref mut rpe_input,
+        ref mut rir_input,
         ref mut notes_input,
         ref mut focused_field,
         ref mut error_message,
@@ -200,7 +236,7 @@ pub fn handle_add_workout_modal_input(app: &mut App, key: KeyEvent) -> Result<()
                     if let Some(selected_index) = suggestion_list_state.selected() {
                         if let Some(selected_suggestion) = exercise_suggestions.get(selected_index)
                         {
-                            let suggestion_clone = selected_suggestion.clone();
+                            let suggestion_clone = selected_suggestion.identifier.clone();
                             match app.service.resolve_exercise_identifier(&suggestion_clone) {
                                 Ok(Some(def)) => {
                                     *exercise_input = def.name.clone();
@@ -518,6 +554,37 @@ pub fn handle_add_workout_modal_input(app: &mut App, key: KeyEvent) -> Result<()
                     }
                     _ => {}
                 },
+                AddWorkoutField::SetType | AddWorkoutField::Rpe | AddWorkoutField::Rir => {
+                    match key.code {
+                        KeyCode::Enter | KeyCode::Tab => {
+                            next_focus_target = Some(get_next_focusable_field(
+                                current_focused_field,
+                                &flags,
+                                NavigationDirection::Forward,
+                                is_add_mode,
+                            ))
+                        }
+                        KeyCode::BackTab => {
+                            next_focus_target = Some(get_next_focusable_field(
+                                current_focused_field,
+                                &flags,
+                                NavigationDirection::Backward,
+                                is_add_mode,
+                            ))
+                        }
+                        KeyCode::Esc => {
+                            app.active_modal = ActiveModal::None;
+                            return Ok(());
+                        }
+                        code => edit_set_meta_field(
+                            current_focused_field,
+                            code,
+                            set_type,
+                            rpe_input,
+                            rir_input,
+                        ),
+                    }
+                }
                 AddWorkoutField::Notes => {
                     exercise_suggestions.clear();
                     suggestion_list_state.select(None);
@@ -679,7 +746,9 @@ pub fn handle_add_workout_modal_input(app: &mut App, key: KeyEvent) -> Result<()
     // ... (keep existing logic here) ...
     if let Some(def_to_repopulate) = repopulate_fields_for_resolved_exercise {
         let last_workout = app.get_last_or_specific_workout(&def_to_repopulate.name, None);
+        let new_suggestion = app.progression_suggestion_for(&def_to_repopulate);
         if let ActiveModal::AddWorkout {
+            ref mut progression_suggestion,
             ref mut sets_input,
             ref mut reps_input,
             ref mut weight_input,
@@ -688,6 +757,7 @@ pub fn handle_add_workout_modal_input(app: &mut App, key: KeyEvent) -> Result<()
             ..
         } = app.active_modal
         {
+            *progression_suggestion = new_suggestion;
             let flags = WorkoutLogFlags::from_def(Some(&def_to_repopulate)); // Get flags for repopulation
 
             if flags.log_sets {
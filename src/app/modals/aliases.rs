@@ -0,0 +1,158 @@
+// src/app/modals/aliases.rs
+
+use crate::app::keymap::{Action, KeyContext};
+use crate::app::navigation_helpers::{ensure_selection_is_valid, list_next, list_previous};
+use crate::app::state::{ActiveModal, AliasManagerField, App};
+use crate::app::AppInputError;
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+
+// --- Submission Logic ---
+
+/// Aliases share one namespace with exercise names, so a new alias may clash with either.
+fn check_alias_conflicts(app: &App, alias: &str, exercise_name: &str) -> Result<(), AppInputError> {
+    let conflict = |taken_by: String| AppInputError::AliasConflict {
+        alias: alias.to_string(),
+        taken_by,
+    };
+    let exercises = app
+        .service
+        .list_exercises(None, None)
+        .map_err(|e| AppInputError::DbError(e.to_string()))?;
+    if let Some(def) = exercises
+        .iter()
+        .find(|def| def.name.eq_ignore_ascii_case(alias))
+    {
+        return Err(conflict(format!("the name of exercise '{}'", def.name)));
+    }
+
+    let aliases = app
+        .service
+        .list_aliases()
+        .map_err(|e| AppInputError::DbError(e.to_string()))?;
+    match aliases
+        .iter()
+        .find(|(existing, _)| existing.eq_ignore_ascii_case(alias))
+    {
+        Some((_, target)) if target == exercise_name => {
+            Err(conflict("an alias of this exercise".to_string()))
+        }
+        Some((_, target)) => Err(conflict(format!("an alias of '{}'", target))),
+        None => Ok(()),
+    }
+}
+
+fn submit_add_alias(
+    app: &mut App,
+    exercise_name: &str,
+    alias_input: &str,
+) -> Result<(), AppInputError> {
+    let alias = alias_input.trim();
+    if alias.is_empty() {
+        return Err(AppInputError::InputEmpty);
+    }
+    check_alias_conflicts(app, alias, exercise_name)?;
+    app.service
+        .create_alias(alias, exercise_name)
+        .map_err(|e| AppInputError::DbError(format!("Error adding alias: {}", e)))?;
+    app.set_info(format!("Added alias '{}' for '{}'", alias, exercise_name));
+    Ok(())
+}
+
+fn submit_delete_alias(app: &mut App, alias: &str) -> Result<(), AppInputError> {
+    app.service
+        .delete_alias(alias)
+        .map_err(|e| AppInputError::DbError(format!("Error deleting alias: {}", e)))?;
+    app.set_info(format!("Deleted alias '{}'", alias));
+    Ok(())
+}
+
+// --- Input Handling ---
+
+pub fn handle_alias_manager_modal_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    let mut alias_to_add = None;
+    let mut alias_to_delete = None;
+
+    if let ActiveModal::AliasManager {
+        ref exercise_name,
+        ref mut alias_input,
+        ref mut list_state,
+        ref mut focused_field,
+        ref mut error_message,
+    } = app.active_modal
+    {
+        *error_message = None;
+        let aliases = app
+            .exercises_aliases
+            .get(exercise_name)
+            .map_or(&[][..], Vec::as_slice);
+
+        match focused_field {
+            AliasManagerField::Input => match key.code {
+                KeyCode::Char(c) => alias_input.push(c),
+                KeyCode::Backspace => {
+                    alias_input.pop();
+                }
+                KeyCode::Enter => alias_to_add = Some(alias_input.clone()),
+                KeyCode::Tab | KeyCode::Down if !aliases.is_empty() => {
+                    *focused_field = AliasManagerField::List;
+                    ensure_selection_is_valid(list_state, aliases.len());
+                }
+                KeyCode::Esc => {
+                    app.active_modal = ActiveModal::None;
+                    return Ok(());
+                }
+                _ => {}
+            },
+            AliasManagerField::List => match app.keymap.action(KeyContext::Aliases, key) {
+                Some(Action::Up) => list_previous(list_state, aliases.len()),
+                Some(Action::Down) => list_next(list_state, aliases.len()),
+                Some(Action::Delete) => {
+                    alias_to_delete = list_state.selected().and_then(|i| aliases.get(i)).cloned();
+                }
+                Some(Action::AddAlias) => *focused_field = AliasManagerField::Input,
+                Some(Action::Close) => {
+                    app.active_modal = ActiveModal::None;
+                    return Ok(());
+                }
+                _ => {}
+            },
+        }
+    } // Mutable borrow of app.active_modal ends here
+
+    let exercise_name = match &app.active_modal {
+        ActiveModal::AliasManager { exercise_name, .. } => exercise_name.clone(),
+        _ => return Ok(()),
+    };
+    let result = if let Some(alias) = alias_to_add {
+        submit_add_alias(app, &exercise_name, &alias)
+    } else if let Some(alias) = alias_to_delete {
+        submit_delete_alias(app, &alias)
+    } else {
+        return Ok(());
+    };
+    app.refresh_exercises_data(); // Pick up the changed alias list right away
+
+    let alias_count = app
+        .exercises_aliases
+        .get(&exercise_name)
+        .map_or(0, Vec::len);
+    if let ActiveModal::AliasManager {
+        ref mut alias_input,
+        ref mut list_state,
+        ref mut focused_field,
+        ref mut error_message,
+        ..
+    } = app.active_modal
+    {
+        match result {
+            Ok(()) => alias_input.clear(),
+            Err(err) => *error_message = Some(err.to_string()),
+        }
+        ensure_selection_is_valid(list_state, alias_count);
+        if alias_count == 0 {
+            *focused_field = AliasManagerField::Input;
+        }
+    }
+    Ok(())
+}
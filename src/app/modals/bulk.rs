@@ -0,0 +1,195 @@
+// src/app/modals/bulk.rs
+
+use crate::app::bulk::Adjustment;
+use crate::app::keymap::{Action, KeyContext};
+use crate::app::state::{ActiveModal, App, BulkEditField};
+use crate::app::utils::parse_modal_date;
+use crate::app::AppInputError;
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+
+// --- Input Handling ---
+
+pub fn handle_confirm_delete_workouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    let workouts = match &app.active_modal {
+        ActiveModal::ConfirmDeleteWorkouts { workouts } => workouts.clone(),
+        _ => {
+            app.active_modal = ActiveModal::None;
+            return Ok(());
+        }
+    };
+    match app.keymap.action(KeyContext::Confirm, key) {
+        Some(Action::Confirm) => {
+            app.active_modal = ActiveModal::None; // Close the modal even on error
+            if let Err(e) = app.bulk_delete_workouts(workouts) {
+                app.set_error(e.to_string());
+            }
+        }
+        Some(Action::Cancel) => {
+            app.active_modal = ActiveModal::None;
+        }
+        _ => {} // Ignore other keys
+    }
+    Ok(())
+}
+
+pub fn handle_bulk_date_modal_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    let mut submit = false;
+    if let ActiveModal::BulkDate {
+        ref mut date_input,
+        ref mut error_message,
+        ..
+    } = app.active_modal
+    {
+        *error_message = None;
+        match key.code {
+            KeyCode::Char(c) => date_input.push(c),
+            KeyCode::Backspace => {
+                date_input.pop();
+            }
+            KeyCode::Enter => submit = true,
+            KeyCode::Esc => {
+                app.active_modal = ActiveModal::None;
+                return Ok(());
+            }
+            _ => {}
+        }
+    } // Mutable borrow of app.active_modal ends here
+
+    if !submit {
+        return Ok(());
+    }
+    let result = match app.active_modal.clone() {
+        ActiveModal::BulkDate {
+            action,
+            workouts,
+            date_input,
+            ..
+        } => parse_modal_date(&date_input)
+            .and_then(|date| app.bulk_copy_or_move_workouts(action, &workouts, date)),
+        _ => return Ok(()),
+    };
+    set_result(app, result);
+    Ok(())
+}
+
+pub fn handle_bulk_edit_modal_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    let mut submit = false;
+    if let ActiveModal::BulkEdit {
+        ref mut weight_input,
+        ref mut reps_input,
+        ref mut focused_field,
+        ref mut error_message,
+        ..
+    } = app.active_modal
+    {
+        *error_message = None; // Clear error on any input
+
+        match focused_field {
+            BulkEditField::Weight | BulkEditField::Reps => {
+                let input = if *focused_field == BulkEditField::Weight {
+                    weight_input
+                } else {
+                    reps_input
+                };
+                match key.code {
+                    KeyCode::Char(c) if "0123456789.+-".contains(c) => input.push(c),
+                    KeyCode::Backspace => {
+                        input.pop();
+                    }
+                    KeyCode::Enter if *focused_field == BulkEditField::Reps => submit = true,
+                    KeyCode::Enter | KeyCode::Down | KeyCode::Tab => {
+                        *focused_field = next_field(*focused_field)
+                    }
+                    KeyCode::Up | KeyCode::BackTab => {
+                        *focused_field = previous_field(*focused_field)
+                    }
+                    KeyCode::Esc => {
+                        app.active_modal = ActiveModal::None;
+                        return Ok(());
+                    }
+                    _ => {}
+                }
+            }
+            BulkEditField::Confirm => match key.code {
+                KeyCode::Enter => submit = true,
+                KeyCode::Right | KeyCode::Tab | KeyCode::Down => {
+                    *focused_field = BulkEditField::Cancel
+                }
+                KeyCode::Up | KeyCode::BackTab => *focused_field = BulkEditField::Reps,
+                KeyCode::Esc => {
+                    app.active_modal = ActiveModal::None;
+                    return Ok(());
+                }
+                _ => {}
+            },
+            BulkEditField::Cancel => match key.code {
+                KeyCode::Enter | KeyCode::Esc => {
+                    app.active_modal = ActiveModal::None;
+                    return Ok(());
+                }
+                KeyCode::Left | KeyCode::Up | KeyCode::BackTab => {
+                    *focused_field = BulkEditField::Confirm
+                }
+                KeyCode::Tab | KeyCode::Down => *focused_field = BulkEditField::Weight, // Wrap around
+                _ => {}
+            },
+        }
+    } // Mutable borrow of app.active_modal ends here
+
+    if !submit {
+        return Ok(());
+    }
+    let result = match app.active_modal.clone() {
+        ActiveModal::BulkEdit {
+            workouts,
+            weight_input,
+            reps_input,
+            ..
+        } => Adjustment::parse(&weight_input).and_then(|weight| {
+            let reps = Adjustment::parse(&reps_input)?;
+            app.bulk_edit_workouts(&workouts, weight, reps)
+        }),
+        _ => return Ok(()),
+    };
+    set_result(app, result);
+    Ok(())
+}
+
+fn next_field(field: BulkEditField) -> BulkEditField {
+    match field {
+        BulkEditField::Weight => BulkEditField::Reps,
+        BulkEditField::Reps => BulkEditField::Confirm,
+        BulkEditField::Confirm => BulkEditField::Cancel,
+        BulkEditField::Cancel => BulkEditField::Weight,
+    }
+}
+
+fn previous_field(field: BulkEditField) -> BulkEditField {
+    match field {
+        BulkEditField::Weight => BulkEditField::Cancel,
+        BulkEditField::Reps => BulkEditField::Weight,
+        BulkEditField::Confirm => BulkEditField::Reps,
+        BulkEditField::Cancel => BulkEditField::Confirm,
+    }
+}
+
+/// Closes the modal on success, otherwise shows the error in it.
+fn set_result(app: &mut App, result: Result<(), AppInputError>) {
+    match result {
+        Ok(()) => app.active_modal = ActiveModal::None,
+        Err(err) => {
+            if let ActiveModal::BulkDate {
+                ref mut error_message,
+                ..
+            }
+            | ActiveModal::BulkEdit {
+                ref mut error_message,
+                ..
+            } = app.active_modal
+            {
+                *error_message = Some(err.to_string());
+            }
+        }
+    }
+}
@@ -1,15 +1,37 @@
 // src/app/modals/confirm_delete_bodyweight.rs
 
+use crate::app::journal::{BodyweightEntry, Change};
+use crate::app::keymap::{Action, KeyContext};
 use crate::app::state::{ActiveModal, App};
 use crate::app::AppInputError;
 use anyhow::Result;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::KeyEvent;
 
 // --- Submission Logic ---
 
 fn sumbit_delete_body_weight(app: &mut App, bodyweight_id: u64) -> Result<(), AppInputError> {
+    let deleted = app
+        .bw_history
+        .iter()
+        .find(|(id, ..)| *id == bodyweight_id as i64)
+        .map(|&(id, timestamp, weight)| BodyweightEntry {
+            id,
+            timestamp,
+            weight,
+        });
     match app.service.delete_bodyweight(bodyweight_id as i64) {
-        Ok(_) => Ok(()),
+        Ok(_) => {
+            if let Some(entry) = deleted {
+                app.record_change(
+                    format!(
+                        "delete bodyweight of {}",
+                        entry.timestamp.format("%Y-%m-%d")
+                    ),
+                    vec![Change::DeleteBodyweight(entry)],
+                );
+            }
+            Ok(())
+        }
         Err(e) => Err(AppInputError::DbError(format!(
             "Error deleting bodyweight: {}",
             e
@@ -27,11 +49,11 @@ pub fn handle_confirm_delete_body_weigth_input(app: &mut App, key: KeyEvent) ->
     // ActiveModal::ConfirmDeleteBodyWeight does not have focused_field
     if let ActiveModal::ConfirmDeleteBodyWeight { body_weight_id, .. } = app.active_modal {
         bodyweight_id_to_delete = body_weight_id; // Capture the ID
-        match key.code {
-            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+        match app.keymap.action(KeyContext::Confirm, key) {
+            Some(Action::Confirm) => {
                 should_delete = true;
             }
-            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc | KeyCode::Backspace => {
+            Some(Action::Cancel) => {
                 app.active_modal = ActiveModal::None; // Close modal, do nothing
                 return Ok(());
             }
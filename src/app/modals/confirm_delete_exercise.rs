@@ -0,0 +1,31 @@
+// src/app/modals/confirm_delete_exercise.rs
+
+use crate::app::keymap::{Action, KeyContext};
+use crate::app::state::{ActiveModal, App};
+use anyhow::Result;
+use crossterm::event::KeyEvent;
+
+// --- Input Handling ---
+
+pub fn handle_confirm_delete_exercise_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    let ActiveModal::ConfirmDeleteExercise {
+        ref exercise_name, ..
+    } = app.active_modal
+    else {
+        app.active_modal = ActiveModal::None;
+        return Ok(());
+    };
+
+    match app.keymap.action(KeyContext::Confirm, key) {
+        Some(Action::Confirm) => {
+            let exercise_name = exercise_name.clone();
+            app.active_modal = ActiveModal::None; // Errors go to the status bar
+            app.delete_exercise(&exercise_name);
+        }
+        Some(Action::Cancel) => {
+            app.active_modal = ActiveModal::None;
+        }
+        _ => {}
+    }
+    Ok(())
+}
@@ -1,15 +1,28 @@
 // src/app/modals/confirm_delete_workout_set.rs
 
+use crate::app::journal::Change;
+use crate::app::keymap::{Action, KeyContext};
 use crate::app::state::{ActiveModal, App};
 use crate::app::AppInputError;
 use anyhow::Result;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::KeyEvent;
 
 // --- Submission Logic ---
 
 fn submit_delete_workout_set(app: &mut App, workout_id: u64) -> Result<(), AppInputError> {
+    let deleted = app
+        .log_sets_for_selected_exercise
+        .iter()
+        .find(|w| w.id == workout_id as i64)
+        .cloned();
     match app.service.delete_workouts(&vec![workout_id as i64]) {
         Ok(_) => {
+            if let Some(workout) = deleted {
+                app.record_change(
+                    format!("delete {} set", workout.exercise_name),
+                    vec![Change::DeleteWorkout(workout)],
+                );
+            }
             // Adjust selection after deletion if necessary
             if let Some(selected_index) = app.log_set_table_state.selected() {
                 if selected_index >= app.log_sets_for_selected_exercise.len().saturating_sub(1) {
@@ -43,11 +56,11 @@ pub fn handle_confirm_delete_modal_input(app: &mut App, key: KeyEvent) -> Result
     // ActiveModal::ConfirmDeleteWorkout does not have focused_field
     if let ActiveModal::ConfirmDeleteWorkout { workout_id, .. } = app.active_modal {
         workout_id_to_delete = workout_id; // Capture the ID
-        match key.code {
-            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+        match app.keymap.action(KeyContext::Confirm, key) {
+            Some(Action::Confirm) => {
                 should_delete = true;
             }
-            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc | KeyCode::Backspace => {
+            Some(Action::Cancel) => {
                 app.active_modal = ActiveModal::None; // Close modal, do nothing
                 return Ok(());
             }
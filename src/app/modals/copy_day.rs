@@ -0,0 +1,138 @@
+// src/app/modals/copy_day.rs
+
+use crate::app::state::{ActiveModal, App, CopyDayField};
+use crate::app::utils::{modify_numeric_input, parse_modal_date};
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+
+const WEIGHT_STEP: f64 = 2.5;
+
+// --- Input Handling ---
+
+pub fn handle_copy_day_modal_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    let mut submit = false;
+    if let ActiveModal::CopyDay {
+        ref mut sets,
+        ref mut date_input,
+        ref mut selected,
+        ref mut focused_field,
+        ref mut error_message,
+        ..
+    } = app.active_modal
+    {
+        *error_message = None; // Clear error on any input
+
+        match focused_field {
+            CopyDayField::Date => match key.code {
+                KeyCode::Char(c) => date_input.push(c),
+                KeyCode::Backspace => {
+                    date_input.pop();
+                }
+                KeyCode::Enter => submit = true,
+                KeyCode::Tab | KeyCode::Down => *focused_field = CopyDayField::Sets,
+                KeyCode::BackTab | KeyCode::Up => *focused_field = CopyDayField::Cancel,
+                KeyCode::Esc => {
+                    app.active_modal = ActiveModal::None;
+                    return Ok(());
+                }
+                _ => {}
+            },
+            CopyDayField::Sets => match key.code {
+                KeyCode::Char('k') | KeyCode::Up => {
+                    if *selected == 0 {
+                        *focused_field = CopyDayField::Date;
+                    } else {
+                        *selected -= 1;
+                    }
+                }
+                KeyCode::Char('j') | KeyCode::Down => {
+                    if *selected + 1 < sets.len() {
+                        *selected += 1;
+                    } else {
+                        *focused_field = CopyDayField::Confirm;
+                    }
+                }
+                KeyCode::Char(' ') => {
+                    if let Some(set) = sets.get_mut(*selected) {
+                        set.include = !set.include;
+                    }
+                }
+                KeyCode::Char(c) if "0123456789.".contains(c) => {
+                    if let Some(set) = sets.get_mut(*selected) {
+                        set.weight_input.push(c);
+                    }
+                }
+                KeyCode::Char('+') | KeyCode::Char('-') => {
+                    let delta = if key.code == KeyCode::Char('+') {
+                        WEIGHT_STEP
+                    } else {
+                        -WEIGHT_STEP
+                    };
+                    if let Some(set) = sets.get_mut(*selected) {
+                        modify_numeric_input(&mut set.weight_input, delta, Some(0.0f64), true);
+                    }
+                }
+                KeyCode::Backspace => {
+                    if let Some(set) = sets.get_mut(*selected) {
+                        set.weight_input.pop();
+                    }
+                }
+                KeyCode::Enter => submit = true,
+                KeyCode::Tab => *focused_field = CopyDayField::Confirm,
+                KeyCode::BackTab => *focused_field = CopyDayField::Date,
+                KeyCode::Esc => {
+                    app.active_modal = ActiveModal::None;
+                    return Ok(());
+                }
+                _ => {}
+            },
+            CopyDayField::Confirm => match key.code {
+                KeyCode::Enter => submit = true,
+                KeyCode::Right | KeyCode::Tab => *focused_field = CopyDayField::Cancel,
+                KeyCode::Up | KeyCode::BackTab => *focused_field = CopyDayField::Sets,
+                KeyCode::Esc => {
+                    app.active_modal = ActiveModal::None;
+                    return Ok(());
+                }
+                _ => {}
+            },
+            CopyDayField::Cancel => match key.code {
+                KeyCode::Enter | KeyCode::Esc => {
+                    app.active_modal = ActiveModal::None;
+                    return Ok(());
+                }
+                KeyCode::Left | KeyCode::BackTab => *focused_field = CopyDayField::Confirm,
+                KeyCode::Up => *focused_field = CopyDayField::Sets,
+                KeyCode::Tab | KeyCode::Down => *focused_field = CopyDayField::Date, // Wrap around
+                _ => {}
+            },
+        }
+    } // Mutable borrow of app.active_modal ends here
+
+    if !submit {
+        return Ok(());
+    }
+    let result = match app.active_modal.clone() {
+        ActiveModal::CopyDay {
+            source_date,
+            sets,
+            date_input,
+            ..
+        } => parse_modal_date(&date_input)
+            .and_then(|target_date| app.copy_day_workouts(source_date, &sets, target_date)),
+        _ => return Ok(()),
+    };
+    match result {
+        Ok(()) => app.active_modal = ActiveModal::None,
+        Err(e) => {
+            if let ActiveModal::CopyDay {
+                ref mut error_message,
+                ..
+            } = app.active_modal
+            {
+                *error_message = Some(e.to_string());
+            }
+        }
+    }
+    Ok(())
+}
@@ -6,9 +6,12 @@ use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use task_athlete_lib::{DbError, ExerciseType};
 
+/// (weight, reps, duration, distance) as the library takes them, `None` = type defaults.
+type LogFlags = Option<(Option<bool>, Option<bool>, Option<bool>, Option<bool>)>;
+
 // --- Submission Logic ---
 
-fn submit_create_exercise(app: &App, modal_state: &ActiveModal) -> Result<(), AppInputError> {
+fn submit_create_exercise(app: &mut App, modal_state: &ActiveModal) -> Result<(), AppInputError> {
     if let ActiveModal::CreateExercise {
         name_input,
         muscles_input,
@@ -17,6 +20,7 @@ fn submit_create_exercise(app: &App, modal_state: &ActiveModal) -> Result<(), Ap
         log_reps,
         log_duration,
         log_distance,
+        editing,
         // ignore focused_field, error_message
         ..
     } = modal_state
@@ -34,6 +38,17 @@ fn submit_create_exercise(app: &App, modal_state: &ActiveModal) -> Result<(), Ap
 
         let arguments = convert_flags(*log_duration, *log_distance, *log_weight, *log_reps);
 
+        if let Some(original_name) = editing {
+            return submit_edit_exercise(
+                app,
+                original_name,
+                trimmed_name,
+                *selected_type,
+                arguments,
+                muscles_opt,
+            );
+        }
+
         // Call AppService to create the exercise
         match app
             .service
@@ -67,6 +82,40 @@ fn submit_create_exercise(app: &App, modal_state: &ActiveModal) -> Result<(), Ap
     }
 }
 
+fn submit_edit_exercise(
+    app: &mut App,
+    original_name: &str,
+    new_name: &str,
+    new_type: ExerciseType,
+    log_flags: LogFlags,
+    muscles: Option<&str>,
+) -> Result<(), AppInputError> {
+    let renamed = new_name != original_name;
+    app.service
+        .edit_exercise(
+            original_name,
+            renamed.then_some(new_name),
+            Some(new_type),
+            log_flags,
+            Some(muscles), // Some(None) clears the muscles
+        )
+        .map_err(|e| match e.downcast_ref::<DbError>() {
+            Some(DbError::ExerciseNameNotUnique(name)) => {
+                AppInputError::DbError(format!("Exercise '{}' already exists.", name))
+            }
+            Some(db_err) => AppInputError::DbError(db_err.to_string()),
+            None => AppInputError::DbError(format!("Error editing exercise: {}", e)),
+        })?;
+
+    if renamed {
+        app.rename_exercise_references(original_name, new_name);
+        app.set_info(format!("Renamed '{}' to '{}'", original_name, new_name));
+    } else {
+        app.set_info(format!("Updated exercise '{}'", new_name));
+    }
+    Ok(())
+}
+
 // --- Input Handling ---
 
 // Made public for re-export in mod.rs
@@ -85,6 +134,7 @@ pub fn handle_create_exercise_modal_input(app: &mut App, key: KeyEvent) -> Resul
         ref mut log_reps,
         ref mut log_duration,
         ref mut log_distance,
+        ..
     } = app.active_modal
     {
         // Always clear error on any input
@@ -351,7 +401,7 @@ pub fn handle_create_exercise_modal_input(app: &mut App, key: KeyEvent) -> Resul
         // --- Handle Submission Result ---
         if submission_result.is_ok() {
             app.active_modal = ActiveModal::None; // Close modal on success
-                                                  // Refresh handled by main loop
+            app.reload_exercise_lists(); // Graph and Exercises tabs cache the list
         } else {
             // Submission failed, re-borrow mutably ONLY if necessary to set error
             if let ActiveModal::CreateExercise {
@@ -367,12 +417,7 @@ pub fn handle_create_exercise_modal_input(app: &mut App, key: KeyEvent) -> Resul
     Ok(())
 }
 
-fn convert_flags(
-    weight: bool,
-    reps: bool,
-    duration: bool,
-    distance: bool,
-) -> Option<(Option<bool>, Option<bool>, Option<bool>, Option<bool>)> {
+fn convert_flags(weight: bool, reps: bool, duration: bool, distance: bool) -> LogFlags {
     match (duration, distance, weight, reps) {
         (false, false, false, false) => None,
         _ => Some((Some(duration), Some(distance), Some(weight), Some(reps))),
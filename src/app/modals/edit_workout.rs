@@ -1,5 +1,8 @@
 // src/app/modals/edit_workout.rs
-use super::input_helpers::{get_next_focusable_field, NavigationDirection}; // Import helper
+use super::input_helpers::{edit_set_meta_field, get_next_focusable_field, NavigationDirection}; // Import helper
+use crate::app::journal::Change;
+use crate::app::keymap::{Action, KeyContext};
+use crate::app::set_meta::SetMeta;
 use crate::app::state::{ActiveModal, AddWorkoutField, App, WorkoutLogFlags}; // Import WorkoutLogFlags
 use crate::app::utils::{modify_numeric_input, parse_optional_float, parse_optional_int};
 use crate::app::AppInputError;
@@ -17,6 +20,9 @@ fn submit_edit_workout(app: &mut App, modal_state: &ActiveModal) -> Result<(), A
         weight_input,
         duration_input,
         distance_input,
+        set_type,
+        rpe_input,
+        rir_input,
         notes_input,
         resolved_exercise, // Needed for type context and flags
         ..
@@ -59,15 +65,51 @@ fn submit_edit_workout(app: &mut App, modal_state: &ActiveModal) -> Result<(), A
         } else {
             None
         };
-        edit_params.new_notes = if flags.log_notes && !notes_input.trim().is_empty() {
-            Some(notes_input.trim().to_string())
+        let set_meta = if flags.log_effort {
+            SetMeta::from_inputs(*set_type, rpe_input, rir_input)?
+        } else {
+            SetMeta::from_inputs(*set_type, "", "")?
+        };
+        edit_params.new_notes = if flags.log_notes {
+            set_meta.encode_notes(notes_input)
         } else {
             None
         };
+        // No tags and no text encode to None, which the edit reads as "unchanged"
+        let clears_notes = flags.log_notes && edit_params.new_notes.is_none();
+
+        let before = app
+            .log_sets_for_selected_exercise
+            .iter()
+            .find(|w| w.id == edit_params.id)
+            .cloned();
 
         // Call AppService's edit_workout
         match app.service.edit_workout(edit_params) {
-            Ok(_) => Ok(()), // Success
+            Ok(_) => {
+                let after = before.as_ref().and_then(|b| {
+                    app.fetch_workout(b.id, &b.exercise_name, b.timestamp.date_naive())
+                });
+                if let (Some(before), Some(mut after)) = (before, after) {
+                    let replace = clears_notes && after.notes.is_some();
+                    if replace {
+                        after.notes = None;
+                    }
+                    app.record_change(
+                        format!("edit {} set", after.exercise_name),
+                        vec![Change::EditWorkout {
+                            before,
+                            after: after.clone(),
+                        }],
+                    );
+                    if replace {
+                        app.replace_workout(&after).map_err(|e| {
+                            AppInputError::DbError(format!("Error clearing notes: {e}"))
+                        })?;
+                    }
+                }
+                Ok(())
+            }
             Err(e) => Err(AppInputError::DbError(format!(
                 "Error editing workout: {e }"
             ))),
@@ -82,6 +124,12 @@ fn submit_edit_workout(app: &mut App, modal_state: &ActiveModal) -> Result<(), A
 // --- Input Handling ---
 
 pub fn handle_edit_workout_modal_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    // Checked before text input swallows the key
+    if app.keymap.action(KeyContext::WorkoutModal, key) == Some(Action::TogglePlates) {
+        app.toggle_plate_calculator();
+        return Ok(());
+    }
+
     let mut submission_result: Result<(), AppInputError> = Ok(());
     let mut should_submit = false;
 
@@ -109,6 +157,7 @@ pub fn handle_edit_workout_modal_input(app: &mut App, key: KeyEvent) -> Result<(
         // Use `ref mut` for mutable fields
         ref mut sets_input, ref mut reps_input, ref mut weight_input,
         ref mut duration_input, ref mut distance_input, ref mut notes_input,
+        ref mut set_type, ref mut rpe_input, ref mut rir_input,
         ref mut focused_field, ref mut error_message, .. // Ignore others here
     } = app.active_modal // Get mutable references here
     {
@@ -186,6 +235,12 @@ pub fn handle_edit_workout_modal_input(app: &mut App, key: KeyEvent) -> Result<(
                      KeyCode::Esc => { app.active_modal = ActiveModal::None; return Ok(()); }
                      _ => {}
                 },
+                AddWorkoutField::SetType | AddWorkoutField::Rpe | AddWorkoutField::Rir => match key.code {
+                     KeyCode::Enter | KeyCode::Tab => move_focus(NavigationDirection::Forward),
+                     // BackTab handled above
+                     KeyCode::Esc => { app.active_modal = ActiveModal::None; return Ok(()); }
+                     code => edit_set_meta_field(current_focus, code, set_type, rpe_input, rir_input),
+                },
                 AddWorkoutField::Notes => match key.code {
                      KeyCode::Char(c) => notes_input.push(c),
                      KeyCode::Backspace => { notes_input.pop(); }
@@ -0,0 +1,101 @@
+// src/app/modals/graph_range.rs
+
+use crate::app::state::{ActiveModal, App, GraphRangeField};
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+
+// --- Input Handling ---
+
+pub fn handle_graph_range_modal_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    let mut submit = false;
+    if let ActiveModal::GraphRange {
+        ref mut start_input,
+        ref mut end_input,
+        ref mut focused_field,
+        ref mut error_message,
+    } = app.active_modal
+    {
+        *error_message = None; // Clear error on any input
+
+        match focused_field {
+            GraphRangeField::Start | GraphRangeField::End => {
+                let input = if *focused_field == GraphRangeField::Start {
+                    start_input
+                } else {
+                    end_input
+                };
+                match key.code {
+                    KeyCode::Char(c) => input.push(c),
+                    KeyCode::Backspace => {
+                        input.pop();
+                    }
+                    KeyCode::Enter if *focused_field == GraphRangeField::End => submit = true,
+                    KeyCode::Enter | KeyCode::Down | KeyCode::Tab => {
+                        *focused_field = match focused_field {
+                            GraphRangeField::Start => GraphRangeField::End,
+                            _ => GraphRangeField::Confirm,
+                        }
+                    }
+                    KeyCode::Up | KeyCode::BackTab => {
+                        *focused_field = match focused_field {
+                            GraphRangeField::Start => GraphRangeField::Cancel,
+                            _ => GraphRangeField::Start,
+                        }
+                    }
+                    KeyCode::Esc => {
+                        app.active_modal = ActiveModal::None;
+                        return Ok(());
+                    }
+                    _ => {}
+                }
+            }
+            GraphRangeField::Confirm => match key.code {
+                KeyCode::Enter => submit = true,
+                KeyCode::Right | KeyCode::Tab | KeyCode::Down => {
+                    *focused_field = GraphRangeField::Cancel
+                }
+                KeyCode::Up | KeyCode::BackTab => *focused_field = GraphRangeField::End,
+                KeyCode::Esc => {
+                    app.active_modal = ActiveModal::None;
+                    return Ok(());
+                }
+                _ => {}
+            },
+            GraphRangeField::Cancel => match key.code {
+                KeyCode::Enter | KeyCode::Esc => {
+                    app.active_modal = ActiveModal::None;
+                    return Ok(());
+                }
+                KeyCode::Left | KeyCode::BackTab => *focused_field = GraphRangeField::Confirm,
+                KeyCode::Up => *focused_field = GraphRangeField::End,
+                KeyCode::Tab | KeyCode::Down => *focused_field = GraphRangeField::Start, // Wrap around
+                _ => {}
+            },
+        }
+    } // Mutable borrow of app.active_modal ends here
+
+    if !submit {
+        return Ok(());
+    }
+    let result = match app.active_modal.clone() {
+        ActiveModal::GraphRange {
+            start_input,
+            end_input,
+            ..
+        } => app.set_custom_graph_range(&start_input, &end_input),
+        _ => return Ok(()),
+    };
+    match result {
+        Ok(()) => app.active_modal = ActiveModal::None,
+        Err(e) => {
+            if let ActiveModal::GraphRange {
+                ref mut error_message,
+                ..
+            } = app.active_modal
+            {
+                *error_message = Some(e.to_string());
+            }
+        }
+    }
+    Ok(())
+}
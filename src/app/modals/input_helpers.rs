@@ -1,5 +1,8 @@
 // src/app/modals/input_helpers.rs
+use crate::app::set_meta::SetType;
 use crate::app::state::{AddWorkoutField, WorkoutLogFlags};
+use crate::app::utils::modify_numeric_input;
+use crossterm::event::KeyCode;
 
 #[derive(PartialEq, Eq)]
 pub(super) enum NavigationDirection {
@@ -15,6 +18,9 @@ const FOCUS_ORDER: &[AddWorkoutField] = &[
     AddWorkoutField::Weight,
     AddWorkoutField::Duration,
     AddWorkoutField::Distance,
+    AddWorkoutField::SetType,
+    AddWorkoutField::Rpe,
+    AddWorkoutField::Rir,
     AddWorkoutField::Notes,
     AddWorkoutField::Confirm,
     AddWorkoutField::Cancel,
@@ -26,6 +32,8 @@ const FLAG_DEPENDENT_FIELDS: &[(AddWorkoutField, fn(&WorkoutLogFlags) -> bool)]
     (AddWorkoutField::Weight, |f| f.log_weight),
     (AddWorkoutField::Duration, |f| f.log_duration),
     (AddWorkoutField::Distance, |f| f.log_distance),
+    (AddWorkoutField::Rpe, |f| f.log_effort),
+    (AddWorkoutField::Rir, |f| f.log_effort),
     // Sets and Notes are handled slightly differently (assume always visible if needed)
 ];
 
@@ -66,6 +74,7 @@ pub(super) fn get_next_focusable_field(
                 AddWorkoutField::Weight => flags.log_weight,
                 AddWorkoutField::Duration => flags.log_duration,
                 AddWorkoutField::Distance => flags.log_distance,
+                AddWorkoutField::Rpe | AddWorkoutField::Rir => flags.log_effort,
                 AddWorkoutField::Notes => flags.log_notes, // Check notes flag (assuming always true)
                 // Exercise, Confirm, Cancel are always considered "visible" if generally focusable
                 _ => true,
@@ -91,3 +100,42 @@ pub(super) fn get_next_focusable_field(
 
     current // Fallback: return current field if nothing else found
 }
+
+/// Editing keys for the set type, RPE and RIR fields, shared by the Add and Edit modals.
+/// Navigation keys are left to the caller.
+pub(super) fn edit_set_meta_field(
+    field: AddWorkoutField,
+    key: KeyCode,
+    set_type: &mut Option<SetType>,
+    rpe_input: &mut String,
+    rir_input: &mut String,
+) {
+    match (field, key) {
+        (AddWorkoutField::SetType, KeyCode::Right | KeyCode::Char(' ')) => {
+            *set_type = SetType::cycle(*set_type, true);
+        }
+        (AddWorkoutField::SetType, KeyCode::Left) => *set_type = SetType::cycle(*set_type, false),
+        (AddWorkoutField::SetType, KeyCode::Backspace | KeyCode::Delete) => *set_type = None,
+        (AddWorkoutField::Rpe, KeyCode::Char(c)) if "0123456789.".contains(c) => rpe_input.push(c),
+        (AddWorkoutField::Rpe, KeyCode::Backspace) => {
+            rpe_input.pop();
+        }
+        (AddWorkoutField::Rpe, KeyCode::Up) => {
+            modify_numeric_input(rpe_input, 0.5f64, Some(1.0f64), true)
+        }
+        (AddWorkoutField::Rpe, KeyCode::Down) => {
+            modify_numeric_input(rpe_input, -0.5f64, Some(1.0f64), true)
+        }
+        (AddWorkoutField::Rir, KeyCode::Char(c)) if c.is_ascii_digit() => rir_input.push(c),
+        (AddWorkoutField::Rir, KeyCode::Backspace) => {
+            rir_input.pop();
+        }
+        (AddWorkoutField::Rir, KeyCode::Up) => {
+            modify_numeric_input(rir_input, 1i64, Some(0i64), false)
+        }
+        (AddWorkoutField::Rir, KeyCode::Down) => {
+            modify_numeric_input(rir_input, -1i64, Some(0i64), false)
+        }
+        _ => {}
+    }
+}
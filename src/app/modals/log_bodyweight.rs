@@ -1,3 +1,4 @@
+use crate::app::journal::{BodyweightEntry, Change};
 use crate::app::state::{ActiveModal, App, LogBodyweightField};
 use crate::app::utils::{parse_modal_date, parse_modal_weight};
 use crate::app::AppInputError;
@@ -22,7 +23,17 @@ fn submit_log_bodyweight(
         .ok_or_else(|| AppInputError::InvalidDate("Internal date conversion error".into()))?;
 
     match app.service.add_bodyweight_entry(timestamp, weight) {
-        Ok(_) => Ok(()),
+        Ok(id) => {
+            app.record_change(
+                format!("log bodyweight for {}", date.format("%Y-%m-%d")),
+                vec![Change::AddBodyweight(BodyweightEntry {
+                    id,
+                    timestamp,
+                    weight,
+                })],
+            );
+            Ok(())
+        }
         Err(e) => {
             if let Some(db_err) = e.downcast_ref::<DbError>() {
                 if let DbError::BodyweightEntryExists(_) = db_err {
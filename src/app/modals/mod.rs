@@ -2,23 +2,40 @@
 
 // Declare the modules within the modals folder
 mod add_workout;
+mod aliases;
+mod bulk;
 mod confirm_delete_bodyweight;
+mod confirm_delete_exercise;
 mod confirm_delete_workout_set;
+mod copy_day;
 mod create_exercise;
 mod edit_workout;
+mod graph_range;
 mod input_helpers;
 mod log_bodyweight;
 mod pb_modal;
+mod routines;
+mod session_summary;
 mod set_target_weight;
 
 // Re-export public input handler functions for use by the main app module
 pub use add_workout::handle_add_workout_modal_input;
+pub use aliases::handle_alias_manager_modal_input;
+pub use bulk::{
+    handle_bulk_date_modal_input, handle_bulk_edit_modal_input,
+    handle_confirm_delete_workouts_input,
+};
 pub use confirm_delete_bodyweight::handle_confirm_delete_body_weigth_input;
+pub use confirm_delete_exercise::handle_confirm_delete_exercise_input;
 pub use confirm_delete_workout_set::handle_confirm_delete_modal_input;
+pub use copy_day::handle_copy_day_modal_input;
 pub use create_exercise::handle_create_exercise_modal_input;
 pub use edit_workout::handle_edit_workout_modal_input;
+pub use graph_range::handle_graph_range_modal_input;
 pub use log_bodyweight::handle_log_bodyweight_modal_input;
 pub use pb_modal::handle_pb_modal_input;
+pub use routines::{handle_create_routine_modal_input, handle_routines_modal_input};
+pub use session_summary::handle_session_summary_modal_input;
 pub use set_target_weight::handle_set_target_weight_modal_input;
 
 // No need to re-export submit or parsing functions as they are generally internal
@@ -0,0 +1,197 @@
+// src/app/modals/routines.rs
+
+use crate::app::keymap::{Action, KeyContext};
+use crate::app::navigation_helpers::{ensure_selection_is_valid, list_next, list_previous};
+use crate::app::routines::{parse_routine_spec, Routine};
+use crate::app::state::{ActiveModal, App, CreateRoutineField};
+use crate::app::AppInputError;
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+
+// --- Submission Logic ---
+
+fn submit_create_routine(
+    app: &mut App,
+    name_input: &str,
+    entries_input: &str,
+) -> Result<(), AppInputError> {
+    let name = name_input.trim();
+    if name.is_empty() {
+        return Err(AppInputError::InputEmpty);
+    }
+    if app
+        .routines
+        .routines
+        .iter()
+        .any(|r| r.name.eq_ignore_ascii_case(name))
+    {
+        return Err(AppInputError::DbError(format!(
+            "A routine named '{}' already exists",
+            name
+        )));
+    }
+
+    // Store canonical exercise names so aliases renamed later don't break the routine
+    let mut entries = parse_routine_spec(entries_input)?;
+    for entry in &mut entries {
+        let def = app
+            .service
+            .resolve_exercise_identifier(&entry.exercise)
+            .map_err(|e| AppInputError::DbError(e.to_string()))?
+            .ok_or_else(|| {
+                AppInputError::DbError(format!("Exercise '{}' not found", entry.exercise))
+            })?;
+        entry.exercise = def.name;
+    }
+
+    app.routines.routines.push(Routine {
+        name: name.to_string(),
+        entries,
+    });
+    if let Err(e) = app.routines.save() {
+        app.routines.routines.pop();
+        return Err(AppInputError::DbError(format!(
+            "Failed to save routines: {}",
+            e
+        )));
+    }
+    app.set_info(format!("Saved routine '{}'", name));
+    Ok(())
+}
+
+// --- Input Handling ---
+
+pub fn handle_routines_modal_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    let routine_count = app.routines.routines.len();
+    let mut apply_index = None;
+    let mut delete_index = None;
+
+    let action = app.keymap.action(KeyContext::Routines, key);
+    if let ActiveModal::Routines { ref mut list_state } = app.active_modal {
+        match action {
+            Some(Action::Up) => list_previous(list_state, routine_count),
+            Some(Action::Down) => list_next(list_state, routine_count),
+            Some(Action::Select) => apply_index = list_state.selected(),
+            Some(Action::Delete) => delete_index = list_state.selected(),
+            Some(Action::NewRoutine) => {
+                app.active_modal = ActiveModal::CreateRoutine {
+                    name_input: String::new(),
+                    entries_input: String::new(),
+                    focused_field: CreateRoutineField::Name,
+                    error_message: None,
+                };
+            }
+            Some(Action::Close) => app.active_modal = ActiveModal::None,
+            _ => {}
+        }
+    }
+
+    if let Some(index) = apply_index {
+        app.active_modal = ActiveModal::None;
+        app.apply_routine(index, app.log_viewed_date);
+    } else if let Some(index) = delete_index {
+        app.delete_routine(index);
+        if let ActiveModal::Routines { ref mut list_state } = app.active_modal {
+            ensure_selection_is_valid(list_state, app.routines.routines.len());
+        }
+    }
+    Ok(())
+}
+
+pub fn handle_create_routine_modal_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    let mut name_to_submit = String::new();
+    let mut entries_to_submit = String::new();
+    let mut should_submit = false;
+
+    if let ActiveModal::CreateRoutine {
+        ref mut name_input,
+        ref mut entries_input,
+        ref mut focused_field,
+        ref mut error_message,
+    } = app.active_modal
+    {
+        *error_message = None;
+
+        match focused_field {
+            CreateRoutineField::Name => match key.code {
+                KeyCode::Char(c) => name_input.push(c),
+                KeyCode::Backspace => {
+                    name_input.pop();
+                }
+                KeyCode::Enter | KeyCode::Down | KeyCode::Tab => {
+                    *focused_field = CreateRoutineField::Entries
+                }
+                KeyCode::Up => *focused_field = CreateRoutineField::Cancel,
+                KeyCode::Esc => {
+                    app.open_routines_modal();
+                    return Ok(());
+                }
+                _ => {}
+            },
+            CreateRoutineField::Entries => match key.code {
+                KeyCode::Char(c) => entries_input.push(c),
+                KeyCode::Backspace => {
+                    entries_input.pop();
+                }
+                KeyCode::Enter | KeyCode::Down | KeyCode::Tab => {
+                    *focused_field = CreateRoutineField::Confirm
+                }
+                KeyCode::Up => *focused_field = CreateRoutineField::Name,
+                KeyCode::Esc => {
+                    app.open_routines_modal();
+                    return Ok(());
+                }
+                _ => {}
+            },
+            CreateRoutineField::Confirm => match key.code {
+                KeyCode::Enter => {
+                    should_submit = true;
+                    name_to_submit = name_input.clone();
+                    entries_to_submit = entries_input.clone();
+                }
+                KeyCode::Right | KeyCode::Down | KeyCode::Tab => {
+                    *focused_field = CreateRoutineField::Cancel
+                }
+                KeyCode::Up => *focused_field = CreateRoutineField::Entries,
+                KeyCode::Esc => {
+                    app.open_routines_modal();
+                    return Ok(());
+                }
+                _ => {}
+            },
+            CreateRoutineField::Cancel => match key.code {
+                KeyCode::Enter | KeyCode::Esc => {
+                    app.open_routines_modal();
+                    return Ok(());
+                }
+                KeyCode::Left => *focused_field = CreateRoutineField::Confirm,
+                KeyCode::Up => *focused_field = CreateRoutineField::Entries,
+                KeyCode::Down | KeyCode::Tab => *focused_field = CreateRoutineField::Name,
+                _ => {}
+            },
+        }
+    } // Mutable borrow of app.active_modal ends here
+
+    if should_submit {
+        match submit_create_routine(app, &name_to_submit, &entries_to_submit) {
+            Ok(()) => {
+                // Back to the list with the new routine selected
+                app.open_routines_modal();
+                if let ActiveModal::Routines { ref mut list_state } = app.active_modal {
+                    list_state.select(Some(app.routines.routines.len() - 1));
+                }
+            }
+            Err(err) => {
+                if let ActiveModal::CreateRoutine {
+                    ref mut error_message,
+                    ..
+                } = app.active_modal
+                {
+                    *error_message = Some(err.to_string());
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+// src/app/modals/session_summary.rs
+
+use crate::app::state::{ActiveModal, App};
+use crate::app::AppInputError;
+use crossterm::event::{KeyCode, KeyEvent};
+
+// --- Input Handling ---
+
+pub fn handle_session_summary_modal_input(
+    app: &mut App,
+    key: KeyEvent,
+) -> Result<(), AppInputError> {
+    // Read-only summary, any of the usual close keys dismisses it
+    if let KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q') = key.code {
+        app.active_modal = ActiveModal::None;
+    }
+    Ok(())
+}
@@ -1,5 +1,6 @@
 // src/app/modals/set_target_weight.rs
 
+use crate::app::journal::Change;
 use crate::app::state::{ActiveModal, App, SetTargetWeightField};
 use crate::app::utils::parse_modal_weight;
 use crate::app::AppInputError;
@@ -10,8 +11,18 @@ use crossterm::event::{KeyCode, KeyEvent};
 
 fn submit_set_target_weight(app: &mut App, weight_input: &str) -> Result<(), AppInputError> {
     let weight = parse_modal_weight(weight_input)?;
+    let before = app.service.get_target_bodyweight();
     match app.service.set_target_bodyweight(Some(weight)) {
-        Ok(()) => Ok(()),
+        Ok(()) => {
+            app.record_change(
+                "set target bodyweight",
+                vec![Change::SetTargetBodyweight {
+                    before,
+                    after: Some(weight),
+                }],
+            );
+            Ok(())
+        }
         Err(e) => Err(AppInputError::DbError(format!(
             "Error setting target: {e}" // ConfigError usually doesn't need DbError type
         ))),
@@ -19,8 +30,18 @@ fn submit_set_target_weight(app: &mut App, weight_input: &str) -> Result<(), App
 }
 
 fn submit_clear_target_weight(app: &mut App) -> Result<(), AppInputError> {
+    let before = app.service.get_target_bodyweight();
     match app.service.set_target_bodyweight(None) {
-        Ok(_) => Ok(()),
+        Ok(_) => {
+            app.record_change(
+                "clear target bodyweight",
+                vec![Change::SetTargetBodyweight {
+                    before,
+                    after: None,
+                }],
+            );
+            Ok(())
+        }
         Err(e) => Err(AppInputError::DbError(format!(
             "Error clearing target: {e}"
         ))),
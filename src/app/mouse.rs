@@ -0,0 +1,279 @@
+// src/app/mouse.rs
+//! Mouse support. While drawing a frame the renderers note where the tabs, list rows and
+//! modal buttons ended up, and clicks and the scroll wheel are hit-tested against that.
+use super::keymap::{Action, KeyContext};
+use super::state::{
+    ActiveModal, ActiveTab, AddExerciseField, AddWorkoutField, App, BodyweightFocus, BulkEditField,
+    CalendarFocus, CopyDayField, CreateRoutineField, ExercisesFocus, GraphRangeField, GraphsFocus,
+    LogBodyweightField, LogFocus, PbModalField, SetTargetWeightField,
+};
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+
+/// A list or table whose rows can be clicked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClickList {
+    LogExercises,
+    LogSets,
+    LogPlanned,
+    History,
+    GraphExercises,
+    GraphTypes,
+    Bodyweight,
+    CalendarList,
+    Exercises,
+}
+
+/// A modal button, by what it does rather than its label ("OK", "Save", "Copy"...).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModalButton {
+    Confirm,
+    Clear, // Only in the target bodyweight modal
+    Cancel,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClickTarget {
+    Tab(ActiveTab),
+    Rows { list: ClickList, first: usize }, // One line per row, `first` at the top of the area
+    Item { list: ClickList, index: usize }, // One row filling the area, like a History day
+    Button(ModalButton),
+}
+
+/// Where the clickable parts of the last frame were drawn, in drawing order.
+#[derive(Debug, Default)]
+pub struct ClickAreas {
+    areas: Vec<(Rect, ClickTarget)>,
+}
+
+impl ClickAreas {
+    pub fn clear(&mut self) {
+        self.areas.clear();
+    }
+
+    pub fn push(&mut self, area: Rect, target: ClickTarget) {
+        self.areas.push((area, target));
+    }
+
+    pub fn push_rows(&mut self, area: Rect, list: ClickList, first: usize) {
+        self.push(area, ClickTarget::Rows { list, first });
+    }
+
+    pub fn push_buttons(&mut self, (confirm, cancel): (Rect, Rect)) {
+        self.push(confirm, ClickTarget::Button(ModalButton::Confirm));
+        self.push(cancel, ClickTarget::Button(ModalButton::Cancel));
+    }
+
+    /// The topmost target under a cell, and how many lines below the top of its area the cell is.
+    fn hit(&self, column: u16, row: u16) -> Option<(ClickTarget, usize)> {
+        self.areas
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains(Position { x: column, y: row }))
+            .map(|(area, target)| (*target, usize::from(row - area.y)))
+    }
+}
+
+impl App {
+    pub fn handle_mouse_event(&mut self, mouse: MouseEvent) -> Result<()> {
+        let Some((target, line)) = self.click_areas.hit(mouse.column, mouse.row) else {
+            return Ok(());
+        };
+        // A modal keeps the tab behind it from reacting, only its own buttons can be clicked
+        if self.active_modal != ActiveModal::None {
+            if let (MouseEventKind::Down(MouseButton::Left), ClickTarget::Button(button)) =
+                (mouse.kind, target)
+            {
+                self.click_modal_button(button)?;
+            }
+            return Ok(());
+        }
+
+        let (list, index) = match target {
+            ClickTarget::Tab(tab) => {
+                if mouse.kind == MouseEventKind::Down(MouseButton::Left) {
+                    self.active_tab = tab;
+                }
+                return Ok(());
+            }
+            ClickTarget::Rows { list, first } => (list, first + line),
+            ClickTarget::Item { list, index } => (list, index),
+            ClickTarget::Button(_) => return Ok(()),
+        };
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.click_list_row(list, index),
+            MouseEventKind::ScrollDown => self.scroll_list(list, true),
+            MouseEventKind::ScrollUp => self.scroll_list(list, false),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Selects a clicked row and focuses its pane. Graph lists also graph the row, like Enter.
+    fn click_list_row(&mut self, list: ClickList, index: usize) {
+        if index >= self.click_list_len(list) {
+            return;
+        }
+        if list != ClickList::LogSets && self.log_focus == LogFocus::SetList {
+            self.commit_log_visual_range();
+        }
+        self.select_list_row(list, index);
+        match list {
+            ClickList::LogExercises => self.log_focus = LogFocus::ExerciseList,
+            ClickList::LogSets => self.log_focus = LogFocus::SetList,
+            ClickList::LogPlanned => self.log_focus = LogFocus::Planned,
+            ClickList::History => {}
+            ClickList::GraphExercises => {
+                self.graph_focus = GraphsFocus::ExerciseList;
+                self.graphs_select_exercise();
+            }
+            ClickList::GraphTypes => {
+                self.graph_focus = GraphsFocus::GraphTypeList;
+                self.graphs_select_type();
+            }
+            ClickList::Bodyweight => self.bw_focus = BodyweightFocus::History,
+            ClickList::CalendarList => self.calendar_focus = CalendarFocus::WorkoutList,
+            ClickList::Exercises => self.exercises_focus = ExercisesFocus::List,
+        }
+    }
+
+    /// Moves the selection of a list one row, stopping at the ends instead of wrapping.
+    fn scroll_list(&mut self, list: ClickList, down: bool) {
+        let len = self.click_list_len(list);
+        if len == 0 {
+            return;
+        }
+        // Other exercises have other sets, so a visual range can't outlive the exercise
+        if list == ClickList::LogExercises {
+            self.commit_log_visual_range();
+        }
+        let index = match (self.click_list_selected(list), down) {
+            (Some(i), true) => (i + 1).min(len - 1),
+            (Some(i), false) => i.saturating_sub(1),
+            (None, _) => 0,
+        };
+        self.select_list_row(list, index);
+    }
+
+    fn click_list_len(&self, list: ClickList) -> usize {
+        match list {
+            ClickList::LogExercises => self.log_exercises_today.len(),
+            ClickList::LogSets => self.log_sets_for_selected_exercise.len(),
+            ClickList::LogPlanned => self.planned_sets_for_viewed_date().len(),
+            ClickList::History => self.history_data.len(),
+            ClickList::GraphExercises => self.graph_exercises_all.len(),
+            ClickList::GraphTypes => self.graph_types_available.len(),
+            ClickList::Bodyweight => self.bw_history.len(),
+            ClickList::CalendarList => self.calendar_workouts_filtered.len(),
+            ClickList::Exercises => self.exercises_filtered.len(),
+        }
+    }
+
+    fn click_list_selected(&self, list: ClickList) -> Option<usize> {
+        match list {
+            ClickList::LogExercises => self.log_exercise_list_state.selected(),
+            ClickList::LogSets => self.log_set_table_state.selected(),
+            ClickList::LogPlanned => self.log_planned_list_state.selected(),
+            ClickList::History => self.history_list_state.selected(),
+            ClickList::GraphExercises => self.graph_exercise_list_state.selected(),
+            ClickList::GraphTypes => self.graph_type_list_state.selected(),
+            ClickList::Bodyweight => self.bw_history_state.selected(),
+            ClickList::CalendarList => self.calendar_list_state.selected(),
+            ClickList::Exercises => self.exercises_table_state.selected(),
+        }
+    }
+
+    fn select_list_row(&mut self, list: ClickList, index: usize) {
+        let index = Some(index);
+        match list {
+            ClickList::LogExercises => self.log_exercise_list_state.select(index),
+            ClickList::LogSets => self.log_set_table_state.select(index),
+            ClickList::LogPlanned => self.log_planned_list_state.select(index),
+            ClickList::History => self.history_list_state.select(index),
+            ClickList::GraphExercises => self.graph_exercise_list_state.select(index),
+            ClickList::GraphTypes => self.graph_type_list_state.select(index),
+            ClickList::Bodyweight => self.bw_history_state.select(index),
+            ClickList::CalendarList => self.calendar_list_state.select(index),
+            ClickList::Exercises => self.exercises_table_state.select(index),
+        }
+    }
+
+    /// Focuses the clicked button and presses Enter on it (or Yes/No on a delete confirmation),
+    /// so clicks do what the keyboard does.
+    fn click_modal_button(&mut self, button: ModalButton) -> Result<()> {
+        use ModalButton::{Cancel, Clear, Confirm};
+        match &mut self.active_modal {
+            ActiveModal::LogBodyweight { focused_field, .. } => {
+                *focused_field = match button {
+                    Confirm => LogBodyweightField::Confirm,
+                    Clear | Cancel => LogBodyweightField::Cancel,
+                }
+            }
+            ActiveModal::SetTargetWeight { focused_field, .. } => {
+                *focused_field = match button {
+                    Confirm => SetTargetWeightField::Set,
+                    Clear => SetTargetWeightField::Clear,
+                    Cancel => SetTargetWeightField::Cancel,
+                }
+            }
+            ActiveModal::AddWorkout { focused_field, .. }
+            | ActiveModal::EditWorkout { focused_field, .. } => {
+                *focused_field = match button {
+                    Confirm => AddWorkoutField::Confirm,
+                    Clear | Cancel => AddWorkoutField::Cancel,
+                }
+            }
+            ActiveModal::CreateExercise { focused_field, .. } => {
+                *focused_field = match button {
+                    Confirm => AddExerciseField::Confirm,
+                    Clear | Cancel => AddExerciseField::Cancel,
+                }
+            }
+            ActiveModal::BulkEdit { focused_field, .. } => {
+                *focused_field = match button {
+                    Confirm => BulkEditField::Confirm,
+                    Clear | Cancel => BulkEditField::Cancel,
+                }
+            }
+            ActiveModal::CopyDay { focused_field, .. } => {
+                *focused_field = match button {
+                    Confirm => CopyDayField::Confirm,
+                    Clear | Cancel => CopyDayField::Cancel,
+                }
+            }
+            ActiveModal::CreateRoutine { focused_field, .. } => {
+                *focused_field = match button {
+                    Confirm => CreateRoutineField::Confirm,
+                    Clear | Cancel => CreateRoutineField::Cancel,
+                }
+            }
+            ActiveModal::GraphRange { focused_field, .. } => {
+                *focused_field = match button {
+                    Confirm => GraphRangeField::Confirm,
+                    Clear | Cancel => GraphRangeField::Cancel,
+                }
+            }
+            ActiveModal::PersonalBest { focused_field, .. } => {
+                *focused_field = PbModalField::OkButton
+            }
+            ActiveModal::SessionSummary { .. } => {}
+            // Delete confirmations have no focus; answer them like the y/n keys do.
+            ActiveModal::ConfirmDeleteWorkout { .. }
+            | ActiveModal::ConfirmDeleteBodyWeight { .. }
+            | ActiveModal::ConfirmDeleteExercise { .. }
+            | ActiveModal::ConfirmDeleteWorkouts { .. } => {
+                let action = match button {
+                    Confirm => Action::Confirm,
+                    Clear | Cancel => Action::Cancel,
+                };
+                return match self.keymap.first_key(KeyContext::Confirm, action) {
+                    Some(key) => self.handle_key_event(key.key_event()),
+                    None => Ok(()), // Unbound in keymap.toml
+                };
+            }
+            _ => return Ok(()),
+        }
+        self.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+    }
+}
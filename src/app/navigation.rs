@@ -1,4 +1,5 @@
 use super::{navigation_helpers, state::App};
+use chrono::{Duration, Months};
 use task_athlete_lib::WorkoutFilters; // Keep lib imports
 
 // --- Log Tab Navigation ---
@@ -116,3 +117,81 @@ pub fn history_list_next(app: &mut App) {
 pub fn history_list_previous(app: &mut App) {
     navigation_helpers::list_previous(&mut app.history_list_state, app.history_data.len());
 }
+
+// --- Calendar Tab Navigation ---
+
+pub fn calendar_change_day(app: &mut App, days: i64) {
+    if let Some(new_date) = app
+        .calendar_selected_date
+        .checked_add_signed(Duration::days(days))
+    {
+        app.calendar_selected_date = new_date;
+    }
+}
+
+// Keeps the day of month where possible (Jan 31 -> Feb 28/29)
+pub fn calendar_change_month(app: &mut App, months: i32) {
+    let current = app.calendar_selected_date;
+    let new_date = if months >= 0 {
+        current.checked_add_months(Months::new(months.unsigned_abs()))
+    } else {
+        current.checked_sub_months(Months::new(months.unsigned_abs()))
+    };
+    if let Some(date) = new_date {
+        app.calendar_selected_date = date;
+    }
+}
+
+pub fn calendar_table_next(app: &mut App) {
+    let list_len = app.calendar_workouts_filtered.len();
+    if list_len == 0 {
+        return;
+    }
+    let i = match app.calendar_list_state.selected() {
+        Some(i) if i >= list_len - 1 => 0,
+        Some(i) => i + 1,
+        None => 0,
+    };
+    app.calendar_list_state.select(Some(i));
+}
+
+pub fn calendar_table_previous(app: &mut App) {
+    let list_len = app.calendar_workouts_filtered.len();
+    if list_len == 0 {
+        return;
+    }
+    let i = match app.calendar_list_state.selected() {
+        Some(0) => list_len - 1,
+        Some(i) => i - 1,
+        None => list_len.saturating_sub(1),
+    };
+    app.calendar_list_state.select(Some(i));
+}
+
+// --- Exercises Tab Navigation ---
+
+pub fn exercises_table_next(app: &mut App) {
+    let list_len = app.exercises_filtered.len();
+    if list_len == 0 {
+        return;
+    }
+    let i = match app.exercises_table_state.selected() {
+        Some(i) if i >= list_len - 1 => 0,
+        Some(i) => i + 1,
+        None => 0,
+    };
+    app.exercises_table_state.select(Some(i));
+}
+
+pub fn exercises_table_previous(app: &mut App) {
+    let list_len = app.exercises_filtered.len();
+    if list_len == 0 {
+        return;
+    }
+    let i = match app.exercises_table_state.selected() {
+        Some(0) => list_len - 1,
+        Some(i) => i - 1,
+        None => list_len.saturating_sub(1),
+    };
+    app.exercises_table_state.select(Some(i));
+}
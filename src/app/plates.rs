@@ -0,0 +1,177 @@
+// src/app/plates.rs
+//! Plate calculator for barbell exercises: which plates to load on each side.
+use super::settings::PlateInventory;
+use super::state::App;
+
+const STEPS_PER_UNIT: f64 = 100.0; // Work in hundredths so 1.25 plates add up exactly
+const MAX_STEPS_PER_SIDE: usize = 100_000; // 1000 kg/lbs a side, anything above is a typo
+
+/// Plates for one side of the bar, heaviest first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlateLoad {
+    pub bar_weight: f64,
+    pub per_side: Vec<(f64, u32)>, // Plate weight, count on each side
+    pub loaded: f64,               // Bar plus both sides
+    pub target: f64,
+}
+
+impl PlateLoad {
+    pub fn is_exact(&self) -> bool {
+        (self.loaded - self.target).abs() < 0.5 / STEPS_PER_UNIT
+    }
+
+    pub fn below_bar(&self) -> bool {
+        self.target < self.bar_weight
+    }
+}
+
+fn to_steps(weight: f64) -> usize {
+    (weight * STEPS_PER_UNIT).round().max(0.0) as usize
+}
+
+/// Finds the heaviest load not above `target` that the inventory allows.
+/// Returns `None` when the target is absurdly heavy.
+pub fn calculate_plates(target: f64, inventory: &PlateInventory) -> Option<PlateLoad> {
+    let per_side_target = to_steps((target - inventory.bar_weight).max(0.0) / 2.0);
+    if per_side_target > MAX_STEPS_PER_SIDE {
+        return None;
+    }
+
+    // Every pair is its own item, heaviest first
+    let mut items: Vec<(usize, f64)> = inventory
+        .plates
+        .iter()
+        .filter(|p| p.weight > 0.0)
+        .flat_map(|p| std::iter::repeat_n((to_steps(p.weight), p.weight), p.pairs as usize))
+        .filter(|(steps, _)| *steps > 0)
+        .collect();
+    items.sort_by_key(|item| std::cmp::Reverse(item.0));
+
+    // Load the way people do at the gym: heaviest plate that still fits, repeatedly
+    let mut chosen = Vec::new();
+    let mut loaded_steps = 0;
+    for (item_index, (steps, _)) in items.iter().enumerate() {
+        if loaded_steps + steps <= per_side_target {
+            loaded_steps += steps;
+            chosen.push(item_index);
+        }
+    }
+    if loaded_steps != per_side_target {
+        (chosen, loaded_steps) = closest_combination(&items, per_side_target);
+    }
+
+    let mut per_side: Vec<(f64, u32)> = Vec::new();
+    for item_index in chosen {
+        let weight = items[item_index].1;
+        match per_side.iter_mut().find(|(w, _)| *w == weight) {
+            Some((_, count)) => *count += 1,
+            None => per_side.push((weight, 1)),
+        }
+    }
+    per_side.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    Some(PlateLoad {
+        bar_weight: inventory.bar_weight,
+        per_side,
+        loaded: inventory.bar_weight + 2.0 * loaded_steps as f64 / STEPS_PER_UNIT,
+        target,
+    })
+}
+
+/// When greedy loading misses, a 0/1 knapsack over the plate pairs finds the heaviest
+/// reachable weight not above the target. Returns the item indices used and their sum.
+fn closest_combination(items: &[(usize, f64)], target_steps: usize) -> (Vec<usize>, usize) {
+    // `reached_by[v]` = (item, previous weight), set once so heavier items win ties
+    let mut reached = vec![false; target_steps + 1];
+    let mut reached_by: Vec<Option<(usize, usize)>> = vec![None; target_steps + 1];
+    reached[0] = true;
+    for (item_index, (steps, _)) in items.iter().enumerate() {
+        for v in (*steps..=target_steps).rev() {
+            if !reached[v] && reached[v - steps] {
+                reached[v] = true;
+                reached_by[v] = Some((item_index, v - steps));
+            }
+        }
+    }
+
+    let best = (0..=target_steps).rev().find(|&v| reached[v]).unwrap_or(0);
+    let mut chosen = Vec::new();
+    let mut v = best;
+    while let Some((item_index, previous)) = reached_by[v] {
+        chosen.push(item_index);
+        v = previous;
+    }
+    (chosen, best)
+}
+
+impl App {
+    /// Shows/hides the plate panel in the Add/Edit Workout modals.
+    pub fn toggle_plate_calculator(&mut self) {
+        self.plate_calculator_visible = !self.plate_calculator_visible;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::settings::{PlateSettings, PlateStock};
+
+    fn inventory(bar_weight: f64, plates: &[(f64, u32)]) -> PlateInventory {
+        PlateInventory {
+            bar_weight,
+            plates: plates
+                .iter()
+                .map(|&(weight, pairs)| PlateStock { weight, pairs })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn loads_heaviest_plates_first() {
+        let metric = PlateSettings::default().metric;
+        let load = calculate_plates(100.0, &metric).unwrap();
+        assert_eq!(load.per_side, vec![(25.0, 1), (15.0, 1)]);
+        assert_eq!(load.loaded, 100.0);
+        assert!(load.is_exact());
+    }
+
+    #[test]
+    fn falls_back_to_knapsack_when_greedy_misses() {
+        // Greedy takes the 15 and can't add a 10; two 10s make the 20 a side exactly
+        let inventory = inventory(20.0, &[(15.0, 1), (10.0, 2)]);
+        let load = calculate_plates(60.0, &inventory).unwrap();
+        assert_eq!(load.per_side, vec![(10.0, 2)]);
+        assert!(load.is_exact());
+    }
+
+    #[test]
+    fn stops_at_the_closest_weight_below_the_target() {
+        let metric = PlateSettings::default().metric;
+        let load = calculate_plates(101.0, &metric).unwrap();
+        assert_eq!(load.loaded, 100.0);
+        assert!(!load.is_exact());
+    }
+
+    #[test]
+    fn bar_heavier_than_the_target_loads_nothing() {
+        let metric = PlateSettings::default().metric;
+        let load = calculate_plates(15.0, &metric).unwrap();
+        assert!(load.below_bar());
+        assert!(load.per_side.is_empty());
+        assert_eq!(load.loaded, 20.0);
+    }
+
+    #[test]
+    fn empty_inventory_is_just_the_bar() {
+        let load = calculate_plates(60.0, &inventory(20.0, &[])).unwrap();
+        assert!(load.per_side.is_empty());
+        assert_eq!(load.loaded, 20.0);
+        assert!(!load.is_exact());
+    }
+
+    #[test]
+    fn absurd_targets_are_rejected() {
+        let metric = PlateSettings::default().metric;
+        assert_eq!(calculate_plates(5000.0, &metric), None);
+    }
+}
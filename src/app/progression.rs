@@ -0,0 +1,246 @@
+// src/app/progression.rs
+//! Progressive overload suggestions for the Add Workout modal, derived from the
+//! last few sessions of an exercise.
+use super::set_meta::{SetMeta, SetType};
+use super::settings::{ProgressionScheme, ProgressionSettings};
+use super::state::{ActiveModal, App, WorkoutLogFlags};
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use task_athlete_lib::{ExerciseDefinition, ExerciseType, Workout, WorkoutFilters};
+
+const HISTORY_LIMIT: u32 = 100; // Workout rows fetched to rebuild recent sessions
+const WEIGHT_EPSILON: f64 = 0.01;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuggestionKind {
+    AddWeight, // Every set hit its reps
+    AddRep,    // Double progression, still inside the rep range
+    Repeat,    // Missed reps, try the same again
+    Deload,    // Missed too many times in a row
+}
+
+/// Next target for an exercise, in the same units as the Add Workout weight field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgressionSuggestion {
+    pub exercise_name: String, // Canonical name the suggestion was computed for
+    pub kind: SuggestionKind,
+    pub weight: f64,
+    pub reps: i64,
+    pub reason: String,
+}
+
+/// The top working weight of one day and the reps done with it.
+#[derive(Clone, Debug, PartialEq)]
+struct SessionResult {
+    date: NaiveDate,
+    weight: f64,
+    target_reps: i64, // Reps of the first set at the top weight
+    min_reps: i64,    // Worst set at the top weight
+}
+
+impl SessionResult {
+    fn hit_all_reps(&self) -> bool {
+        self.min_reps >= self.target_reps
+    }
+}
+
+/// Groups workout rows by day (newest first) and keeps each day's top weight, ignoring warm-ups.
+/// `to_input_weight` maps stored weights to what the user types (added weight for bodyweight).
+fn session_results(
+    workouts: &[Workout],
+    to_input_weight: impl Fn(f64) -> f64,
+) -> Vec<SessionResult> {
+    let mut by_day: BTreeMap<NaiveDate, Vec<&Workout>> = BTreeMap::new();
+    for workout in workouts {
+        let (set_meta, _) = SetMeta::parse_notes(workout.notes.as_deref());
+        if workout.reps.is_some()
+            && workout.weight.is_some()
+            && set_meta.set_type != Some(SetType::Warmup)
+        {
+            by_day
+                .entry(workout.timestamp.date_naive())
+                .or_default()
+                .push(workout);
+        }
+    }
+
+    by_day
+        .into_iter()
+        .rev()
+        .filter_map(|(date, mut rows)| {
+            rows.sort_by_key(|w| w.timestamp);
+            let weight = rows
+                .iter()
+                .filter_map(|w| w.weight)
+                .map(&to_input_weight)
+                .fold(f64::MIN, f64::max);
+            let top_reps: Vec<i64> = rows
+                .iter()
+                .filter(|w| {
+                    w.weight
+                        .is_some_and(|wt| (to_input_weight(wt) - weight).abs() < WEIGHT_EPSILON)
+                })
+                .filter_map(|w| w.reps)
+                .collect();
+            Some(SessionResult {
+                date,
+                weight,
+                target_reps: *top_reps.first()?,
+                min_reps: *top_reps.iter().min()?,
+            })
+        })
+        .collect()
+}
+
+/// Rounds to the nearest multiple of the increment, so deloads land on loadable weights.
+fn round_to_increment(weight: f64, increment: f64) -> f64 {
+    if increment > 0.0 {
+        ((weight / increment).round() * increment).max(0.0)
+    } else {
+        weight.max(0.0)
+    }
+}
+
+/// Counts consecutive sessions (newest first) at the latest weight that were misses.
+fn consecutive_misses(sessions: &[SessionResult], scheme: ProgressionScheme) -> usize {
+    let latest_weight = sessions[0].weight;
+    let same_weight: Vec<&SessionResult> = sessions
+        .iter()
+        .take_while(|s| (s.weight - latest_weight).abs() < WEIGHT_EPSILON)
+        .collect();
+    match scheme {
+        ProgressionScheme::Linear => same_weight.iter().take_while(|s| !s.hit_all_reps()).count(),
+        // A double progression session is a miss when the worst set didn't improve
+        ProgressionScheme::Double => same_weight
+            .windows(2)
+            .take_while(|pair| pair[0].min_reps <= pair[1].min_reps)
+            .count(),
+    }
+}
+
+fn suggest(
+    sessions: &[SessionResult],
+    scheme: ProgressionScheme,
+    settings: &ProgressionSettings,
+) -> Option<(SuggestionKind, f64, i64, String)> {
+    let last = sessions.first()?;
+    let increment = settings.weight_increment;
+
+    let misses = consecutive_misses(sessions, scheme);
+    if settings.deload_after_misses > 0 && misses >= settings.deload_after_misses {
+        let weight = round_to_increment(
+            last.weight * (1.0 - settings.deload_percent / 100.0),
+            increment,
+        );
+        let reps = match scheme {
+            ProgressionScheme::Linear => last.target_reps,
+            ProgressionScheme::Double => settings.rep_range_min,
+        };
+        let reason = format!("stalled {} sessions at {:.1}, deload", misses, last.weight);
+        return Some((SuggestionKind::Deload, weight, reps, reason));
+    }
+
+    let since = last.date.format("%m-%d");
+    Some(match scheme {
+        ProgressionScheme::Linear if last.hit_all_reps() => (
+            SuggestionKind::AddWeight,
+            last.weight + increment,
+            last.target_reps,
+            format!("hit {} reps on every set ({})", last.target_reps, since),
+        ),
+        ProgressionScheme::Linear => (
+            SuggestionKind::Repeat,
+            last.weight,
+            last.target_reps,
+            format!("missed reps on {}, repeat", since),
+        ),
+        ProgressionScheme::Double if last.min_reps >= settings.rep_range_max => (
+            SuggestionKind::AddWeight,
+            last.weight + increment,
+            settings.rep_range_min,
+            format!(
+                "top of {}-{} reps reached ({})",
+                settings.rep_range_min, settings.rep_range_max, since
+            ),
+        ),
+        ProgressionScheme::Double => {
+            let reps = (last.min_reps + 1).clamp(settings.rep_range_min, settings.rep_range_max);
+            (
+                SuggestionKind::AddRep,
+                last.weight,
+                reps,
+                format!("worst set was {} reps ({})", last.min_reps, since),
+            )
+        }
+    })
+}
+
+impl App {
+    /// Suggests the next weight/reps for a weight-and-reps exercise, if enabled and
+    /// there is history to go on.
+    pub fn progression_suggestion_for(
+        &self,
+        def: &ExerciseDefinition,
+    ) -> Option<ProgressionSuggestion> {
+        let settings = &self.settings.progression;
+        let flags = WorkoutLogFlags::from_def(Some(def));
+        if !settings.enabled || !flags.log_weight || !flags.log_reps {
+            return None;
+        }
+
+        let filters = WorkoutFilters {
+            exercise_name: Some(&def.name),
+            limit: Some(HISTORY_LIMIT),
+            ..Default::default()
+        };
+        let workouts = self.service.list_workouts(&filters).ok()?;
+
+        // Same conversion as the prefilled weight field: added weight for bodyweight exercises
+        let bodyweight = if def.type_ == ExerciseType::BodyWeight {
+            self.service.config.bodyweight.unwrap_or(0.0)
+        } else {
+            0.0
+        };
+        let sessions: Vec<SessionResult> =
+            session_results(&workouts, |w| (w - bodyweight).max(0.0))
+                .into_iter()
+                .take(settings.history_sessions.max(1))
+                .collect();
+
+        let (kind, weight, reps, reason) =
+            suggest(&sessions, settings.scheme_for(&def.name), settings)?;
+        Some(ProgressionSuggestion {
+            exercise_name: def.name.clone(),
+            kind,
+            weight,
+            reps,
+            reason,
+        })
+    }
+
+    /// Copies the suggestion shown in the Add Workout modal into its reps/weight fields.
+    pub fn accept_progression_suggestion(&mut self) {
+        if let ActiveModal::AddWorkout {
+            ref mut reps_input,
+            ref mut weight_input,
+            ref resolved_exercise,
+            ref progression_suggestion,
+            ..
+        } = self.active_modal
+        {
+            let Some(suggestion) = progression_suggestion.as_ref().filter(|s| {
+                resolved_exercise
+                    .as_ref()
+                    .is_some_and(|def| def.name == s.exercise_name)
+            }) else {
+                return;
+            };
+            *reps_input = suggestion.reps.to_string();
+            *weight_input = if suggestion.weight > 0.0 {
+                format!("{:.1}", suggestion.weight)
+            } else {
+                String::new()
+            };
+        }
+    }
+}
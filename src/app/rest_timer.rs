@@ -0,0 +1,146 @@
+// src/app/rest_timer.rs
+use super::state::App;
+use std::time::{Duration, Instant};
+
+const REST_TIMER_STEP_SECS: u64 = 15; // How much +/- changes the running timer
+const REST_DONE_DISPLAY: Duration = Duration::from_secs(5); // Keep "Rest over" visible this long
+
+/// Countdown between sets, started after a set is logged.
+#[derive(Debug, Clone)]
+pub struct RestTimer {
+    pub exercise_name: String,
+    pub total: Duration,
+    started_at: Instant,
+    paused_elapsed: Option<Duration>, // Frozen elapsed time while paused
+    finished_at: Option<Instant>,
+}
+
+impl RestTimer {
+    pub fn new(exercise_name: String, seconds: u64) -> Self {
+        Self {
+            exercise_name,
+            total: Duration::from_secs(seconds),
+            started_at: Instant::now(),
+            paused_elapsed: None,
+            finished_at: None,
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.paused_elapsed
+            .unwrap_or_else(|| self.started_at.elapsed())
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.total.saturating_sub(self.elapsed())
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_elapsed.is_some()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished_at.is_some()
+    }
+
+    fn toggle_pause(&mut self) {
+        match self.paused_elapsed.take() {
+            Some(elapsed) => self.started_at = Instant::now() - elapsed,
+            None => self.paused_elapsed = Some(self.started_at.elapsed()),
+        }
+    }
+
+    fn adjust(&mut self, extend: bool) {
+        let step = Duration::from_secs(REST_TIMER_STEP_SECS);
+        if extend {
+            self.total += step;
+        } else {
+            self.total = self.total.saturating_sub(step);
+        }
+        if self.remaining() > Duration::ZERO {
+            self.finished_at = None; // Extending a finished timer restarts the countdown
+        }
+    }
+
+    /// Remaining time as `m:ss`.
+    pub fn format_remaining(&self) -> String {
+        let secs = self.remaining().as_secs();
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+impl App {
+    /// Starts (or restarts) the rest timer using the exercise's configured rest length.
+    pub fn start_rest_timer(&mut self, exercise_name: &str) {
+        let settings = &self.settings.rest_timer;
+        if !settings.enabled {
+            return;
+        }
+        let seconds = settings.seconds_for(exercise_name);
+        if seconds > 0 {
+            self.rest_timer = Some(RestTimer::new(exercise_name.to_string(), seconds));
+        }
+    }
+
+    /// Advances the rest timer. Returns true exactly once, when the countdown reaches zero
+    /// and the bell is enabled, so the caller can ring it.
+    pub fn tick_rest_timer(&mut self) -> bool {
+        let Some(timer) = self.rest_timer.as_mut() else {
+            return false;
+        };
+        if let Some(finished_at) = timer.finished_at {
+            if finished_at.elapsed() >= REST_DONE_DISPLAY {
+                self.rest_timer = None;
+            }
+            return false;
+        }
+        if timer.remaining() > Duration::ZERO {
+            return false;
+        }
+        timer.finished_at = Some(Instant::now());
+        let message = format!("Rest over - next set of {}", timer.exercise_name);
+        self.set_info(message);
+        self.settings.rest_timer.bell
+    }
+
+    pub fn rest_timer_toggle_pause(&mut self) {
+        if let Some(timer) = self.rest_timer.as_mut().filter(|t| !t.is_finished()) {
+            timer.toggle_pause();
+        }
+    }
+
+    pub fn rest_timer_adjust(&mut self, extend: bool) {
+        if let Some(timer) = self.rest_timer.as_mut() {
+            timer.adjust(extend);
+        }
+    }
+
+    pub fn rest_timer_skip(&mut self) {
+        self.rest_timer = None;
+    }
+
+    /// Remembers the running timer's length as the default for its exercise.
+    pub fn rest_timer_save_as_default(&mut self) {
+        let Some(timer) = self.rest_timer.as_ref() else {
+            return;
+        };
+        let (name, seconds) = (timer.exercise_name.clone(), timer.total.as_secs());
+        self.settings
+            .rest_timer
+            .per_exercise
+            .insert(name.clone(), seconds);
+        match self.settings.save() {
+            Ok(()) => self.set_info(format!("Rest for {} set to {}s", name, seconds)),
+            Err(e) => self.set_error(format!("Failed to save settings: {}", e)),
+        }
+    }
+
+    /// True while the finished timer should be drawn highlighted, alternating each tick.
+    pub fn rest_timer_flash_on(&self) -> bool {
+        self.settings.rest_timer.flash
+            && self.rest_timer.as_ref().is_some_and(|t| {
+                t.finished_at
+                    .is_some_and(|at| (at.elapsed().as_millis() / 250) % 2 == 0)
+            })
+    }
+}
@@ -0,0 +1,238 @@
+// src/app/routines.rs
+use super::settings::TuiSettings;
+use super::state::{ActiveModal, App, LogFocus};
+use super::AppInputError;
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use ratatui::widgets::ListState;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const ROUTINES_FILE_NAME: &str = "routines.toml";
+
+/// One line of a routine, e.g. "Bench Press 3x5 @ 60".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoutineEntry {
+    pub exercise: String, // Canonical exercise name
+    pub sets: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reps: Option<i64>, // None = as many reps as possible
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>, // As typed in the Add Workout modal
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Routine {
+    pub name: String,
+    #[serde(default)]
+    pub entries: Vec<RoutineEntry>,
+}
+
+/// All saved routines, stored next to the TUI settings file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoutineBook {
+    pub routines: Vec<Routine>,
+}
+
+impl RoutineBook {
+    fn path() -> Option<PathBuf> {
+        TuiSettings::config_dir().map(|dir| dir.join(ROUTINES_FILE_NAME))
+    }
+
+    pub fn load() -> Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path().context("Could not determine the config directory")?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let contents = toml::to_string_pretty(self)?;
+        fs::write(&path, contents).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// A single set planned for a day from a routine, waiting to be logged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedSet {
+    pub routine_name: String,
+    pub exercise_name: String,
+    pub reps: Option<i64>,
+    pub weight: Option<f64>,
+    pub set_number: u32,
+    pub total_sets: u32,
+}
+
+impl RoutineEntry {
+    /// `3x5 @ 60.0`, `3xAMRAP`.
+    pub fn scheme(&self) -> String {
+        let reps = self
+            .reps
+            .map_or_else(|| "AMRAP".to_string(), |r| r.to_string());
+        match self.weight {
+            Some(w) => format!("{}x{} @ {:.1}", self.sets, reps, w),
+            None => format!("{}x{}", self.sets, reps),
+        }
+    }
+}
+
+/// Parses a routine written as comma (or newline) separated entries,
+/// e.g. `bench 3x5 @60, ohp 3x8, dips 3xAMRAP`.
+/// Exercise names are returned as typed, resolve them before saving.
+pub fn parse_routine_spec(spec: &str) -> Result<Vec<RoutineEntry>, AppInputError> {
+    let mut entries = Vec::new();
+    for part in spec.split([',', '\n']).map(str::trim) {
+        if part.is_empty() {
+            continue;
+        }
+        let mut tokens: Vec<&str> = part.split_whitespace().collect();
+
+        // Weight may be glued to the scheme ("3x5@60") or its own token ("3x5 @60")
+        let mut weight = None;
+        if let Some(last) = tokens.last().copied() {
+            if let Some(w) = last.strip_prefix('@') {
+                weight = Some(parse_routine_weight(w)?);
+                tokens.pop();
+            }
+        }
+        let scheme = tokens
+            .pop()
+            .ok_or_else(|| AppInputError::InvalidRoutine(part.to_string()))?;
+        let scheme = match scheme.split_once('@') {
+            Some((scheme, w)) => {
+                weight = Some(parse_routine_weight(w)?);
+                scheme
+            }
+            None => scheme,
+        };
+        if tokens.is_empty() {
+            return Err(AppInputError::InvalidRoutine(part.to_string()));
+        }
+
+        let (sets_str, reps_str) = scheme
+            .to_lowercase()
+            .split_once('x')
+            .map(|(s, r)| (s.to_string(), r.to_string()))
+            .ok_or_else(|| AppInputError::InvalidRoutine(part.to_string()))?;
+        let sets = sets_str
+            .parse::<u32>()
+            .ok()
+            .filter(|s| *s > 0)
+            .ok_or_else(|| AppInputError::InvalidNumber(sets_str.clone()))?;
+        let reps = match reps_str.as_str() {
+            "amrap" | "max" => None,
+            r => Some(
+                r.parse::<i64>()
+                    .map_err(|_| AppInputError::InvalidNumber(r.to_string()))?,
+            ),
+        };
+
+        entries.push(RoutineEntry {
+            exercise: tokens.join(" "),
+            sets,
+            reps,
+            weight,
+        });
+    }
+    if entries.is_empty() {
+        return Err(AppInputError::InputEmpty);
+    }
+    Ok(entries)
+}
+
+fn parse_routine_weight(input: &str) -> Result<f64, AppInputError> {
+    input
+        .parse::<f64>()
+        .ok()
+        .filter(|w| *w >= 0.0)
+        .ok_or_else(|| AppInputError::InvalidNumber(input.to_string()))
+}
+
+impl App {
+    pub fn open_routines_modal(&mut self) {
+        let mut list_state = ListState::default();
+        if !self.routines.routines.is_empty() {
+            list_state.select(Some(0));
+        }
+        self.active_modal = ActiveModal::Routines { list_state };
+    }
+
+    /// Expands a saved routine into planned sets for `date`.
+    pub fn apply_routine(&mut self, routine_index: usize, date: NaiveDate) {
+        let Some(routine) = self.routines.routines.get(routine_index) else {
+            return;
+        };
+        let planned = self.log_planned_sets.entry(date).or_default();
+        let mut added = 0;
+        for entry in &routine.entries {
+            for set_number in 1..=entry.sets {
+                planned.push(PlannedSet {
+                    routine_name: routine.name.clone(),
+                    exercise_name: entry.exercise.clone(),
+                    reps: entry.reps,
+                    weight: entry.weight,
+                    set_number,
+                    total_sets: entry.sets,
+                });
+                added += 1;
+            }
+        }
+        let message = format!(
+            "Planned {} sets from '{}' for {}",
+            added,
+            routine.name,
+            date.format("%Y-%m-%d")
+        );
+        self.set_info(message);
+        self.log_planned_list_state.select(Some(0));
+        self.log_focus = LogFocus::Planned;
+    }
+
+    pub fn delete_routine(&mut self, routine_index: usize) {
+        if routine_index >= self.routines.routines.len() {
+            return;
+        }
+        let removed = self.routines.routines.remove(routine_index);
+        match self.routines.save() {
+            Ok(()) => self.set_info(format!("Deleted routine '{}'", removed.name)),
+            Err(e) => self.set_error(format!("Failed to save routines: {}", e)),
+        }
+    }
+
+    /// Planned sets for the day shown in the Log tab.
+    pub fn planned_sets_for_viewed_date(&self) -> &[PlannedSet] {
+        self.log_planned_sets
+            .get(&self.log_viewed_date)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    pub fn remove_planned_set(&mut self, date: NaiveDate, planned: &PlannedSet) {
+        if let Some(sets) = self.log_planned_sets.get_mut(&date) {
+            if let Some(pos) = sets.iter().position(|p| p == planned) {
+                sets.remove(pos);
+            }
+            if sets.is_empty() {
+                self.log_planned_sets.remove(&date);
+            }
+        }
+    }
+
+    /// Called after a workout is added: drops the planned set it was confirming, if any.
+    pub(crate) fn complete_pending_planned_set(&mut self) {
+        if let Some((date, planned)) = self.log_pending_planned_set.take() {
+            self.remove_planned_set(date, &planned);
+        }
+    }
+}
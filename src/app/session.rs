@@ -0,0 +1,230 @@
+// src/app/session.rs
+use super::data::workout_volume;
+use super::journal::{Change, Recreated};
+use super::state::{ActiveModal, App};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+use task_athlete_lib::{PBInfo, Workout};
+
+/// Per-exercise totals for the current session, in the order exercises were first logged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionExercise {
+    pub name: String,
+    pub sets: i64,
+    pub volume: f64, // kg
+}
+
+/// Snapshot of a finished session, shown in the summary modal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionSummary {
+    pub started_at: DateTime<Local>,
+    pub duration: Duration,
+    pub exercises: Vec<SessionExercise>,
+    pub pbs: Vec<(String, PBInfo)>,
+}
+
+impl SessionSummary {
+    pub fn total_sets(&self) -> i64 {
+        self.exercises.iter().map(|e| e.sets).sum()
+    }
+
+    pub fn total_volume(&self) -> f64 {
+        self.exercises.iter().map(|e| e.volume).sum()
+    }
+}
+
+/// What one counted set added to the totals, so it can be taken out exactly.
+#[derive(Debug, Clone)]
+struct CountedSet {
+    exercise_name: String,
+    sets: i64,
+    volume: f64,
+}
+
+/// A workout in progress, started and finished explicitly from the Log tab.
+/// The totals follow the change journal: sets logged, copied, edited, deleted, undone
+/// and redone while it runs are all counted, keyed by workout id.
+#[derive(Debug, Clone)]
+pub struct WorkoutSession {
+    started_instant: Instant, // Monotonic, for the elapsed time
+    summary: SessionSummary,  // Running totals, duration filled in on finish
+    counted: HashMap<i64, CountedSet>,
+    dropped: HashSet<i64>, // Counted sets since deleted or moved away, counted again on undo
+}
+
+impl WorkoutSession {
+    fn new() -> Self {
+        Self {
+            started_instant: Instant::now(),
+            summary: SessionSummary {
+                started_at: Local::now(),
+                duration: Duration::ZERO,
+                exercises: Vec::new(),
+                pbs: Vec::new(),
+            },
+            counted: HashMap::new(),
+            dropped: HashSet::new(),
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.started_instant.elapsed()
+    }
+
+    pub fn total_sets(&self) -> i64 {
+        self.summary.total_sets()
+    }
+
+    pub fn total_volume(&self) -> f64 {
+        self.summary.total_volume()
+    }
+
+    /// True for dates from the day the session started up to today, so a session that runs
+    /// past midnight keeps counting while sets back-filled on earlier days don't.
+    fn spans(&self, date: NaiveDate) -> bool {
+        let started = self.summary.started_at.with_timezone(&Utc).date_naive();
+        (started..=Utc::now().date_naive()).contains(&date)
+    }
+
+    /// Adds (or with negative numbers takes out) a set's share of an exercise's totals.
+    /// An exercise with no sets left drops out of the summary.
+    fn add_totals(&mut self, exercise_name: &str, sets: i64, volume: f64) {
+        let exercises = &mut self.summary.exercises;
+        match exercises.iter().position(|e| e.name == exercise_name) {
+            Some(index) => {
+                exercises[index].sets += sets;
+                exercises[index].volume += volume;
+                if exercises[index].sets <= 0 {
+                    exercises.remove(index);
+                }
+            }
+            None if sets > 0 => exercises.push(SessionExercise {
+                name: exercise_name.to_string(),
+                sets,
+                volume,
+            }),
+            None => {}
+        }
+    }
+
+    fn count(&mut self, workout: &Workout, volume: f64) {
+        self.uncount(workout.id); // An id is only ever counted once
+        let set = CountedSet {
+            exercise_name: workout.exercise_name.clone(),
+            sets: workout.sets.unwrap_or(1),
+            volume,
+        };
+        self.add_totals(&set.exercise_name, set.sets, set.volume);
+        self.dropped.remove(&workout.id);
+        self.counted.insert(workout.id, set);
+    }
+
+    /// Takes a set back out of the totals. False if it wasn't counted.
+    fn uncount(&mut self, id: i64) -> bool {
+        let Some(set) = self.counted.remove(&id) else {
+            return false;
+        };
+        self.add_totals(&set.exercise_name, -set.sets, -set.volume);
+        true
+    }
+
+    /// Follows one change to the data. A new set counts if it's on one of the session's
+    /// days; an undone or redone one only if it was counted before it went away, so sets
+    /// from before the session stay out.
+    fn apply(&mut self, change: &Change, replayed: bool, volume_of: impl Fn(&Workout) -> f64) {
+        match change {
+            Change::AddWorkout(workout) => {
+                let counts = if replayed {
+                    self.dropped.contains(&workout.id)
+                } else {
+                    self.spans(workout.timestamp.date_naive())
+                };
+                if counts {
+                    self.count(workout, volume_of(workout));
+                }
+            }
+            Change::DeleteWorkout(workout) if self.uncount(workout.id) => {
+                self.dropped.insert(workout.id);
+            }
+            Change::EditWorkout { before, after } => {
+                let was_counted =
+                    self.uncount(before.id) || (replayed && self.dropped.remove(&before.id));
+                if !was_counted {
+                    return;
+                }
+                if self.spans(after.timestamp.date_naive()) {
+                    self.count(after, volume_of(after));
+                } else {
+                    self.dropped.insert(after.id); // Moved off the session's days
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Follows a row that was re-created under a new id.
+    fn remap(&mut self, old_id: i64, new_id: i64) {
+        if let Some(set) = self.counted.remove(&old_id) {
+            self.counted.insert(new_id, set);
+        }
+        if self.dropped.remove(&old_id) {
+            self.dropped.insert(new_id);
+        }
+    }
+
+    fn finish(mut self) -> SessionSummary {
+        self.summary.duration = self.elapsed();
+        self.summary
+    }
+}
+
+/// Formats a duration as `H:MM:SS`.
+pub fn format_elapsed(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{}:{:02}:{:02}", secs / 3600, (secs / 60) % 60, secs % 60)
+}
+
+impl App {
+    /// Starts a session, or finishes the running one and shows its summary.
+    pub fn toggle_session(&mut self) {
+        match self.session.take() {
+            Some(session) => {
+                self.active_modal = ActiveModal::SessionSummary {
+                    summary: session.finish(),
+                };
+            }
+            None => {
+                self.session = Some(WorkoutSession::new());
+                self.set_info("Session started".to_string());
+            }
+        }
+    }
+
+    /// Updates the running session's totals for changes to the data, `replayed` when they
+    /// come from undo or redo. Volume is counted the way the calendar and graphs count it.
+    pub(crate) fn track_session_changes(&mut self, changes: &[Change], replayed: bool) {
+        let Some(session) = self.session.as_mut() else {
+            return;
+        };
+        let volume_settings = &self.settings.volume;
+        for change in changes {
+            session.apply(change, replayed, |w| workout_volume(w, volume_settings));
+        }
+    }
+
+    pub(crate) fn remap_session_set(&mut self, recreated: Recreated) {
+        if let (Some(session), Recreated::Workout { old_id, new_id }) =
+            (self.session.as_mut(), recreated)
+        {
+            session.remap(old_id, new_id);
+        }
+    }
+
+    /// Notes a PB set in the running session, for its summary.
+    pub fn record_session_pb(&mut self, exercise_name: &str, pb: PBInfo) {
+        if let Some(session) = self.session.as_mut() {
+            session.summary.pbs.push((exercise_name.to_string(), pb));
+        }
+    }
+}
@@ -0,0 +1,313 @@
+// src/app/set_meta.rs
+//! RPE/RIR and set-type tags for logged sets. The library has no columns for them yet,
+//! so they are stored at the start of the notes as `{type:warmup rpe:8 rir:2} free text`.
+use super::AppInputError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SetType {
+    Warmup,
+    Working,
+    Drop,
+    Failure,
+    Amrap,
+}
+
+impl SetType {
+    pub const ALL: [SetType; 5] = [
+        SetType::Warmup,
+        SetType::Working,
+        SetType::Drop,
+        SetType::Failure,
+        SetType::Amrap,
+    ];
+
+    /// Name used in the notes encoding and in the settings file.
+    pub fn key(self) -> &'static str {
+        match self {
+            SetType::Warmup => "warmup",
+            SetType::Working => "working",
+            SetType::Drop => "drop",
+            SetType::Failure => "failure",
+            SetType::Amrap => "amrap",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SetType::Warmup => "Warm-up",
+            SetType::Working => "Working",
+            SetType::Drop => "Drop set",
+            SetType::Failure => "Failure",
+            SetType::Amrap => "AMRAP",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.key() == key)
+    }
+
+    /// Cycles untagged -> Warm-up -> ... -> AMRAP -> untagged.
+    pub fn cycle(current: Option<Self>, forward: bool) -> Option<Self> {
+        let len = Self::ALL.len() + 1; // Slot 0 is "untagged"
+        let index = current.map_or(0, |t| {
+            1 + Self::ALL.iter().position(|&a| a == t).unwrap_or(0)
+        });
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        next.checked_sub(1).map(|i| Self::ALL[i])
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SetMeta {
+    pub set_type: Option<SetType>,
+    pub rpe: Option<f64>,
+    pub rir: Option<i64>,
+}
+
+impl SetMeta {
+    pub fn is_empty(&self) -> bool {
+        self.set_type.is_none() && self.rpe.is_none() && self.rir.is_none()
+    }
+
+    /// Splits stored notes into tags and the free text the user typed.
+    /// Notes written before tagging existed may still say "RPE 8", which is picked up too
+    /// and taken out of the text, so saving the set again doesn't store it twice.
+    pub fn parse_notes(notes: Option<&str>) -> (SetMeta, String) {
+        let (mut meta, text) = split_tags(notes);
+        if meta.rpe.is_none() {
+            if let Some((rpe, rest)) = legacy_rpe(&text) {
+                meta.rpe = Some(rpe);
+                return (meta, rest);
+            }
+        }
+        (meta, text)
+    }
+
+    /// Builds the notes to store for these tags and free text.
+    pub fn encode_notes(&self, text: &str) -> Option<String> {
+        let text = text.trim();
+        let mut tags = Vec::new();
+        if let Some(set_type) = self.set_type {
+            tags.push(format!("type:{}", set_type.key()));
+        }
+        if let Some(rpe) = self.rpe {
+            tags.push(format!("rpe:{}", rpe));
+        }
+        if let Some(rir) = self.rir {
+            tags.push(format!("rir:{}", rir));
+        }
+        match (tags.is_empty(), text.is_empty()) {
+            (true, true) => None,
+            (true, false) => Some(text.to_string()),
+            (false, true) => Some(format!("{{{}}}", tags.join(" "))),
+            (false, false) => Some(format!("{{{}}} {}", tags.join(" "), text)),
+        }
+    }
+
+    /// `Warm-up, RPE 8, RIR 2`
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(set_type) = self.set_type {
+            parts.push(set_type.label().to_string());
+        }
+        if let Some(rpe) = self.rpe {
+            parts.push(format!("RPE {}", rpe));
+        }
+        if let Some(rir) = self.rir {
+            parts.push(format!("RIR {}", rir));
+        }
+        parts.join(", ")
+    }
+
+    /// Parses the RPE/RIR modal inputs. Empty inputs mean "not recorded".
+    pub fn from_inputs(
+        set_type: Option<SetType>,
+        rpe_input: &str,
+        rir_input: &str,
+    ) -> Result<SetMeta, AppInputError> {
+        let rpe = match rpe_input.trim() {
+            "" => None,
+            s => Some(
+                s.parse::<f64>()
+                    .ok()
+                    .filter(|r| (1.0..=10.0).contains(r))
+                    .ok_or_else(|| AppInputError::InvalidNumber(format!("RPE '{}' (1-10)", s)))?,
+            ),
+        };
+        let rir = match rir_input.trim() {
+            "" => None,
+            s => Some(
+                s.parse::<i64>()
+                    .ok()
+                    .filter(|r| *r >= 0)
+                    .ok_or_else(|| AppInputError::InvalidNumber(format!("RIR '{}'", s)))?,
+            ),
+        };
+        Ok(SetMeta { set_type, rpe, rir })
+    }
+}
+
+/// Separates the `{...}` tag prefix from the free text, ignoring legacy RPE mentions.
+fn split_tags(notes: Option<&str>) -> (SetMeta, String) {
+    let notes = notes.unwrap_or_default().trim();
+    let mut meta = SetMeta::default();
+    match notes
+        .strip_prefix('{')
+        .and_then(|rest| rest.split_once('}'))
+        .filter(|(tags, _)| parse_tags(tags, &mut meta))
+    {
+        Some((_, text)) => (meta, text.trim().to_string()),
+        None => (SetMeta::default(), notes.to_string()),
+    }
+}
+
+/// Fills `meta` from `type:warmup rpe:8 rir:2`. Returns false if anything is unrecognised,
+/// in which case the braces are treated as ordinary note text.
+fn parse_tags(tags: &str, meta: &mut SetMeta) -> bool {
+    for tag in tags.split_whitespace() {
+        let Some((key, value)) = tag.split_once(':') else {
+            return false;
+        };
+        let parsed = match key {
+            "type" => SetType::from_key(value).map(|t| meta.set_type = Some(t)),
+            "rpe" => value.parse().ok().map(|r| meta.rpe = Some(r)),
+            "rir" => value.parse().ok().map(|r| meta.rir = Some(r)),
+            _ => None,
+        };
+        if parsed.is_none() {
+            return false;
+        }
+    }
+    true
+}
+
+/// Finds "RPE 8" / "rpe:8.5" in free text, returning it and the text without it.
+fn legacy_rpe(text: &str) -> Option<(f64, String)> {
+    let start = text.to_ascii_lowercase().find("rpe")?; // Same byte offsets as `text`
+    let value = text[start + 3..].trim_start_matches([' ', ':', '=']);
+    let number: String = value
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    let rpe = number.parse().ok().filter(|r| (1.0..=10.0).contains(r))?;
+
+    // Drop the separator next to the mention only where it would be left dangling
+    let before = text[..start].trim_end();
+    let after = value[number.len()..].trim_start();
+    let rest = match (before.is_empty(), after.is_empty()) {
+        (true, _) => after
+            .trim_start_matches([',', ';'])
+            .trim_start()
+            .to_string(),
+        (false, true) => before.trim_end_matches([',', ';']).to_string(),
+        (false, false) => {
+            let after = after.trim_start_matches([',', ';']).trim_start();
+            format!("{} {}", before, after)
+        }
+    };
+    Some((rpe, rest))
+}
+
+/// Notes as shown in tables: tags first, then the free text.
+pub fn notes_for_display(notes: Option<&str>) -> String {
+    let (meta, text) = split_tags(notes);
+    match (meta.is_empty(), text.is_empty()) {
+        (true, _) => text,
+        (false, true) => format!("[{}]", meta.describe()),
+        (false, false) => format!("[{}] {}", meta.describe(), text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_and_parse_round_trip() {
+        let meta = SetMeta {
+            set_type: Some(SetType::Warmup),
+            rpe: Some(8.5),
+            rir: Some(2),
+        };
+        let notes = meta.encode_notes(" felt easy ").unwrap();
+        assert_eq!(notes, "{type:warmup rpe:8.5 rir:2} felt easy");
+        assert_eq!(
+            SetMeta::parse_notes(Some(&notes)),
+            (meta, "felt easy".to_string())
+        );
+    }
+
+    #[test]
+    fn tags_or_text_alone_round_trip() {
+        let meta = SetMeta {
+            rir: Some(0),
+            ..Default::default()
+        };
+        let notes = meta.encode_notes("").unwrap();
+        assert_eq!(SetMeta::parse_notes(Some(&notes)), (meta, String::new()));
+
+        let notes = SetMeta::default().encode_notes("just text").unwrap();
+        assert_eq!(
+            SetMeta::parse_notes(Some(&notes)),
+            (SetMeta::default(), "just text".to_string())
+        );
+    }
+
+    #[test]
+    fn empty_notes_encode_to_none() {
+        assert_eq!(SetMeta::default().encode_notes("  "), None);
+        assert_eq!(
+            SetMeta::parse_notes(None),
+            (SetMeta::default(), String::new())
+        );
+    }
+
+    #[test]
+    fn unknown_tags_stay_in_the_text() {
+        let notes = "{superset with rows} felt good";
+        assert_eq!(
+            SetMeta::parse_notes(Some(notes)),
+            (SetMeta::default(), notes.to_string())
+        );
+    }
+
+    #[test]
+    fn legacy_rpe_is_taken_out_of_the_text() {
+        let (meta, text) = SetMeta::parse_notes(Some("Felt heavy, RPE 8"));
+        assert_eq!(meta.rpe, Some(8.0));
+        assert_eq!(text, "Felt heavy");
+
+        let (meta, text) = SetMeta::parse_notes(Some("rpe:9.5; grind on the last rep"));
+        assert_eq!(meta.rpe, Some(9.5));
+        assert_eq!(text, "grind on the last rep");
+
+        // Saving it again stores the tag instead of the mention
+        let notes = meta.encode_notes(&text).unwrap();
+        assert_eq!(notes, "{rpe:9.5} grind on the last rep");
+        assert_eq!(SetMeta::parse_notes(Some(&notes)), (meta, text));
+    }
+
+    #[test]
+    fn out_of_range_legacy_rpe_is_left_alone() {
+        let (meta, text) = SetMeta::parse_notes(Some("RPE 12 somehow"));
+        assert_eq!(meta.rpe, None);
+        assert_eq!(text, "RPE 12 somehow");
+    }
+
+    #[test]
+    fn modal_inputs_are_validated() {
+        assert_eq!(
+            SetMeta::from_inputs(None, " 7 ", "").unwrap(),
+            SetMeta {
+                rpe: Some(7.0),
+                ..Default::default()
+            }
+        );
+        assert!(SetMeta::from_inputs(None, "11", "").is_err());
+        assert!(SetMeta::from_inputs(None, "", "-1").is_err());
+    }
+}
@@ -0,0 +1,275 @@
+// src/app/settings.rs
+//! TUI-only preferences. The library owns its own config (units, bodyweight, sync...),
+//! this file holds things only the terminal UI cares about.
+use super::set_meta::SetType;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use task_athlete_lib::Units;
+
+const SETTINGS_DIR_ENV_VAR: &str = "TASK_ATHLETE_TUI_CONFIG_DIR";
+const SETTINGS_DIR_NAME: &str = "task-athlete-tui";
+const SETTINGS_FILE_NAME: &str = "settings.toml";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TuiSettings {
+    pub rest_timer: RestTimerSettings,
+    pub progression: ProgressionSettings,
+    pub plates: PlateSettings,
+    pub volume: VolumeSettings,
+    pub warmup: WarmupSettings,
+    pub theme: ThemeSettings,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RestTimerSettings {
+    pub enabled: bool,
+    pub default_seconds: u64,
+    pub bell: bool,                         // Ring the terminal bell when rest is over
+    pub flash: bool,                        // Flash the Log tab header when rest is over
+    pub per_exercise: HashMap<String, u64>, // Canonical exercise name -> rest seconds
+}
+
+impl Default for RestTimerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            default_seconds: 90,
+            bell: true,
+            flash: true,
+            per_exercise: HashMap::new(),
+        }
+    }
+}
+
+impl RestTimerSettings {
+    pub fn seconds_for(&self, exercise_name: &str) -> u64 {
+        self.per_exercise
+            .get(exercise_name)
+            .copied()
+            .unwrap_or(self.default_seconds)
+    }
+}
+
+/// How the Add Workout modal proposes the next target for an exercise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgressionScheme {
+    Linear, // Add weight once every set hits its reps
+    Double, // Add reps up to the top of the range, then add weight
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProgressionSettings {
+    pub enabled: bool,
+    pub scheme: ProgressionScheme,
+    pub weight_increment: f64, // Same units as the Add Workout weight field
+    pub rep_range_min: i64,    // Double progression only
+    pub rep_range_max: i64,
+    pub deload_after_misses: usize, // 0 disables deloads
+    pub deload_percent: f64,
+    pub history_sessions: usize, // How many past sessions are looked at
+    pub per_exercise: HashMap<String, ProgressionScheme>, // Canonical exercise name -> scheme
+}
+
+impl Default for ProgressionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            scheme: ProgressionScheme::Linear,
+            weight_increment: 2.5,
+            rep_range_min: 8,
+            rep_range_max: 12,
+            deload_after_misses: 3,
+            deload_percent: 10.0,
+            history_sessions: 6,
+            per_exercise: HashMap::new(),
+        }
+    }
+}
+
+impl ProgressionSettings {
+    pub fn scheme_for(&self, exercise_name: &str) -> ProgressionScheme {
+        self.per_exercise
+            .get(exercise_name)
+            .copied()
+            .unwrap_or(self.scheme)
+    }
+}
+
+/// Bar and plates available for the plate calculator, one set per unit system.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlateSettings {
+    pub show_by_default: bool, // Open the panel with the Add/Edit Workout modal
+    pub metric: PlateInventory,
+    pub imperial: PlateInventory,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlateInventory {
+    pub bar_weight: f64,
+    pub plates: Vec<PlateStock>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlateStock {
+    pub weight: f64,
+    pub pairs: u32, // Plates come in pairs, one for each side
+}
+
+impl PlateInventory {
+    fn new(bar_weight: f64, plates: &[(f64, u32)]) -> Self {
+        Self {
+            bar_weight,
+            plates: plates
+                .iter()
+                .map(|&(weight, pairs)| PlateStock { weight, pairs })
+                .collect(),
+        }
+    }
+}
+
+impl Default for PlateSettings {
+    fn default() -> Self {
+        Self {
+            show_by_default: false,
+            metric: PlateInventory::new(
+                20.0,
+                &[
+                    (25.0, 4),
+                    (20.0, 2),
+                    (15.0, 2),
+                    (10.0, 2),
+                    (5.0, 2),
+                    (2.5, 2),
+                    (1.25, 2),
+                ],
+            ),
+            imperial: PlateInventory::new(
+                45.0,
+                &[
+                    (45.0, 4),
+                    (35.0, 2),
+                    (25.0, 2),
+                    (10.0, 2),
+                    (5.0, 2),
+                    (2.5, 2),
+                ],
+            ),
+        }
+    }
+}
+
+impl PlateSettings {
+    pub fn for_units(&self, units: Units) -> &PlateInventory {
+        match units {
+            Units::Metric => &self.metric,
+            Units::Imperial => &self.imperial,
+        }
+    }
+}
+
+/// How much each set type counts towards volume totals and graphs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolumeSettings {
+    pub set_type_weights: HashMap<String, f64>, // Set type key -> factor, missing = 1.0
+}
+
+impl Default for VolumeSettings {
+    fn default() -> Self {
+        Self {
+            set_type_weights: HashMap::from([(SetType::Warmup.key().to_string(), 0.0)]),
+        }
+    }
+}
+
+impl VolumeSettings {
+    pub fn factor_for(&self, set_type: Option<SetType>) -> f64 {
+        set_type
+            .and_then(|t| self.set_type_weights.get(t.key()).copied())
+            .unwrap_or(1.0)
+    }
+}
+
+/// Warm-up ramp generated from the working weight in the Add Workout modal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WarmupSettings {
+    pub steps: Vec<WarmupStep>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WarmupStep {
+    pub percent: f64, // Of the working weight, 0 = empty bar
+    pub reps: i64,
+}
+
+impl Default for WarmupSettings {
+    fn default() -> Self {
+        Self {
+            steps: [(0.0, 10), (40.0, 5), (60.0, 3), (80.0, 1)]
+                .into_iter()
+                .map(|(percent, reps)| WarmupStep { percent, reps })
+                .collect(),
+        }
+    }
+}
+
+/// Which theme colors the UI, see `theme.rs`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeSettings {
+    pub name: String, // Built-in theme or a table of themes.toml
+}
+
+impl Default for ThemeSettings {
+    fn default() -> Self {
+        Self {
+            name: "dark".to_string(),
+        }
+    }
+}
+
+impl TuiSettings {
+    /// `$TASK_ATHLETE_TUI_CONFIG_DIR/settings.toml`, or the platform config dir.
+    pub fn config_dir() -> Option<PathBuf> {
+        match std::env::var_os(SETTINGS_DIR_ENV_VAR) {
+            Some(dir) => Some(PathBuf::from(dir)),
+            None => dirs::config_dir().map(|dir| dir.join(SETTINGS_DIR_NAME)),
+        }
+    }
+
+    fn path() -> Option<PathBuf> {
+        Self::config_dir().map(|dir| dir.join(SETTINGS_FILE_NAME))
+    }
+
+    /// Loads the settings file, falling back to defaults if it doesn't exist yet.
+    pub fn load() -> Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path().context("Could not determine the config directory")?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let contents = toml::to_string_pretty(self)?;
+        fs::write(&path, contents).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
@@ -4,7 +4,27 @@ use ratatui::{
     text::Line,
     widgets::{ListState, TableState},
 };
+use std::collections::{HashMap, HashSet};
 use std::time::Instant;
+use tokio::sync::mpsc;
+
+use super::exercises::ExerciseUsage;
+use super::goal::GoalProjection;
+use super::graph_range::GraphRange;
+use super::journal::Journal;
+use super::keymap::Keymap;
+use super::mouse::ClickAreas;
+use super::progression::ProgressionSuggestion;
+use super::rest_timer::RestTimer;
+use super::routines::{PlannedSet, RoutineBook};
+use super::session::{SessionSummary, WorkoutSession};
+use super::set_meta::SetType;
+use super::settings::TuiSettings;
+use super::suggestions::{ExerciseIdentifier, ExerciseSuggestion};
+use super::sync_actions::SyncResult;
+use super::theme::Theme;
+use super::trends::{TrendOverlays, Trends};
+use super::workout_filter::WorkoutQuery;
 use task_athlete_lib::{
     AppService, ExerciseDefinition, ExerciseType, GraphType, PBInfo, Workout, WorkoutFilters,
 }; // Keep lib imports
@@ -16,6 +36,8 @@ pub enum ActiveTab {
     History,
     Graphs,
     Bodyweight,
+    Calendar,
+    Exercises,
 }
 
 // Represents which pane has focus in a multi-pane tab
@@ -23,6 +45,7 @@ pub enum ActiveTab {
 pub enum LogFocus {
     ExerciseList,
     SetList,
+    Planned, // Only reachable while the viewed day has planned sets
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -74,6 +97,9 @@ pub enum AddWorkoutField {
     Weight,
     Duration,
     Distance,
+    SetType, // Cycled with ←/→, not typed
+    Rpe,
+    Rir,
     Notes,
     Confirm,
     Cancel,
@@ -91,11 +117,91 @@ pub enum HistoryFocus {
     DayList, // The main list showing days
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExercisesFocus {
+    SearchInput,
+    List,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CalendarView {
+    CalendarView, // Month grid
+    ListView,     // Flat table of workouts across all dates
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CalendarFocus {
+    FilterInput,
+    WorkoutList,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PbModalField {
     OkButton,
 }
 
+/// What the date prompt does with the marked sets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BulkDateAction {
+    Copy,
+    Move,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BulkEditField {
+    Weight,
+    Reps,
+    Confirm,
+    Cancel,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopyDayField {
+    Date,
+    Sets, // Row selection, typing edits the selected row's weight
+    Confirm,
+    Cancel,
+}
+
+/// One set of the day being copied, as reviewed before it's logged again.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CopyDaySet {
+    pub workout: Workout,
+    pub weight_input: String,
+    pub include: bool,
+}
+
+/// One line of the Graphs tab chart: a pinned series or the current selection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphSeries {
+    pub exercise: String,
+    pub graph_type: GraphType,
+    pub points: Vec<(f64, f64)>, // Days since the chart's first point, value (% of max if normalised)
+    pub max: f64,                // Best value in the range, in the series' own unit
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphRangeField {
+    Start,
+    End,
+    Confirm,
+    Cancel,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AliasManagerField {
+    Input, // New alias text box
+    List,  // Existing aliases
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CreateRoutineField {
+    Name,
+    Entries,
+    Confirm,
+    Cancel,
+}
+
 // NEW: Helper struct for workout field visibility flags
 #[derive(Debug, Clone, Copy, Default)]
 pub struct WorkoutLogFlags {
@@ -104,6 +210,7 @@ pub struct WorkoutLogFlags {
     pub log_weight: bool,
     pub log_duration: bool,
     pub log_distance: bool,
+    pub log_effort: bool, // RPE/RIR, only meaningful for sets with reps
     pub log_notes: bool,
 }
 
@@ -119,6 +226,7 @@ impl WorkoutLogFlags {
                 log_weight: true,
                 log_duration: true,
                 log_distance: true,
+                log_effort: true,
                 log_notes: true,
             },
             |d| Self {
@@ -127,6 +235,7 @@ impl WorkoutLogFlags {
                 log_weight: d.log_weight,
                 log_duration: d.log_duration,
                 log_distance: d.log_distance,
+                log_effort: d.log_reps,
                 log_notes: true, // Assume notes are always logged
             },
         )
@@ -156,16 +265,21 @@ pub enum ActiveModal {
         weight_input: String, // Added weight for bodyweight, direct for others
         duration_input: String,
         distance_input: String,
-        notes_input: String,
+        set_type: Option<SetType>,
+        rpe_input: String,
+        rir_input: String,
+        notes_input: String, // Free text only, tags are stored separately (see set_meta)
         focused_field: AddWorkoutField,
         error_message: Option<String>,
-        all_exercise_identifiers: Vec<String>,
+        all_exercise_identifiers: Vec<ExerciseIdentifier>,
         // Holds the currently filtered suggestions based on input
-        exercise_suggestions: Vec<String>,
+        exercise_suggestions: Vec<ExerciseSuggestion>,
         // State for navigating the suggestion list
         suggestion_list_state: ListState,
         // Store the resolved definition temporarily after user leaves exercise field
         resolved_exercise: Option<ExerciseDefinition>,
+        // Next target from recent history, accepted with Ctrl+A
+        progression_suggestion: Option<ProgressionSuggestion>,
     },
     CreateExercise {
         name_input: String,
@@ -178,6 +292,7 @@ pub enum ActiveModal {
         log_reps: bool,
         log_duration: bool,
         log_distance: bool,
+        editing: Option<String>, // Canonical name when editing an existing definition
     },
     EditWorkout {
         workout_id: u64,       // ID of the workout being edited
@@ -187,6 +302,9 @@ pub enum ActiveModal {
         weight_input: String,
         duration_input: String,
         distance_input: String,
+        set_type: Option<SetType>,
+        rpe_input: String,
+        rir_input: String,
         notes_input: String,
         focused_field: AddWorkoutField, // Reuse AddWorkoutField for focus, minus Exercise/Suggestions
         error_message: Option<String>,
@@ -198,15 +316,68 @@ pub enum ActiveModal {
         exercise_name: String,
         set_index: usize, // For display purposes ("Delete set X of Y?")
     },
+    ConfirmDeleteWorkouts {
+        workouts: Vec<Workout>, // Marked sets, listed in the confirmation
+    },
+    BulkDate {
+        action: BulkDateAction,
+        workouts: Vec<Workout>,
+        date_input: String,
+        error_message: Option<String>,
+    },
+    BulkEdit {
+        workouts: Vec<Workout>,
+        weight_input: String, // "+2.5" / "-5" adjust, "60" sets, empty leaves as is
+        reps_input: String,
+        focused_field: BulkEditField,
+        error_message: Option<String>,
+    },
+    CopyDay {
+        source_date: NaiveDate,
+        sets: Vec<CopyDaySet>,
+        date_input: String,
+        selected: usize,
+        focused_field: CopyDayField,
+        error_message: Option<String>,
+    },
+    GraphRange {
+        start_input: String, // Empty for no lower bound
+        end_input: String,   // Empty for no upper bound
+        focused_field: GraphRangeField,
+        error_message: Option<String>,
+    },
     ConfirmDeleteBodyWeight {
         body_weight_id: u64,
         set_index: usize, // For display purposes ("Delete set X of Y?")
     },
+    ConfirmDeleteExercise {
+        exercise_name: String,
+        workout_count: usize, // Logged workouts referencing the exercise
+    },
+    AliasManager {
+        exercise_name: String, // Canonical name the aliases point to
+        alias_input: String,
+        list_state: ListState, // Selected alias in app.exercises_aliases[exercise_name]
+        focused_field: AliasManagerField,
+        error_message: Option<String>,
+    },
     PersonalBest {
         exercise_name: String,
         pb_info: PBInfo, // Store the PB details
         focused_field: PbModalField,
     },
+    SessionSummary {
+        summary: SessionSummary,
+    },
+    Routines {
+        list_state: ListState, // Selected routine in the saved routines list
+    },
+    CreateRoutine {
+        name_input: String,
+        entries_input: String, // e.g. "bench 3x5 @60, ohp 3x8, dips 3xAMRAP"
+        focused_field: CreateRoutineField,
+        error_message: Option<String>,
+    },
 }
 
 // Holds the application state
@@ -217,6 +388,28 @@ pub struct App {
     pub active_modal: ActiveModal,
     pub last_error: Option<String>, // For status bar errors
     pub error_clear_time: Option<Instant>,
+    pub last_info: Option<String>, // For status bar notices (e.g. sync results)
+    pub info_clear_time: Option<Instant>,
+
+    // === Sync State ===
+    pub sync_receiver: Option<mpsc::Receiver<SyncResult>>, // Some while a sync is running
+    pub sync_started_at: Option<Instant>,
+
+    // === TUI Settings ===
+    pub settings: TuiSettings,
+    pub routines: RoutineBook,          // Saved routines, loaded at startup
+    pub plate_calculator_visible: bool, // Plate panel in the Add/Edit Workout modals
+    pub journal: Journal,               // Undo/redo history of data changes
+    pub keymap: Keymap,                 // Tab keybindings, defaults plus keymap.toml
+    pub help_scroll: u16,               // First line shown in the help modal
+    pub theme: Theme,                   // Colors of every tab and modal
+    pub click_areas: ClickAreas,        // Where tabs, rows and buttons were drawn last frame
+
+    // === Rest Timer State ===
+    pub rest_timer: Option<RestTimer>, // Running (or just finished) countdown between sets
+
+    // === Workout Session State ===
+    pub session: Option<WorkoutSession>, // Some between starting and finishing a session
 
     // === Log Tab State ===
     pub log_focus: LogFocus,
@@ -225,6 +418,11 @@ pub struct App {
     pub log_exercise_list_state: ListState,
     pub log_sets_for_selected_exercise: Vec<Workout>,
     pub log_set_table_state: TableState,
+    pub log_planned_sets: HashMap<NaiveDate, Vec<PlannedSet>>, // Sets planned from routines, per day
+    pub log_planned_list_state: ListState,
+    pub log_pending_planned_set: Option<(NaiveDate, PlannedSet)>, // Being confirmed in AddWorkout
+    pub log_marked_sets: HashSet<i64>, // Workout ids marked for bulk operations
+    pub log_visual_anchor: Option<usize>, // Set row where visual marking started
 
     // === History Tab State ===
     pub history_focus: HistoryFocus,
@@ -240,11 +438,18 @@ pub struct App {
     pub graph_type_list_state: ListState, // State for graph type list widget
     pub graph_selected_exercise: Option<String>, // Name of the selected exercise
     pub graph_selected_type: Option<GraphType>, // Selected graph type enum
-    pub graph_data_points: Vec<(f64, f64)>, // Processed data for the chart
+    pub graph_pinned: Vec<(String, GraphType)>, // Series kept on the chart, in pin order
+    pub graph_series: Vec<GraphSeries>,   // Pinned series, then the selection if not pinned
+    pub graph_overlays: TrendOverlays,    // Average/trend line toggles
+    pub graph_trends: Trends,             // Overlays of the series drawn on top
+    pub graph_first_date: Option<NaiveDate>, // Date at x = 0
+    pub graph_cursor: Option<usize>,      // Point of the top series being read, in cursor mode
+    pub graph_normalised: bool,           // Series measure different things, drawn as % of max
     pub graph_x_bounds: [f64; 2],         // X-axis bounds for the chart
     pub graph_y_bounds: [f64; 2],
     pub graph_start_date_filter: Option<NaiveDate>,
     pub graph_end_date_filter: Option<NaiveDate>,
+    pub graph_range: GraphRange, // Preset or custom range behind the date filters
 
     // === Bodyweight Tab State ===
     pub bw_focus: BodyweightFocus,
@@ -256,6 +461,33 @@ pub struct App {
     pub bw_graph_x_bounds: [f64; 2],
     pub bw_graph_y_bounds: [f64; 2],
     pub bw_graph_range_months: u32,
+    pub bw_graph_first_date: Option<NaiveDate>, // Date at x = 0
+    pub bw_graph_cursor: Option<usize>,         // Index into bw_graph_data, in cursor mode
+    pub bw_overlays: TrendOverlays,
+    pub bw_trends: Trends,
+    pub bw_projection: Option<GoalProjection>, // None without a target or recent weigh-ins
+    pub bw_projection_line: Vec<(f64, f64)>,   // Dashes towards the target, while on track
+
+    // === Calendar Tab State ===
+    pub calendar_view: CalendarView,
+    pub calendar_focus: CalendarFocus,
+    pub calendar_selected_date: NaiveDate,
+    pub calendar_day_volumes: HashMap<NaiveDate, f64>, // Volume per trained day in the viewed month
+    pub calendar_volumes_month: Option<(i32, u32)>, // Month the volumes are for, None after a write
+    pub calendar_filter_input: String,
+    pub calendar_filter_applied: String,
+    pub calendar_filter_query: WorkoutQuery, // Parsed form of calendar_filter_applied
+    pub calendar_workouts_filtered: Vec<Workout>,
+    pub calendar_list_state: TableState,
+
+    // === Exercises Tab State ===
+    pub exercises_focus: ExercisesFocus,
+    pub exercises_all: Vec<ExerciseDefinition>,
+    pub exercises_aliases: HashMap<String, Vec<String>>, // Canonical name -> aliases
+    pub exercises_filtered: Vec<ExerciseDefinition>,     // Matching the search, best first
+    pub exercises_search_input: String,
+    pub exercises_table_state: TableState,
+    pub exercises_selected_usage: Option<ExerciseUsage>,
 }
 
 impl App {
@@ -263,6 +495,22 @@ impl App {
         let today = chrono::Utc::now().date_naive();
         let exercises = service.list_exercises(None, None).unwrap_or_default();
         let exercises_names = exercises.iter().map(|e| e.name.clone()).collect();
+        let (settings, settings_error) = match TuiSettings::load() {
+            Ok(settings) => (settings, None),
+            Err(e) => (TuiSettings::default(), Some(format!("{:#}", e))),
+        };
+        let (routines, routines_error) = match RoutineBook::load() {
+            Ok(routines) => (routines, None),
+            Err(e) => (RoutineBook::default(), Some(format!("{:#}", e))),
+        };
+        let (keymap, keymap_error) = match Keymap::load() {
+            Ok(keymap) => (keymap, None),
+            Err(e) => (Keymap::default(), Some(format!("{:#}", e))),
+        };
+        let (theme, theme_error) = match Theme::load(&settings.theme) {
+            Ok(theme) => (theme, None),
+            Err(e) => (Theme::default(), Some(format!("{:#}", e))),
+        };
         let mut app = App {
             active_tab: ActiveTab::Log,
             should_quit: false,
@@ -274,6 +522,11 @@ impl App {
             log_exercise_list_state: ListState::default(),
             log_sets_for_selected_exercise: Vec::new(),
             log_set_table_state: TableState::default(),
+            log_planned_sets: HashMap::new(),
+            log_planned_list_state: ListState::default(),
+            log_pending_planned_set: None,
+            log_marked_sets: HashSet::new(),
+            log_visual_anchor: None,
             // --- History Tab State ---
             history_focus: HistoryFocus::DayList,
             history_data: Vec::new(),
@@ -296,11 +549,18 @@ impl App {
             graph_type_list_state: ListState::default(),
             graph_selected_exercise: None,
             graph_selected_type: None,
-            graph_data_points: Vec::new(),
+            graph_pinned: Vec::new(),
+            graph_series: Vec::new(),
+            graph_overlays: TrendOverlays::default(),
+            graph_trends: Trends::default(),
+            graph_first_date: None,
+            graph_cursor: None,
+            graph_normalised: false,
             graph_x_bounds: [0.0, 1.0], // Default bounds
             graph_y_bounds: [0.0, 1.0], // Default bounds
             graph_start_date_filter: None,
             graph_end_date_filter: None,
+            graph_range: GraphRange::All,
             // --- Bodyweight Tab State ---
             bw_focus: BodyweightFocus::History,
             bw_history: Vec::new(),
@@ -311,11 +571,59 @@ impl App {
             bw_graph_x_bounds: [0.0, 1.0],
             bw_graph_y_bounds: [0.0, 1.0],
             bw_graph_range_months: 3,
+            bw_graph_first_date: None,
+            bw_graph_cursor: None,
+            bw_overlays: TrendOverlays::default(),
+            bw_trends: Trends::default(),
+            bw_projection: None,
+            bw_projection_line: Vec::new(),
+            // --- Calendar Tab State ---
+            calendar_view: CalendarView::CalendarView,
+            calendar_focus: CalendarFocus::WorkoutList,
+            calendar_selected_date: today,
+            calendar_day_volumes: HashMap::new(),
+            calendar_volumes_month: None,
+            calendar_filter_input: String::new(),
+            calendar_filter_applied: String::new(),
+            calendar_filter_query: WorkoutQuery::default(),
+            calendar_workouts_filtered: Vec::new(),
+            calendar_list_state: TableState::default(),
+            // --- Exercises Tab State ---
+            exercises_focus: ExercisesFocus::List,
+            exercises_all: Vec::new(),
+            exercises_aliases: HashMap::new(),
+            exercises_filtered: Vec::new(),
+            exercises_search_input: String::new(),
+            exercises_table_state: TableState::default(),
+            exercises_selected_usage: None,
             // --- General State ---
             last_error: None,
             error_clear_time: None,
+            last_info: None,
+            info_clear_time: None,
+            // --- Sync State ---
+            sync_receiver: None,
+            sync_started_at: None,
+            // --- Settings & Rest Timer ---
+            plate_calculator_visible: settings.plates.show_by_default,
+            settings,
+            routines,
+            journal: Journal::default(),
+            keymap,
+            help_scroll: 0,
+            theme,
+            click_areas: ClickAreas::default(),
+            rest_timer: None,
+            session: None,
             service,
         };
+        if let Some(err) = settings_error
+            .or(routines_error)
+            .or(keymap_error)
+            .or(theme_error)
+        {
+            app.set_error(err);
+        }
         app.log_exercise_list_state.select(Some(0));
         app.log_set_table_state.select(Some(0));
         app.bw_history_state.select(Some(0));
@@ -333,6 +641,13 @@ impl App {
             Some(Instant::now() + chrono::Duration::seconds(5).to_std().unwrap());
     }
 
+    // Method to set non-error status bar messages
+    pub fn set_info(&mut self, msg: String) {
+        self.last_info = Some(msg);
+        self.info_clear_time =
+            Some(Instant::now() + chrono::Duration::seconds(8).to_std().unwrap());
+    }
+
     // Method to clear expired error messages (called in refresh_data_for_active_tab)
     pub(crate) fn clear_expired_error(&mut self) {
         if let Some(clear_time) = self.error_clear_time {
@@ -341,6 +656,12 @@ impl App {
                 self.error_clear_time = None;
             }
         }
+        if let Some(clear_time) = self.info_clear_time {
+            if Instant::now() >= clear_time {
+                self.last_info = None;
+                self.info_clear_time = None;
+            }
+        }
     }
     pub fn get_last_or_specific_workout(
         &self,
@@ -358,19 +679,4 @@ impl App {
             _ => None,
         }
     }
-
-    pub fn get_all_exercise_identifiers(&self) -> Vec<String> {
-        let mut identifiers = Vec::new();
-        // Add exercise names
-        if let Ok(exercises) = self.service.list_exercises(None, None) {
-            identifiers.extend(exercises.into_iter().map(|e| e.name));
-        }
-        // Add aliases
-        if let Ok(aliases) = self.service.list_aliases() {
-            identifiers.extend(aliases.into_keys());
-        }
-        identifiers.sort_unstable_by_key(|a| a.to_lowercase());
-        identifiers.dedup_by(|a, b| a.eq_ignore_ascii_case(b)); // Remove duplicates (like name matching alias)
-        identifiers
-    }
 }
@@ -0,0 +1,219 @@
+// src/app/suggestions.rs
+//! Exercise suggestions for the Add Workout modal: fuzzy matches on names and aliases,
+//! with exercises logged often or recently ranked higher.
+use super::fuzzy::fuzzy_match;
+use super::state::App;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use task_athlete_lib::WorkoutFilters;
+
+pub const MAX_SUGGESTIONS: usize = 5;
+const FREQUENCY_WEIGHT: i64 = 2; // Per doubling of the number of logged sets
+const RECENT_WEEK_BONUS: i64 = 6;
+const RECENT_MONTH_BONUS: i64 = 4;
+const RECENT_QUARTER_BONUS: i64 = 2;
+
+/// A name or alias that can be typed into the exercise field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExerciseIdentifier {
+    pub identifier: String,
+    pub canonical: Option<String>, // Set for aliases: the exercise they resolve to
+    pub uses: usize,               // Logged sets of the exercise
+    pub last_used: Option<NaiveDate>,
+}
+
+impl ExerciseIdentifier {
+    pub fn exercise_name(&self) -> &str {
+        self.canonical.as_deref().unwrap_or(&self.identifier)
+    }
+}
+
+/// One row of the suggestion popup.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExerciseSuggestion {
+    pub identifier: String,
+    pub canonical: Option<String>,
+    pub positions: Vec<usize>, // Matched chars of `identifier`, for highlighting
+}
+
+fn usage_boost(identifier: &ExerciseIdentifier, today: NaiveDate) -> i64 {
+    let frequency = (identifier.uses + 1).ilog2() as i64 * FREQUENCY_WEIGHT;
+    let recency = match identifier.last_used.map(|d| (today - d).num_days()) {
+        Some(days) if days <= 7 => RECENT_WEEK_BONUS,
+        Some(days) if days <= 30 => RECENT_MONTH_BONUS,
+        Some(days) if days <= 90 => RECENT_QUARTER_BONUS,
+        _ => 0,
+    };
+    frequency + recency
+}
+
+/// The best suggestions for `query`, one per exercise: an exercise matched by both its
+/// name and an alias is listed once, by whichever scored higher.
+pub fn rank_suggestions(
+    query: &str,
+    identifiers: &[ExerciseIdentifier],
+    today: NaiveDate,
+) -> Vec<ExerciseSuggestion> {
+    if query.trim().is_empty() {
+        return Vec::new();
+    }
+    let mut best: HashMap<&str, (i64, &ExerciseIdentifier, Vec<usize>)> = HashMap::new();
+    for identifier in identifiers {
+        let Some(m) = fuzzy_match(query, &identifier.identifier) else {
+            continue;
+        };
+        let score = m.score + usage_boost(identifier, today);
+        let exercise_name = identifier.exercise_name();
+        let beaten = best
+            .get(exercise_name)
+            .is_some_and(|(best_score, ..)| *best_score >= score);
+        if !beaten {
+            best.insert(exercise_name, (score, identifier, m.positions));
+        }
+    }
+
+    let mut ranked: Vec<(i64, &ExerciseIdentifier, Vec<usize>)> = best.into_values().collect();
+    ranked.sort_by(|a, b| {
+        b.0.cmp(&a.0).then_with(|| {
+            a.1.identifier
+                .to_lowercase()
+                .cmp(&b.1.identifier.to_lowercase())
+        })
+    });
+    ranked
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, identifier, positions)| ExerciseSuggestion {
+            identifier: identifier.identifier.clone(),
+            canonical: identifier.canonical.clone(),
+            positions,
+        })
+        .collect()
+}
+
+impl App {
+    /// Every exercise name and alias, with how often and how recently the exercise was logged.
+    pub fn get_all_exercise_identifiers(&self) -> Vec<ExerciseIdentifier> {
+        let mut usage: HashMap<String, (usize, Option<NaiveDate>)> = HashMap::new();
+        for workout in self
+            .service
+            .list_workouts(&WorkoutFilters::default())
+            .unwrap_or_default()
+        {
+            let date = workout.timestamp.date_naive();
+            let entry = usage.entry(workout.exercise_name).or_default();
+            entry.0 += workout.sets.unwrap_or(1).max(1) as usize;
+            entry.1 = entry.1.max(Some(date));
+        }
+        let identifier = |name: String, canonical: Option<String>| {
+            let (uses, last_used) = usage
+                .get(canonical.as_ref().unwrap_or(&name))
+                .copied()
+                .unwrap_or_default();
+            ExerciseIdentifier {
+                identifier: name,
+                canonical,
+                uses,
+                last_used,
+            }
+        };
+
+        let mut identifiers = Vec::new();
+        // Add exercise names
+        if let Ok(exercises) = self.service.list_exercises(None, None) {
+            identifiers.extend(exercises.into_iter().map(|e| identifier(e.name, None)));
+        }
+        // Add aliases
+        if let Ok(aliases) = self.service.list_aliases() {
+            identifiers.extend(
+                aliases
+                    .into_iter()
+                    .map(|(alias, canonical)| identifier(alias, Some(canonical))),
+            );
+        }
+        // Stable sort: names come before aliases, so a name wins over an alias spelled the same
+        identifiers.sort_by_key(|a| a.identifier.to_lowercase());
+        // Remove duplicates (like name matching alias)
+        identifiers.dedup_by(|a, b| a.identifier.eq_ignore_ascii_case(&b.identifier));
+        identifiers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+    }
+
+    fn exercise(name: &str, uses: usize, days_ago: Option<i64>) -> ExerciseIdentifier {
+        ExerciseIdentifier {
+            identifier: name.to_string(),
+            canonical: None,
+            uses,
+            last_used: days_ago.map(|days| today() - chrono::Duration::days(days)),
+        }
+    }
+
+    fn alias(alias: &str, of: &ExerciseIdentifier) -> ExerciseIdentifier {
+        ExerciseIdentifier {
+            identifier: alias.to_string(),
+            canonical: Some(of.identifier.clone()),
+            ..of.clone()
+        }
+    }
+
+    fn names(suggestions: &[ExerciseSuggestion]) -> Vec<&str> {
+        suggestions.iter().map(|s| s.identifier.as_str()).collect()
+    }
+
+    #[test]
+    fn empty_query_suggests_nothing() {
+        let identifiers = [exercise("Squat", 10, Some(1))];
+        assert!(rank_suggestions("", &identifiers, today()).is_empty());
+        assert!(rank_suggestions("  ", &identifiers, today()).is_empty());
+    }
+
+    #[test]
+    fn query_with_no_match_suggests_nothing() {
+        let identifiers = [
+            exercise("Squat", 10, Some(1)),
+            exercise("Deadlift", 3, None),
+        ];
+        assert!(rank_suggestions("zz", &identifiers, today()).is_empty());
+    }
+
+    #[test]
+    fn frequent_and_recent_exercises_rank_higher() {
+        let identifiers = [
+            exercise("Bench Press", 0, None),
+            exercise("Bent Over Row", 40, Some(2)),
+        ];
+        let ranked = rank_suggestions("ben", &identifiers, today());
+        assert_eq!(names(&ranked), vec!["Bent Over Row", "Bench Press"]);
+    }
+
+    #[test]
+    fn an_exercise_is_listed_once_by_its_best_match() {
+        let bench = exercise("Bench Press", 5, Some(3));
+        let identifiers = [bench.clone(), alias("bp", &bench)];
+        let ranked = rank_suggestions("bp", &identifiers, today());
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].identifier, "bp");
+        assert_eq!(ranked[0].canonical.as_deref(), Some("Bench Press"));
+    }
+
+    #[test]
+    fn keeps_the_top_suggestions() {
+        let identifiers: Vec<_> = (0..MAX_SUGGESTIONS + 3)
+            .map(|i| exercise(&format!("Curl {}", i), i, None))
+            .collect();
+        let ranked = rank_suggestions("curl", &identifiers, today());
+        assert_eq!(ranked.len(), MAX_SUGGESTIONS);
+        assert_eq!(
+            ranked[0].identifier,
+            format!("Curl {}", MAX_SUGGESTIONS + 2)
+        );
+    }
+}
@@ -1,11 +1,14 @@
 // src/app/sync_actions.rs
 
+use super::state::App;
 use anyhow::Result;
-use std::path::PathBuf;
+use std::time::Instant;
 use task_athlete_lib::{AppService, Config, SyncSummary};
-use tokio::sync::mpsc;
+use tokio::sync::mpsc::{self, error::TryRecvError};
 use tracing::{error, info};
 
+const SPINNER_FRAMES: [&str; 8] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧"];
+
 pub enum SyncResult {
     Success(SyncSummary, SyncSummary),
     Error(String),
@@ -13,9 +16,7 @@ pub enum SyncResult {
 
 pub async fn sync_operation_background(
     // These arguments are passed by value and are Send/Sync
-    db_path: PathBuf,
-    config_path: PathBuf,
-    mut initial_config: Config, // Taking Config by value
+    initial_config: Config, // Taking Config by value
     sender: mpsc::Sender<SyncResult>,
 ) {
     info!("Starting background sync operation...");
@@ -23,7 +24,7 @@ pub async fn sync_operation_background(
     // This entire block runs within the spawned Tokio task.
     // Here, we create an AppService instance that owns its own Rusqlite connection,
     // ensuring it's not shared across thread boundaries from the main UI thread.
-    let sync_result: Result<SyncResult> = (|| async {
+    let sync_result: Result<SyncResult> = async {
         // Create a new AppService instance. This involves opening a new rusqlite::Connection
         // which is then owned by this `service` variable, local to this async task.
         let mut service = AppService::initialize()
@@ -46,7 +47,7 @@ pub async fn sync_operation_background(
                 Ok(SyncResult::Error(format!("Sync failed: {}", e)))
             }
         }
-    })()
+    }
     .await; // Await the inner async block
 
     // Send the result back to the main thread
@@ -57,3 +58,81 @@ pub async fn sync_operation_background(
         error!("Failed to send sync result: {}", e);
     }
 }
+
+impl App {
+    /// Starts a sync in the background. The sync runs on its own thread with a
+    /// current-thread Tokio runtime, so the UI loop never blocks on the network.
+    pub fn start_sync(&mut self) {
+        if self.sync_receiver.is_some() {
+            self.set_error("Sync already in progress.".to_string());
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel(1);
+        let config = self.service.config.clone();
+
+        let spawn_result = std::thread::Builder::new()
+            .name("task-athlete-sync".to_string())
+            .spawn(move || {
+                match tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                {
+                    Ok(runtime) => runtime.block_on(sync_operation_background(config, sender)),
+                    Err(e) => {
+                        // Receiver may already be gone if the app is quitting
+                        let _ = sender.blocking_send(SyncResult::Error(format!(
+                            "Failed to start sync runtime: {}",
+                            e
+                        )));
+                    }
+                }
+            });
+
+        match spawn_result {
+            Ok(_) => {
+                self.sync_receiver = Some(receiver);
+                self.sync_started_at = Some(Instant::now());
+            }
+            Err(e) => self.set_error(format!("Failed to start sync: {}", e)),
+        }
+    }
+
+    /// Checks for a finished sync without blocking. Called once per main loop tick.
+    pub fn poll_sync_result(&mut self) {
+        let result = match self.sync_receiver.as_mut() {
+            Some(receiver) => match receiver.try_recv() {
+                Ok(result) => result,
+                Err(TryRecvError::Empty) => return, // Still running
+                Err(TryRecvError::Disconnected) => {
+                    SyncResult::Error("Sync stopped without reporting a result.".to_string())
+                }
+            },
+            None => return,
+        };
+
+        self.sync_receiver = None;
+        self.sync_started_at = None;
+
+        match result {
+            SyncResult::Success(pushed, pulled) => {
+                self.set_info(format!(
+                    "Sync done. Pushed {} workouts | Pulled {} workouts",
+                    pushed.workouts, pulled.workouts
+                ));
+                // The sync ran on its own connection, so every tab may be stale now
+                self.refresh_all_data();
+            }
+            SyncResult::Error(msg) => self.set_error(msg),
+        }
+    }
+
+    /// Returns the spinner glyph for the current frame, or `None` when no sync is running.
+    pub fn sync_spinner_frame(&self) -> Option<&'static str> {
+        self.sync_started_at.map(|started| {
+            // The main loop redraws every 250ms or on input, which drives the animation
+            let frame = (started.elapsed().as_millis() / 125) as usize % SPINNER_FRAMES.len();
+            SPINNER_FRAMES[frame]
+        })
+    }
+}
@@ -0,0 +1,443 @@
+// src/app/theme.rs
+//! Colors of the UI. Built-in themes can be picked in settings.toml, more can be defined
+//! in `themes.toml` next to it, and `NO_COLOR` switches to a theme that only uses bold,
+//! underline and reverse video.
+use super::settings::{ThemeSettings, TuiSettings};
+use anyhow::{bail, Context, Result};
+use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+const THEMES_FILE_NAME: &str = "themes.toml";
+const NO_COLOR_ENV_VAR: &str = "NO_COLOR";
+pub const BUILT_IN_THEMES: [&str; 4] = ["dark", "light", "high-contrast", "no-color"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    pub focused_border: Style,
+    pub unfocused_border: Style,
+    pub modal_border: Style,
+    pub danger_border: Style, // Delete confirmations
+    pub title: Style,         // Section headings, e.g. in the help modal
+    pub header: Style,        // Table headers and field labels
+    pub selected: Style,      // Highlighted row of a list or table
+    pub zebra: Style,         // Every other row of a table
+    pub text: Style,          // Input fields and buttons
+    pub muted: Style,         // Hints, skipped rows, empty days
+    pub axis: Style,          // Chart axes and labels
+    pub error: Style,
+    pub warning: Style,
+    pub success: Style,
+    pub pb: Style,     // Personal bests and their notification
+    pub marked: Style, // Marked sets in the Log tab
+    pub tab: Style,    // Inactive tab titles
+    pub tab_active: Style,
+    pub status_bar: Style,
+    pub series: Vec<Color>, // Chart lines, the first one is used for single-series charts
+    pub heatmap: [Color; 5], // Calendar cells, from no workouts to the busiest days
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            name: "dark".to_string(),
+            focused_border: Style::new().fg(Color::Yellow),
+            unfocused_border: Style::new().fg(Color::DarkGray),
+            modal_border: Style::new().fg(Color::Yellow),
+            danger_border: Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+            title: Style::new().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            header: Style::new().fg(Color::LightBlue),
+            selected: Style::new()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+            zebra: Style::new().bg(Color::Indexed(235)),
+            text: Style::new().fg(Color::White),
+            muted: Style::new().fg(Color::DarkGray),
+            axis: Style::new().fg(Color::Gray),
+            error: Style::new().fg(Color::Red),
+            warning: Style::new().fg(Color::Yellow),
+            success: Style::new().fg(Color::Green),
+            pb: Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            marked: Style::new().fg(Color::Yellow),
+            tab: Style::new().fg(Color::Gray),
+            tab_active: Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            status_bar: Style::new().fg(Color::White).bg(Color::DarkGray),
+            series: vec![
+                Color::Cyan,
+                Color::Magenta,
+                Color::Green,
+                Color::Yellow,
+                Color::LightRed,
+                Color::LightBlue,
+            ],
+            heatmap: [
+                Color::Reset,
+                Color::Rgb(14, 68, 41),
+                Color::Rgb(0, 109, 50),
+                Color::Rgb(38, 166, 65),
+                Color::Rgb(57, 211, 83),
+            ],
+        }
+    }
+
+    /// For terminals with a light background.
+    pub fn light() -> Self {
+        let amber = Color::Indexed(130);
+        Self {
+            name: "light".to_string(),
+            focused_border: Style::new().fg(Color::Blue),
+            unfocused_border: Style::new().fg(Color::Gray),
+            modal_border: Style::new().fg(Color::Blue),
+            danger_border: Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+            title: Style::new().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            header: Style::new().fg(Color::Blue),
+            selected: Style::new()
+                .bg(Color::Indexed(252))
+                .add_modifier(Modifier::BOLD),
+            zebra: Style::new().bg(Color::Indexed(255)),
+            text: Style::new().fg(Color::Black),
+            muted: Style::new().fg(Color::DarkGray),
+            axis: Style::new().fg(Color::DarkGray),
+            error: Style::new().fg(Color::Red),
+            warning: Style::new().fg(amber),
+            success: Style::new().fg(Color::Green),
+            pb: Style::new().fg(amber).add_modifier(Modifier::BOLD),
+            marked: Style::new().fg(Color::Magenta),
+            tab: Style::new().fg(Color::DarkGray),
+            tab_active: Style::new().fg(Color::Blue).add_modifier(Modifier::BOLD),
+            status_bar: Style::new().fg(Color::Black).bg(Color::Indexed(252)),
+            series: vec![
+                Color::Blue,
+                Color::Magenta,
+                Color::Green,
+                amber,
+                Color::Red,
+                Color::Cyan,
+            ],
+            heatmap: [
+                Color::Reset,
+                Color::Rgb(214, 245, 220),
+                Color::Rgb(172, 230, 184),
+                Color::Rgb(120, 208, 140),
+                Color::Rgb(64, 180, 96),
+            ],
+        }
+    }
+
+    /// Bright colors and bold text on the terminal's own background.
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "high-contrast".to_string(),
+            focused_border: Style::new()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+            unfocused_border: Style::new().fg(Color::White),
+            modal_border: Style::new()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+            danger_border: Style::new()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
+            title: Style::new().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            header: Style::new()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::BOLD),
+            selected: Style::new()
+                .fg(Color::Black)
+                .bg(Color::White)
+                .add_modifier(Modifier::BOLD),
+            zebra: Style::new(),
+            text: Style::new().fg(Color::White),
+            muted: Style::new().fg(Color::Gray),
+            axis: Style::new().fg(Color::White),
+            error: Style::new()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
+            warning: Style::new().fg(Color::LightYellow),
+            success: Style::new().fg(Color::LightGreen),
+            pb: Style::new()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+            marked: Style::new()
+                .fg(Color::LightMagenta)
+                .add_modifier(Modifier::BOLD),
+            tab: Style::new().fg(Color::White),
+            tab_active: Style::new()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            status_bar: Style::new().fg(Color::Black).bg(Color::White),
+            series: vec![
+                Color::LightCyan,
+                Color::LightMagenta,
+                Color::LightGreen,
+                Color::LightYellow,
+                Color::LightRed,
+                Color::White,
+            ],
+            heatmap: [
+                Color::Reset,
+                Color::Green,
+                Color::Green,
+                Color::LightGreen,
+                Color::LightGreen,
+            ],
+        }
+    }
+
+    /// No colors at all, only text attributes. Used whenever `NO_COLOR` is set.
+    pub fn no_color() -> Self {
+        let plain = Style::new();
+        let bold = Style::new().add_modifier(Modifier::BOLD);
+        let reversed = Style::new().add_modifier(Modifier::REVERSED);
+        Self {
+            name: "no-color".to_string(),
+            focused_border: bold,
+            unfocused_border: plain,
+            modal_border: bold,
+            danger_border: bold,
+            title: Style::new().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            header: Style::new().add_modifier(Modifier::UNDERLINED),
+            selected: reversed,
+            zebra: plain,
+            text: plain,
+            muted: Style::new().add_modifier(Modifier::DIM),
+            axis: plain,
+            error: bold,
+            warning: bold,
+            success: plain,
+            pb: bold,
+            marked: Style::new().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            tab: plain,
+            tab_active: Style::new().add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            status_bar: reversed,
+            series: vec![Color::Reset],
+            heatmap: [Color::Reset; 5],
+        }
+    }
+
+    fn built_in(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            "no-color" => Some(Self::no_color()),
+            _ => None,
+        }
+    }
+
+    pub fn border(&self, focused: bool) -> Style {
+        if focused {
+            self.focused_border
+        } else {
+            self.unfocused_border
+        }
+    }
+
+    /// Style of the `index`th row of a table, striped when the theme has zebra rows.
+    pub fn row(&self, index: usize) -> Style {
+        if index % 2 == 1 {
+            self.zebra
+        } else {
+            Style::new()
+        }
+    }
+
+    /// Color of the `index`th series of a chart, cycling through the palette.
+    pub fn series_color(&self, index: usize) -> Color {
+        self.series
+            .get(index % self.series.len().max(1))
+            .copied()
+            .unwrap_or(Color::Reset)
+    }
+
+    /// The theme chosen in settings.toml, or the no-color theme when `NO_COLOR` is set.
+    /// Themes that aren't built in are looked up in themes.toml.
+    pub fn load(settings: &ThemeSettings) -> Result<Self> {
+        if std::env::var_os(NO_COLOR_ENV_VAR).is_some_and(|v| !v.is_empty()) {
+            return Ok(Self::no_color());
+        }
+        if let Some(theme) = Self::built_in(&settings.name) {
+            return Ok(theme);
+        }
+        let Some(path) = Self::path() else {
+            bail!("Unknown theme '{}'", settings.name);
+        };
+        let mut themes: HashMap<String, ThemeFile> = if path.exists() {
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            toml::from_str(&contents)
+                .with_context(|| format!("Failed to parse {}", path.display()))?
+        } else {
+            HashMap::new()
+        };
+        let Some(file) = themes.remove(&settings.name) else {
+            bail!(
+                "Unknown theme '{}' (built in: {}; others go in {})",
+                settings.name,
+                BUILT_IN_THEMES.join(", "),
+                path.display()
+            );
+        };
+        file.into_theme(&settings.name)
+            .with_context(|| format!("Invalid theme '{}' in {}", settings.name, path.display()))
+    }
+
+    fn path() -> Option<PathBuf> {
+        TuiSettings::config_dir().map(|dir| dir.join(THEMES_FILE_NAME))
+    }
+}
+
+/// A style in themes.toml: a color name (`"yellow"`, `"#ffaa00"`, `"236"`) for the
+/// foreground, or a table with `fg`, `bg` and text attributes.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StyleSpec {
+    Fg(String),
+    Full {
+        fg: Option<String>,
+        bg: Option<String>,
+        #[serde(default)]
+        bold: bool,
+        #[serde(default)]
+        italic: bool,
+        #[serde(default)]
+        underlined: bool,
+        #[serde(default)]
+        reversed: bool,
+    },
+}
+
+impl StyleSpec {
+    fn to_style(&self) -> Result<Style> {
+        Ok(match self {
+            StyleSpec::Fg(fg) => Style::new().fg(parse_color(fg)?),
+            StyleSpec::Full {
+                fg,
+                bg,
+                bold,
+                italic,
+                underlined,
+                reversed,
+            } => {
+                let mut style = Style::new();
+                if let Some(fg) = fg {
+                    style = style.fg(parse_color(fg)?);
+                }
+                if let Some(bg) = bg {
+                    style = style.bg(parse_color(bg)?);
+                }
+                for (enabled, modifier) in [
+                    (bold, Modifier::BOLD),
+                    (italic, Modifier::ITALIC),
+                    (underlined, Modifier::UNDERLINED),
+                    (reversed, Modifier::REVERSED),
+                ] {
+                    if *enabled {
+                        style = style.add_modifier(modifier);
+                    }
+                }
+                style
+            }
+        })
+    }
+}
+
+fn parse_color(name: &str) -> Result<Color> {
+    Color::from_str(name).map_err(|_| anyhow::anyhow!("unknown color '{}'", name))
+}
+
+/// One `[name]` table of themes.toml. Anything left out comes from `base`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    #[serde(default = "default_base")]
+    base: String,
+    focused_border: Option<StyleSpec>,
+    unfocused_border: Option<StyleSpec>,
+    modal_border: Option<StyleSpec>,
+    danger_border: Option<StyleSpec>,
+    title: Option<StyleSpec>,
+    header: Option<StyleSpec>,
+    selected: Option<StyleSpec>,
+    zebra: Option<StyleSpec>,
+    text: Option<StyleSpec>,
+    muted: Option<StyleSpec>,
+    axis: Option<StyleSpec>,
+    error: Option<StyleSpec>,
+    warning: Option<StyleSpec>,
+    success: Option<StyleSpec>,
+    pb: Option<StyleSpec>,
+    marked: Option<StyleSpec>,
+    tab: Option<StyleSpec>,
+    tab_active: Option<StyleSpec>,
+    status_bar: Option<StyleSpec>,
+    series: Option<Vec<String>>,
+    heatmap: Option<[String; 5]>,
+}
+
+fn default_base() -> String {
+    "dark".to_string()
+}
+
+impl ThemeFile {
+    fn into_theme(self, name: &str) -> Result<Theme> {
+        let Some(mut theme) = Theme::built_in(&self.base) else {
+            bail!(
+                "unknown base '{}' (expected one of {})",
+                self.base,
+                BUILT_IN_THEMES.join(", ")
+            );
+        };
+        theme.name = name.to_string();
+        for (spec, style) in [
+            (&self.focused_border, &mut theme.focused_border),
+            (&self.unfocused_border, &mut theme.unfocused_border),
+            (&self.modal_border, &mut theme.modal_border),
+            (&self.danger_border, &mut theme.danger_border),
+            (&self.title, &mut theme.title),
+            (&self.header, &mut theme.header),
+            (&self.selected, &mut theme.selected),
+            (&self.zebra, &mut theme.zebra),
+            (&self.text, &mut theme.text),
+            (&self.muted, &mut theme.muted),
+            (&self.axis, &mut theme.axis),
+            (&self.error, &mut theme.error),
+            (&self.warning, &mut theme.warning),
+            (&self.success, &mut theme.success),
+            (&self.pb, &mut theme.pb),
+            (&self.marked, &mut theme.marked),
+            (&self.tab, &mut theme.tab),
+            (&self.tab_active, &mut theme.tab_active),
+            (&self.status_bar, &mut theme.status_bar),
+        ] {
+            if let Some(spec) = spec {
+                *style = spec.to_style()?;
+            }
+        }
+        if let Some(series) = &self.series {
+            if series.is_empty() {
+                bail!("series needs at least one color");
+            }
+            theme.series = series
+                .iter()
+                .map(|c| parse_color(c))
+                .collect::<Result<_>>()?;
+        }
+        if let Some(heatmap) = &self.heatmap {
+            for (color, name) in theme.heatmap.iter_mut().zip(heatmap) {
+                *color = parse_color(name)?;
+            }
+        }
+        Ok(theme)
+    }
+}
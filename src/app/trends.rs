@@ -0,0 +1,239 @@
+// src/app/trends.rs
+//! Overlays drawn over the raw points of the Graphs and Bodyweight charts: a moving
+//! average, a least-squares trend line and, for bodyweight, an exponentially smoothed
+//! trend that evens out day-to-day water swings. Points are (days since the chart's
+//! first point, value), oldest first.
+use super::state::App;
+
+const AVERAGE_WINDOW_DAYS: f64 = 7.0;
+const SMOOTHING_PER_DAY: f64 = 0.1; // Share of the gap to a new weigh-in the trend closes per day
+const DAYS_PER_MONTH: f64 = 30.44;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overlay {
+    Average,
+    Regression,
+    Smoothed, // Bodyweight chart only
+}
+
+/// Which overlays a chart shows, toggled from its tab.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TrendOverlays {
+    pub average: bool,
+    pub regression: bool,
+    pub smoothed: bool,
+}
+
+impl TrendOverlays {
+    pub fn toggle(&mut self, overlay: Overlay) {
+        let shown = match overlay {
+            Overlay::Average => &mut self.average,
+            Overlay::Regression => &mut self.regression,
+            Overlay::Smoothed => &mut self.smoothed,
+        };
+        *shown = !*shown;
+    }
+}
+
+/// A least-squares line through a chart's points.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrendLine {
+    pub points: Vec<(f64, f64)>, // The line at the first and last point
+    pub slope_per_day: f64,
+}
+
+impl TrendLine {
+    /// The slope per week, or per month over ranges longer than four months, e.g. "+1.2 kg/wk".
+    pub fn rate_label(&self, unit: &str) -> String {
+        let span = self.points[1].0 - self.points[0].0;
+        if span > 4.0 * DAYS_PER_MONTH {
+            format!("{:+.1} {}/mo", self.slope_per_day * DAYS_PER_MONTH, unit)
+        } else {
+            format!("{:+.1} {}/wk", self.slope_per_day * 7.0, unit)
+        }
+    }
+}
+
+/// The overlays a chart has switched on, computed from its points.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trends {
+    pub average: Vec<(f64, f64)>,
+    pub regression: Option<TrendLine>,
+    pub smoothed: Vec<(f64, f64)>,
+}
+
+impl Trends {
+    pub fn compute(points: &[(f64, f64)], overlays: TrendOverlays) -> Self {
+        Trends {
+            average: if overlays.average {
+                moving_average(points, AVERAGE_WINDOW_DAYS)
+            } else {
+                Vec::new()
+            },
+            regression: if overlays.regression {
+                linear_regression(points)
+            } else {
+                None
+            },
+            smoothed: if overlays.smoothed {
+                exponential_smoothing(points, SMOOTHING_PER_DAY)
+            } else {
+                Vec::new()
+            },
+        }
+    }
+}
+
+/// The mean of every point within `window_days` up to and including each point.
+fn moving_average(points: &[(f64, f64)], window_days: f64) -> Vec<(f64, f64)> {
+    let mut start = 0;
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &(x, _))| {
+            while points[start].0 <= x - window_days {
+                start += 1;
+            }
+            let window = &points[start..=i];
+            let sum: f64 = window.iter().map(|(_, y)| y).sum();
+            (x, sum / window.len() as f64)
+        })
+        .collect()
+}
+
+/// None until there are two points on different days.
+pub(crate) fn linear_regression(points: &[(f64, f64)]) -> Option<TrendLine> {
+    let (first, last) = (points.first()?.0, points.last()?.0);
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let sxx: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    if sxx < f64::EPSILON {
+        return None;
+    }
+    let sxy: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let slope = sxy / sxx;
+    let at = |x: f64| mean_y + slope * (x - mean_x);
+    Some(TrendLine {
+        points: vec![(first, at(first)), (last, at(last))],
+        slope_per_day: slope,
+    })
+}
+
+/// Each point pulls the trend `per_day` of the way towards it for every day since the
+/// previous point, so one heavy or light day barely moves it.
+fn exponential_smoothing(points: &[(f64, f64)], per_day: f64) -> Vec<(f64, f64)> {
+    let mut trend: Option<(f64, f64)> = None;
+    points
+        .iter()
+        .map(|&(x, y)| {
+            let value = match trend {
+                None => y,
+                Some((last_x, last)) => {
+                    let weight = 1.0 - (1.0 - per_day).powf((x - last_x).max(1.0));
+                    last + weight * (y - last)
+                }
+            };
+            trend = Some((x, value));
+            (x, value)
+        })
+        .collect()
+}
+
+impl App {
+    pub fn graphs_toggle_overlay(&mut self, overlay: Overlay) {
+        self.graph_overlays.toggle(overlay);
+        self.refresh_graph_trends();
+    }
+
+    pub fn bw_toggle_overlay(&mut self, overlay: Overlay) {
+        self.bw_overlays.toggle(overlay);
+        self.refresh_bw_trends();
+    }
+
+    /// Trends of the series drawn on top, the selection unless only pinned ones are left.
+    pub(crate) fn refresh_graph_trends(&mut self) {
+        self.graph_trends = match self.graph_series.last() {
+            Some(series) => Trends::compute(&series.points, self.graph_overlays),
+            None => Trends::default(),
+        };
+    }
+
+    pub(crate) fn refresh_bw_trends(&mut self) {
+        self.bw_trends = Trends::compute(&self.bw_graph_data, self.bw_overlays);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: TrendOverlays = TrendOverlays {
+        average: true,
+        regression: true,
+        smoothed: true,
+    };
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn empty_points_have_no_overlays() {
+        assert_eq!(Trends::compute(&[], ALL), Trends::default());
+    }
+
+    #[test]
+    fn overlays_that_are_off_are_not_computed() {
+        let points = [(0.0, 80.0), (1.0, 81.0)];
+        assert_eq!(
+            Trends::compute(&points, TrendOverlays::default()),
+            Trends::default()
+        );
+    }
+
+    #[test]
+    fn moving_average_covers_the_last_week() {
+        let points = [(0.0, 80.0), (3.0, 82.0), (6.0, 84.0), (7.0, 86.0)];
+        let average = moving_average(&points, AVERAGE_WINDOW_DAYS);
+        let values: Vec<f64> = average.iter().map(|(_, y)| *y).collect();
+        // Day 0 drops out of the window on day 7
+        assert_eq!(values, vec![80.0, 81.0, 82.0, 84.0]);
+        assert_eq!(average[3].0, 7.0);
+    }
+
+    #[test]
+    fn regression_follows_a_straight_line() {
+        let points = [(0.0, 80.0), (7.0, 79.0), (14.0, 78.0)];
+        let line = linear_regression(&points).unwrap();
+        assert_close(line.slope_per_day, -1.0 / 7.0);
+        assert_close(line.points[0].1, 80.0);
+        assert_close(line.points[1].1, 78.0);
+        assert_eq!(line.rate_label("kg"), "-1.0 kg/wk");
+    }
+
+    #[test]
+    fn regression_needs_two_days() {
+        assert_eq!(linear_regression(&[]), None);
+        assert_eq!(linear_regression(&[(3.0, 80.0)]), None);
+        assert_eq!(linear_regression(&[(3.0, 80.0), (3.0, 82.0)]), None);
+    }
+
+    #[test]
+    fn long_ranges_report_the_rate_per_month() {
+        let line = linear_regression(&[(0.0, 100.0), (200.0, 120.0)]).unwrap();
+        assert_eq!(line.rate_label("kg"), "+3.0 kg/mo");
+    }
+
+    #[test]
+    fn smoothing_moves_part_of_the_way_to_each_point() {
+        let smoothed = exponential_smoothing(&[(0.0, 80.0), (1.0, 90.0), (3.0, 80.0)], 0.1);
+        assert_close(smoothed[0].1, 80.0);
+        assert_close(smoothed[1].1, 81.0);
+        // Two days close 1 - 0.9^2 = 19% of the gap
+        assert_close(smoothed[2].1, 81.0 - 0.19);
+    }
+}
@@ -0,0 +1,218 @@
+// src/app/warmup.rs
+//! Warm-up ramps: a few lighter sets leading up to the working weight typed in the
+//! Add Workout modal, rounded to what the plate inventory can load.
+use super::journal::Change;
+use super::plates::calculate_plates;
+use super::set_meta::{SetMeta, SetType};
+use super::settings::{PlateInventory, WarmupStep};
+use super::state::{ActiveModal, App, WorkoutLogFlags};
+use super::utils::parse_optional_float;
+use super::AppInputError;
+use task_athlete_lib::{AddWorkoutParams, ExerciseDefinition, ExerciseType, Workout};
+
+const WEIGHT_EPSILON: f64 = 0.01;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WarmupSet {
+    pub weight: f64, // Same units as the Add Workout weight field
+    pub reps: i64,
+}
+
+/// Builds the ramp for `working_weight`. Steps that round to the working weight, or to the
+/// same load as the step before, are dropped so the ramp only ever goes up.
+pub fn warmup_ramp(
+    working_weight: f64,
+    steps: &[WarmupStep],
+    inventory: &PlateInventory,
+) -> Vec<WarmupSet> {
+    let mut ramp: Vec<WarmupSet> = Vec::new();
+    for step in steps.iter().filter(|s| s.reps > 0) {
+        let target = (working_weight * step.percent / 100.0).max(inventory.bar_weight);
+        let Some(load) = calculate_plates(target, inventory) else {
+            continue;
+        };
+        let weight = (load.loaded * 100.0).round() / 100.0; // Keep 57.5 from printing as 57.4999
+        let repeats_previous = ramp
+            .last()
+            .is_some_and(|prev| weight <= prev.weight + WEIGHT_EPSILON);
+        if weight < working_weight - WEIGHT_EPSILON && !repeats_previous {
+            ramp.push(WarmupSet {
+                weight,
+                reps: step.reps,
+            });
+        }
+    }
+    ramp
+}
+
+impl App {
+    /// Logs a warm-up ramp for the exercise and weight in the Add Workout modal, each set
+    /// tagged as a warm-up. The modal stays open for the working sets.
+    pub fn log_warmup_sets(&mut self) {
+        let result = self.try_log_warmup_sets();
+        if let ActiveModal::AddWorkout {
+            ref mut error_message,
+            ..
+        } = self.active_modal
+        {
+            *error_message = result.err().map(|e| e.to_string());
+        }
+    }
+
+    fn try_log_warmup_sets(&mut self) -> Result<(), AppInputError> {
+        let (def, weight_input) = match &self.active_modal {
+            ActiveModal::AddWorkout {
+                exercise_input,
+                weight_input,
+                resolved_exercise,
+                ..
+            } => (
+                self.resolve_warmup_exercise(resolved_exercise.as_ref(), exercise_input)?,
+                weight_input.clone(),
+            ),
+            _ => return Ok(()),
+        };
+
+        let flags = WorkoutLogFlags::from_def(Some(&def));
+        if def.type_ != ExerciseType::Resistance || !flags.log_weight || !flags.log_reps {
+            return Err(AppInputError::WarmupUnavailable(format!(
+                "'{}' isn't a weight and reps exercise",
+                def.name
+            )));
+        }
+        let working_weight = parse_optional_float(&weight_input)?.ok_or_else(|| {
+            AppInputError::WarmupUnavailable("enter the working weight first".to_string())
+        })?;
+
+        let units = self.service.config.units;
+        let inventory = self.settings.plates.for_units(units);
+        if working_weight < inventory.bar_weight {
+            return Err(AppInputError::WarmupUnavailable(format!(
+                "{:.1} is lighter than the {:.1} bar",
+                working_weight, inventory.bar_weight
+            )));
+        }
+        let ramp = warmup_ramp(working_weight, &self.settings.warmup.steps, inventory);
+        if ramp.is_empty() {
+            return Err(AppInputError::WarmupUnavailable(
+                "no warm-up sets below the working weight".to_string(),
+            ));
+        }
+
+        let notes = SetMeta {
+            set_type: Some(SetType::Warmup),
+            ..Default::default()
+        }
+        .encode_notes("");
+        let date = self.timestamp_for_viewed_date()?;
+
+        let mut logged = Vec::new();
+        for set in &ramp {
+            let params = AddWorkoutParams {
+                exercise_identifier: def.name.as_str(),
+                date,
+                sets: Some(1),
+                reps: Some(set.reps),
+                weight: Some(set.weight),
+                notes: notes.clone(),
+                ..Default::default()
+            };
+            let workout_id = match self.service.add_workout(params) {
+                Ok((workout_id, _)) => workout_id,
+                Err(e) => {
+                    // Keep the sets already logged undoable before reporting the failure
+                    self.record_warmup_change(&def.name, logged);
+                    return Err(AppInputError::DbError(format!(
+                        "Error adding warm-up: {}",
+                        e
+                    )));
+                }
+            };
+            if let Some(workout) = self.fetch_workout(workout_id, &def.name, date.date_naive()) {
+                logged.push(workout);
+            }
+        }
+
+        self.record_warmup_change(&def.name, logged);
+
+        let summary = ramp
+            .iter()
+            .map(|s| format!("{}x{}", s.weight, s.reps))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.set_info(format!(
+            "Logged {} warm-up sets for {}: {}",
+            ramp.len(),
+            def.name,
+            summary
+        ));
+        Ok(())
+    }
+
+    fn record_warmup_change(&mut self, exercise_name: &str, logged: Vec<Workout>) {
+        self.record_change(
+            format!("log {} warm-up sets for {}", logged.len(), exercise_name),
+            logged.into_iter().map(Change::AddWorkout).collect(),
+        );
+    }
+
+    /// The modal only resolves the exercise when focus leaves its field, so fall back to
+    /// resolving whatever has been typed.
+    fn resolve_warmup_exercise(
+        &self,
+        resolved: Option<&ExerciseDefinition>,
+        exercise_input: &str,
+    ) -> Result<ExerciseDefinition, AppInputError> {
+        if let Some(def) = resolved {
+            return Ok(def.clone());
+        }
+        self.service
+            .resolve_exercise_identifier(exercise_input.trim())
+            .map_err(|e| AppInputError::DbError(e.to_string()))?
+            .ok_or_else(|| AppInputError::WarmupUnavailable("select a valid exercise".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::settings::{PlateSettings, WarmupSettings};
+
+    fn ramp(working_weight: f64) -> Vec<(f64, i64)> {
+        let steps = WarmupSettings::default().steps;
+        warmup_ramp(working_weight, &steps, &PlateSettings::default().metric)
+            .into_iter()
+            .map(|set| (set.weight, set.reps))
+            .collect()
+    }
+
+    #[test]
+    fn ramps_up_from_the_empty_bar() {
+        assert_eq!(
+            ramp(100.0),
+            vec![(20.0, 10), (40.0, 5), (60.0, 3), (80.0, 1)]
+        );
+    }
+
+    #[test]
+    fn skips_steps_that_round_to_the_previous_load() {
+        // 40% and 60% of 30 kg are below the bar, 80% rounds down to 22.5
+        assert_eq!(ramp(30.0), vec![(20.0, 10), (22.5, 1)]);
+    }
+
+    #[test]
+    fn bar_heavier_than_the_working_weight_gives_no_ramp() {
+        assert!(ramp(15.0).is_empty());
+    }
+
+    #[test]
+    fn no_steps_give_no_ramp() {
+        let inventory = PlateSettings::default().metric;
+        assert!(warmup_ramp(100.0, &[], &inventory).is_empty());
+        let no_reps = [WarmupStep {
+            percent: 50.0,
+            reps: 0,
+        }];
+        assert!(warmup_ramp(100.0, &no_reps, &inventory).is_empty());
+    }
+}
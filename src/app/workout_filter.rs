@@ -0,0 +1,167 @@
+// src/app/workout_filter.rs
+use super::AppInputError;
+use chrono::NaiveDate;
+use task_athlete_lib::{Units, Workout};
+
+/// Numeric workout fields that can be compared in a filter (`weight>100`, `reps>=5`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterField {
+    Sets,
+    Reps,
+    Weight,
+    Duration,
+    Distance,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterOp {
+    Less,
+    LessEq,
+    Equal,
+    GreaterEq,
+    Greater,
+}
+
+impl FilterOp {
+    fn apply(self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            FilterOp::Less => lhs < rhs,
+            FilterOp::LessEq => lhs <= rhs,
+            FilterOp::Equal => (lhs - rhs).abs() < 1e-6,
+            FilterOp::GreaterEq => lhs >= rhs,
+            FilterOp::Greater => lhs > rhs,
+        }
+    }
+}
+
+/// A parsed free-text workout filter, e.g. `ex:squat muscle:legs after:2025-01-01 weight>100`.
+///
+/// Supported terms:
+/// - `ex:NAME` / `exercise:NAME`: exercise name or alias (substring match if it doesn't resolve)
+/// - `muscle:NAME`: muscle group, as stored on the exercise definition
+/// - `on:DATE`, `after:DATE`, `before:DATE`: `YYYY-MM-DD`, `after`/`before` are exclusive
+/// - `FIELD OP VALUE` with FIELD one of sets, reps, weight, duration, distance and
+///   OP one of `<`, `<=`, `=`, `>=`, `>` (weight/distance in the configured units)
+/// - any other word is matched against the exercise name and notes
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WorkoutQuery {
+    pub exercise: Option<String>,
+    pub muscle: Option<String>,
+    pub on: Option<NaiveDate>,
+    pub after: Option<NaiveDate>,
+    pub before: Option<NaiveDate>,
+    pub comparisons: Vec<(FilterField, FilterOp, f64)>,
+    pub text: Vec<String>,
+}
+
+impl WorkoutQuery {
+    pub fn parse(input: &str) -> Result<Self, AppInputError> {
+        let mut query = WorkoutQuery::default();
+
+        for term in input.split_whitespace() {
+            if let Some((key, value)) = term.split_once(':') {
+                if value.is_empty() {
+                    return Err(AppInputError::InvalidFilter(term.to_string()));
+                }
+                match key.to_lowercase().as_str() {
+                    "ex" | "exercise" => query.exercise = Some(value.to_string()),
+                    "muscle" => query.muscle = Some(value.to_string()),
+                    "on" => query.on = Some(parse_filter_date(value)?),
+                    "after" => query.after = Some(parse_filter_date(value)?),
+                    "before" => query.before = Some(parse_filter_date(value)?),
+                    _ => return Err(AppInputError::InvalidFilter(term.to_string())),
+                }
+            } else if let Some(comparison) = parse_comparison(term)? {
+                query.comparisons.push(comparison);
+            } else {
+                query.text.push(term.to_lowercase());
+            }
+        }
+
+        Ok(query)
+    }
+
+    /// Checks the parts of the query that can't be expressed as `WorkoutFilters`.
+    /// `exercise_resolved` is true when `ex:` was resolved and already filtered by the service.
+    pub fn matches(&self, workout: &Workout, units: Units, exercise_resolved: bool) -> bool {
+        let date = workout.timestamp.date_naive();
+        if self.after.is_some_and(|d| date <= d) || self.before.is_some_and(|d| date >= d) {
+            return false;
+        }
+
+        let name_lower = workout.exercise_name.to_lowercase();
+        if !exercise_resolved {
+            if let Some(ex) = &self.exercise {
+                if !name_lower.contains(&ex.to_lowercase()) {
+                    return false;
+                }
+            }
+        }
+
+        let notes_lower = workout.notes.as_deref().unwrap_or_default().to_lowercase();
+        if !self
+            .text
+            .iter()
+            .all(|t| name_lower.contains(t) || notes_lower.contains(t))
+        {
+            return false;
+        }
+
+        self.comparisons.iter().all(|(field, op, rhs)| {
+            field_value(workout, *field, units).is_some_and(|lhs| op.apply(lhs, *rhs))
+        })
+    }
+}
+
+fn parse_filter_date(value: &str) -> Result<NaiveDate, AppInputError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| AppInputError::InvalidDate(value.to_string()))
+}
+
+/// Parses `reps>=5` style terms. Returns `Ok(None)` if the term isn't a comparison.
+fn parse_comparison(term: &str) -> Result<Option<(FilterField, FilterOp, f64)>, AppInputError> {
+    let Some(op_start) = term.find(['<', '>', '=']) else {
+        return Ok(None);
+    };
+    let (field_str, rest) = term.split_at(op_start);
+    let field = match field_str.to_lowercase().as_str() {
+        "sets" => FilterField::Sets,
+        "reps" => FilterField::Reps,
+        "weight" => FilterField::Weight,
+        "duration" => FilterField::Duration,
+        "distance" => FilterField::Distance,
+        _ => return Err(AppInputError::InvalidFilter(term.to_string())),
+    };
+    let (op, value_str) = if let Some(v) = rest.strip_prefix(">=") {
+        (FilterOp::GreaterEq, v)
+    } else if let Some(v) = rest.strip_prefix("<=") {
+        (FilterOp::LessEq, v)
+    } else if let Some(v) = rest.strip_prefix('>') {
+        (FilterOp::Greater, v)
+    } else if let Some(v) = rest.strip_prefix('<') {
+        (FilterOp::Less, v)
+    } else {
+        (FilterOp::Equal, rest.trim_start_matches('='))
+    };
+    let value = value_str
+        .parse::<f64>()
+        .map_err(|_| AppInputError::InvalidNumber(value_str.to_string()))?;
+    Ok(Some((field, op, value)))
+}
+
+/// Workout value for a field, converted to the units the user types in.
+fn field_value(workout: &Workout, field: FilterField, units: Units) -> Option<f64> {
+    match field {
+        FilterField::Sets => workout.sets.map(|v| v as f64),
+        FilterField::Reps => workout.reps.map(|v| v as f64),
+        FilterField::Duration => workout.duration_minutes.map(|v| v as f64),
+        FilterField::Weight => workout.weight.map(|kg| match units {
+            Units::Metric => kg,
+            Units::Imperial => kg * 2.20462,
+        }),
+        FilterField::Distance => workout.distance.map(|km| match units {
+            Units::Metric => km,
+            Units::Imperial => km * 0.621_371,
+        }),
+    }
+}
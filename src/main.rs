@@ -2,6 +2,7 @@ use anyhow::Result;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyEventKind},
     execute,
+    style::Print,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
@@ -51,6 +52,14 @@ fn main() -> Result<()> {
 
 fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()> {
     loop {
+        // Pick up the result of a background sync, if one finished since the last tick
+        app.poll_sync_result();
+
+        // Count the rest timer down, ringing the terminal bell when it runs out
+        if app.tick_rest_timer() {
+            execute!(io::stdout(), Print('\x07'))?;
+        }
+
         // Ensure data is fresh before drawing (moved inside loop)
         app.refresh_data_for_active_tab(); // Refresh data *before* drawing
 
@@ -58,18 +67,20 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
 
         // Poll for events with a timeout (e.g., 250ms)
         if event::poll(Duration::from_millis(250))? {
-            if let Event::Key(key) = event::read()? {
+            match event::read()? {
                 // Only process key press events
-                if key.kind == KeyEventKind::Press {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
                     // Pass key event to the app's input handler
                     // handle_key_event is now a method on App
                     app.handle_key_event(key)?;
                 }
+                // Hit-tested against where the last frame drew things
+                Event::Mouse(mouse) => app.handle_mouse_event(mouse)?,
+                // Resizing also clears the screen, so the next frame (drawn right away, with the
+                // layout picked for the new size) doesn't mix with what the old size left behind
+                Event::Resize(_, _) => terminal.autoresize()?,
+                _ => {}
             }
-            // TODO: Handle other events like resize if needed
-            // if let Event::Resize(width, height) = event::read()? {
-            //     // Handle resize
-            // }
         }
 
         if app.should_quit {
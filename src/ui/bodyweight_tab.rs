@@ -1,7 +1,11 @@
-use crate::app::{state::BodyweightFocus, App}; // Use App from crate::app
+use crate::app::{
+    goal::GoalProjection, mouse::ClickList, state::BodyweightFocus, trends::TrendLine, App,
+}; // Use App from crate::app
+use crate::ui::chart::{cursor_dataset, cursor_line, cursor_title, date_labels};
+use crate::ui::layout::{is_compact, list_rows_area};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style, Stylize},
+    style::{Modifier, Style, Stylize},
     symbols,
     text::{Line, Span},
     widgets::{
@@ -30,26 +34,80 @@ pub fn render_bodyweight_graph(f: &mut Frame, app: &App, area: Rect) {
     let target_data;
     let mut datasets = vec![];
 
-    let data_points: Vec<(f64, f64)> = app
-        .bw_graph_data
-        .iter()
-        .map(|(x, y)| {
-            let display_weight = match app.service.config.units {
-                Units::Metric => *y,
-                Units::Imperial => *y * 2.20462,
-            };
-            (*x, display_weight)
-        })
-        .collect();
+    let to_display = |points: &[(f64, f64)]| -> Vec<(f64, f64)> {
+        points
+            .iter()
+            .map(|(x, y)| {
+                let display_weight = match app.service.config.units {
+                    Units::Metric => *y,
+                    Units::Imperial => *y * 2.20462,
+                };
+                (*x, display_weight)
+            })
+            .collect()
+    };
+    let data_points = to_display(&app.bw_graph_data);
+    let smoothed_points = to_display(&app.bw_trends.smoothed);
+    let average_points = to_display(&app.bw_trends.average);
+    let projection_points = to_display(&app.bw_projection_line);
+    let trend_line = app.bw_trends.regression.as_ref().map(|trend| TrendLine {
+        points: to_display(&trend.points),
+        slope_per_day: match app.service.config.units {
+            Units::Metric => trend.slope_per_day,
+            Units::Imperial => trend.slope_per_day * 2.20462,
+        },
+    });
 
     datasets.push(
         Dataset::default()
             .name("Bodyweight")
             .marker(symbols::Marker::Dot)
             .graph_type(GraphType::Line)
-            .style(Style::default().fg(Color::Cyan))
+            .style(Style::default().fg(app.theme.series_color(0)))
             .data(&data_points),
     );
+    if !smoothed_points.is_empty() {
+        datasets.push(
+            Dataset::default()
+                .name("Smoothed Trend")
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(app.theme.series_color(1)))
+                .data(&smoothed_points),
+        );
+    }
+    if !average_points.is_empty() {
+        datasets.push(
+            Dataset::default()
+                .name("7-Day Average")
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(app.theme.series_color(2)))
+                .data(&average_points),
+        );
+    }
+    if let Some(trend) = &trend_line {
+        datasets.push(
+            Dataset::default()
+                .name(format!("Trend {}", trend.rate_label(weight_unit)))
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(app.theme.muted)
+                .data(&trend.points),
+        );
+    }
+
+    if !projection_points.is_empty() {
+        // Scattered dashes, so the extrapolation reads apart from the logged line
+        datasets.push(
+            Dataset::default()
+                .name("Projection")
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Scatter)
+                .style(app.theme.success)
+                .data(&projection_points),
+        );
+    }
 
     if let Some(target_raw) = app.bw_target {
         let target_display = match app.service.config.units {
@@ -66,11 +124,7 @@ pub fn render_bodyweight_graph(f: &mut Frame, app: &App, area: Rect) {
                     .name("Target")
                     .marker(symbols::Marker::Braille)
                     .graph_type(GraphType::Line)
-                    .style(
-                        Style::default()
-                            .fg(Color::Green)
-                            .add_modifier(Modifier::ITALIC),
-                    )
+                    .style(app.theme.success.add_modifier(Modifier::ITALIC))
                     .data(&target_data),
             );
         }
@@ -92,42 +146,54 @@ pub fn render_bodyweight_graph(f: &mut Frame, app: &App, area: Rect) {
         _ => "All",
     };
     let chart_title = format!("Bodyweight Trend ({})", range_label);
+    let mut graph_block = Block::default()
+        .borders(Borders::ALL)
+        .title(chart_title)
+        .border_style(app.theme.border(app.bw_focus == BodyweightFocus::Graph));
+
+    // Cursor mode: a line through the entry being read, and its date and weight in the title
+    let cursor_points;
+    if let Some((date, point)) = app.bw_cursor_point() {
+        let (x, weight) = to_display(&[point])[0];
+        cursor_points = cursor_line(x, display_y_bounds);
+        datasets.push(cursor_dataset(&cursor_points, app.theme.warning));
+        graph_block =
+            graph_block.title(cursor_title(date, format!("{:.1} {}", weight, weight_unit)));
+    }
+
+    let mut x_axis = Axis::default()
+        .style(app.theme.axis)
+        .bounds(app.bw_graph_x_bounds);
+    let mut y_axis = Axis::default()
+        .style(app.theme.axis)
+        .bounds(display_y_bounds);
+    // Narrow terminals leave the axis titles and labels out, so the line keeps the room
+    if !is_compact(f.size()) {
+        let min_label = display_y_bounds[0].ceil() as i32;
+        let max_label = display_y_bounds[1].floor() as i32;
+        let range = (max_label - min_label).max(1);
+        let step = (range / 5).max(1);
+        if let Some(anchor) = app.bw_graph_first_date {
+            x_axis = x_axis.labels(date_labels(anchor, app.bw_graph_x_bounds, area.width));
+        }
+        x_axis = x_axis.title("Date".italic());
+        y_axis = y_axis
+            .title(format!("Weight ({})", weight_unit).italic())
+            .labels(
+                (min_label..=max_label)
+                    .step_by(step as usize)
+                    .map(|w| Span::from(format!("{:.0}", w)))
+                    .collect(),
+            );
+    }
 
     let chart = Chart::new(datasets)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(chart_title)
-                .border_style(if app.bw_focus == BodyweightFocus::Graph {
-                    Style::default().fg(Color::Yellow)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                }),
-        )
-        .x_axis(
-            Axis::default()
-                .title("Date".italic())
-                .style(Style::default().fg(Color::Gray))
-                .bounds(app.bw_graph_x_bounds)
-                .labels(vec![]),
-        )
-        .y_axis(
-            Axis::default()
-                .title(format!("Weight ({})", weight_unit).italic())
-                .style(Style::default().fg(Color::Gray))
-                .bounds(display_y_bounds)
-                .labels({
-                    let min_label = display_y_bounds[0].ceil() as i32;
-                    let max_label = display_y_bounds[1].floor() as i32;
-                    let range = (max_label - min_label).max(1);
-                    let step = (range / 5).max(1);
-                    (min_label..=max_label)
-                        .step_by(step as usize)
-                        .map(|w| Span::from(format!("{:.0}", w)))
-                        .collect()
-                }),
-        )
-        .legend_position(Some(LegendPosition::TopLeft));
+        .block(graph_block)
+        .x_axis(x_axis)
+        .y_axis(y_axis)
+        .legend_position(Some(LegendPosition::TopLeft))
+        // Room for the overlays' names too
+        .hidden_legend_constraints((Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)));
 
     f.render_widget(chart, area);
 }
@@ -170,45 +236,65 @@ fn render_bodyweight_status(f: &mut Frame, app: &App, area: Rect) {
         }
         None => "Not Set".to_string(),
     };
+    let to_display_rate = |rate: f64| match app.service.config.units {
+        Units::Metric => rate,
+        Units::Imperial => rate * 2.20462,
+    };
+    let projection = app.bw_projection.map(|projection| match projection {
+        GoalProjection::Reached => Span::styled("target reached", app.theme.success),
+        GoalProjection::OnTrack {
+            date,
+            rate_per_week,
+        } => Span::styled(
+            format!(
+                "on track for {} ({:+.1} {}/wk)",
+                date.format("%Y-%m-%d"),
+                to_display_rate(rate_per_week),
+                weight_unit
+            ),
+            app.theme.success,
+        ),
+        GoalProjection::MovingAway { rate_per_week } => Span::styled(
+            format!(
+                "moving away from target ({:+.1} {}/wk)",
+                to_display_rate(rate_per_week),
+                weight_unit
+            ),
+            app.theme.warning,
+        ),
+        GoalProjection::NoChange => Span::styled("no change lately", app.theme.muted),
+    });
 
-    let text = vec![
+    let mut text = vec![
         Line::from(vec![
             Span::styled("Latest: ", Style::default().bold()),
             Span::raw(latest_weight_str),
-            Span::styled(
-                format!(" {}", latest_date_str),
-                Style::default().fg(Color::DarkGray),
-            ),
+            Span::styled(format!(" {}", latest_date_str), app.theme.muted),
         ]),
         Line::from(vec![
             Span::styled("Target: ", Style::default().bold()),
             Span::raw(target_weight_str),
         ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            " [L]og New ",
-            Style::default().fg(Color::Cyan),
-        )),
-        Line::from(Span::styled(
-            " [T]arget Weight ",
-            Style::default().fg(Color::Cyan),
-        )),
-        Line::from(Span::styled(
-            " [R]ange Cycle ",
-            Style::default().fg(Color::Cyan),
-        )),
     ];
+    if let Some(projection) = projection {
+        text.push(Line::from(vec![
+            Span::styled("Projection: ", Style::default().bold()),
+            projection,
+        ]));
+    }
+    text.extend([
+        Line::from(""),
+        Line::from(Span::styled(" [L]og New ", app.theme.header)),
+        Line::from(Span::styled(" [T]arget Weight ", app.theme.header)),
+        Line::from(Span::styled(" [R]ange Cycle ", app.theme.header)),
+    ]);
 
     let paragraph = Paragraph::new(text)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .title("Status & Actions")
-                .border_style(if app.bw_focus == BodyweightFocus::Actions {
-                    Style::default().fg(Color::Yellow)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                }),
+                .border_style(app.theme.border(app.bw_focus == BodyweightFocus::Actions)),
         )
         .wrap(Wrap { trim: true });
 
@@ -223,39 +309,41 @@ fn render_bodyweight_history(f: &mut Frame, app: &mut App, area: Rect) {
     let table_block = Block::default()
         .borders(Borders::ALL)
         .title("History")
-        .border_style(if app.bw_focus == BodyweightFocus::History {
-            Style::default().fg(Color::Yellow)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        });
+        .border_style(app.theme.border(app.bw_focus == BodyweightFocus::History));
 
     let weight_cell_header = format!("Weight ({})", weight_unit);
     let header_cells = ["Date", weight_cell_header.as_str()]
         .into_iter()
-        .map(|h| Cell::from(h).style(Style::default().fg(Color::LightBlue)));
+        .map(|h| Cell::from(h).style(app.theme.header));
     let header = Row::new(header_cells).height(1).bottom_margin(1);
 
-    let rows = app.bw_history.iter().map(|(_, date, weight_kg)| {
-        let display_weight = match app.service.config.units {
-            Units::Metric => *weight_kg,
-            Units::Imperial => *weight_kg * 2.20462,
-        };
-        Row::new(vec![
-            Cell::from(date.format("%Y-%m-%d").to_string()),
-            Cell::from(format!("{:.1}", display_weight)),
-        ])
-    });
+    let rows = app
+        .bw_history
+        .iter()
+        .enumerate()
+        .map(|(i, (_, date, weight_kg))| {
+            let display_weight = match app.service.config.units {
+                Units::Metric => *weight_kg,
+                Units::Imperial => *weight_kg * 2.20462,
+            };
+            Row::new(vec![
+                Cell::from(date.format("%Y-%m-%d").to_string()),
+                Cell::from(format!("{:.1}", display_weight)),
+            ])
+            .style(app.theme.row(i))
+        });
 
     let widths = [Constraint::Length(12), Constraint::Min(10)];
     let table = Table::new(rows, widths)
         .header(header)
         .block(table_block)
-        .highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        )
+        .highlight_style(app.theme.selected)
         .highlight_symbol(">> ");
 
     f.render_stateful_widget(table, area, &mut app.bw_history_state);
+    app.click_areas.push_rows(
+        list_rows_area(area, 2), // Header and its bottom margin
+        ClickList::Bodyweight,
+        app.bw_history_state.offset(),
+    );
 }
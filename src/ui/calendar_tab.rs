@@ -1,19 +1,23 @@
 //src/ui/calendar_tab.rs
 use crate::app::{
+    mouse::ClickList,
+    set_meta::notes_for_display,
     state::{CalendarFocus, CalendarView},
     App,
 };
+use crate::ui::layout::list_rows_area;
 use crate::ui::modals::helpers::render_input_field; // Use helper for input field
-use crate::ui::placeholders::render_placeholder; // Import placeholder renderer
+use chrono::{Datelike, Duration, NaiveDate, Utc};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Paragraph, Row, Table},
     Frame,
 };
 use task_athlete_lib::Units; // Import Units
 
-pub fn render_calendar_tab(f: &mut Frame, app: &mut App, area: Rect) {
+pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(1), Constraint::Min(0)]) // Date/View switch line, Content
@@ -23,9 +27,7 @@ pub fn render_calendar_tab(f: &mut Frame, app: &mut App, area: Rect) {
 
     match app.calendar_view {
         CalendarView::ListView => render_calendar_list_view(f, app, chunks[1]),
-        CalendarView::CalendarView => {
-            render_placeholder(f, "Calendar View (Not Yet Implemented)", chunks[1])
-        }
+        CalendarView::CalendarView => render_calendar_month_view(f, app, chunks[1]),
     }
 }
 
@@ -38,11 +40,172 @@ fn render_calendar_view_switcher(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(paragraph, area);
 }
 
+fn render_calendar_month_view(f: &mut Frame, app: &App, area: Rect) {
+    let selected = app.calendar_selected_date;
+    let first_of_month = selected.with_day(1).unwrap_or(selected);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(first_of_month.format(" %B %Y ").to_string())
+        .border_style(app.theme.focused_border);
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Weekday header
+            Constraint::Min(6),    // Day grid
+            Constraint::Length(1), // Legend / summary
+        ])
+        .split(inner_area);
+
+    let column_constraints = [Constraint::Ratio(1, 7); 7];
+    let header_cells = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(column_constraints)
+        .split(chunks[0]);
+    for (i, name) in ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+        .iter()
+        .enumerate()
+    {
+        f.render_widget(
+            Paragraph::new(*name)
+                .alignment(ratatui::layout::Alignment::Center)
+                .style(app.theme.header),
+            header_cells[i],
+        );
+    }
+
+    // Always draw six weeks so the grid doesn't jump around between months
+    let week_rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Ratio(1, 6); 6])
+        .split(chunks[1]);
+    let grid_start =
+        first_of_month - Duration::days(i64::from(first_of_month.weekday().num_days_from_monday()));
+    let max_volume = app
+        .calendar_day_volumes
+        .values()
+        .copied()
+        .fold(0.0, f64::max);
+    let today = Utc::now().date_naive();
+
+    for (week, row_area) in week_rows.iter().enumerate() {
+        let day_cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(column_constraints)
+            .split(*row_area);
+        for (weekday, cell_area) in day_cells.iter().enumerate() {
+            let date = grid_start + Duration::days((week * 7 + weekday) as i64);
+            render_day_cell(f, app, *cell_area, date, first_of_month, today, max_volume);
+        }
+    }
+
+    render_month_summary(f, app, chunks[2]);
+}
+
+fn render_day_cell(
+    f: &mut Frame,
+    app: &App,
+    area: Rect,
+    date: NaiveDate,
+    first_of_month: NaiveDate,
+    today: NaiveDate,
+    max_volume: f64,
+) {
+    let in_month = date.month() == first_of_month.month();
+    let volume = if in_month {
+        app.calendar_day_volumes.get(&date).copied()
+    } else {
+        None // Only the viewed month is loaded
+    };
+
+    // Background shade by relative training volume (index 0 = rest day)
+    let shade = volume.map_or(0, |v| volume_shade_level(v, max_volume));
+    let shade_color = app.theme.heatmap[shade];
+    let mut style = Style::default().bg(shade_color);
+    style = if !in_month {
+        style.patch(app.theme.muted)
+    } else if shade >= 3 && shade_color != Color::Reset {
+        style.fg(Color::Black) // The busiest shades are bright
+    } else {
+        style.patch(app.theme.text)
+    };
+    if date == today {
+        style = style.add_modifier(Modifier::UNDERLINED);
+    }
+    if date == app.calendar_selected_date {
+        style = style
+            .patch(app.theme.focused_border)
+            .add_modifier(Modifier::BOLD | Modifier::REVERSED);
+    }
+
+    let mut lines = vec![Line::from(format!("{:>2}", date.day()))];
+    if let Some(v) = volume.filter(|v| *v > 0.0) {
+        let display_volume = match app.service.config.units {
+            Units::Metric => v,
+            Units::Imperial => v * 2.20462,
+        };
+        lines.push(Line::from(format_volume_short(display_volume)));
+    } else if volume.is_some() {
+        lines.push(Line::from("•")); // Trained, but nothing with weight x reps
+    }
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .alignment(ratatui::layout::Alignment::Center)
+            .style(style),
+        area,
+    );
+}
+
+fn render_month_summary(f: &mut Frame, app: &App, area: Rect) {
+    let (unit, factor) = match app.service.config.units {
+        Units::Metric => ("kg", 1.0),
+        Units::Imperial => ("lbs", 2.20462),
+    };
+    let total_volume: f64 = app.calendar_day_volumes.values().sum::<f64>() * factor;
+
+    let mut spans = vec![
+        Span::styled("Trained days: ", Style::default().bold()),
+        Span::raw(format!("{}  ", app.calendar_day_volumes.len())),
+        Span::styled("Volume: ", Style::default().bold()),
+        Span::raw(format!("{} {}  ", format_volume_short(total_volume), unit)),
+        Span::raw("Less "),
+    ];
+    spans.extend(
+        app.theme.heatmap[1..]
+            .iter()
+            .map(|shade| Span::styled("  ", Style::default().bg(*shade))),
+    );
+    spans.push(Span::raw(" More"));
+
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// Maps a day's volume to a shade index. Any trained day gets at least shade 1.
+fn volume_shade_level(volume: f64, max_volume: f64) -> usize {
+    if max_volume <= 0.0 || volume <= 0.0 {
+        return 1;
+    }
+    let steps = 3.0; // Shades 1..=4 -> 3 steps above the minimum
+    1 + ((volume / max_volume) * steps).round() as usize
+}
+
+fn format_volume_short(volume: f64) -> String {
+    if volume >= 1000.0 {
+        format!("{:.1}k", volume / 1000.0)
+    } else {
+        format!("{:.0}", volume)
+    }
+}
+
 fn render_calendar_list_view(f: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(2), // Filter Input
+            Constraint::Length(3), // Filter Input
             Constraint::Min(0),    // Workout List
         ])
         .split(area);
@@ -54,8 +217,8 @@ fn render_calendar_list_view(f: &mut Frame, app: &mut App, area: Rect) {
     if app.calendar_focus == CalendarFocus::FilterInput {
         let cursor_area = chunks[0].inner(&ratatui::layout::Margin {
             vertical: 1,
-            horizontal: 1,
-        }); // Area *inside* the render_input_field block
+            horizontal: 2,
+        }); // Inside the block border plus render_input_field's own margin
         let cursor_x = (cursor_area.x + app.calendar_filter_input.chars().count() as u16)
             .min(cursor_area.right().saturating_sub(1));
         f.set_cursor(cursor_x, cursor_area.y);
@@ -64,35 +227,32 @@ fn render_calendar_list_view(f: &mut Frame, app: &mut App, area: Rect) {
 
 fn render_calendar_filter_input(f: &mut Frame, app: &App, area: Rect) {
     let filter_title = if app.calendar_filter_applied.is_empty() {
-        "Filter (Exercise or Muscle):"
+        "Filter (e.g. ex:squat muscle:legs after:2025-01-01 weight>100):"
     } else {
         // Show applied filter
         &format!("Filter (Applied: '{}'):", app.calendar_filter_applied)
     };
 
-    let block_style = if app.calendar_focus == CalendarFocus::FilterInput {
-        Style::default().fg(Color::Yellow)
-    } else {
-        Style::default().fg(Color::DarkGray)
-    };
-
-    let input_area = render_input_field(
-        f,
-        area,
-        "calendar",
-        app.calendar_filter_input.as_str(),
-        app.calendar_focus == CalendarFocus::FilterInput,
-    );
+    let block_style = app
+        .theme
+        .border(app.calendar_focus == CalendarFocus::FilterInput);
 
-    // Draw the label and surrounding block manually
     let block = Block::default()
         .borders(Borders::ALL)
         .title(filter_title)
         .border_style(block_style);
-
+    let inner_area = block.inner(area);
     f.render_widget(block, area);
 
-    // The input field itself was rendered inside the block area by render_input_field
+    // Label is carried by the block title, so the input takes the whole inner line
+    render_input_field(
+        f,
+        &app.theme,
+        inner_area,
+        "",
+        app.calendar_filter_input.as_str(),
+        app.calendar_focus == CalendarFocus::FilterInput,
+    );
 }
 
 fn render_calendar_workout_list(f: &mut Frame, app: &mut App, area: Rect) {
@@ -102,11 +262,10 @@ fn render_calendar_workout_list(f: &mut Frame, app: &mut App, area: Rect) {
             "Workouts ({})",
             app.calendar_workouts_filtered.len()
         )) // Show count
-        .border_style(if app.calendar_focus == CalendarFocus::WorkoutList {
-            Style::default().fg(Color::Yellow)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        });
+        .border_style(
+            app.theme
+                .border(app.calendar_focus == CalendarFocus::WorkoutList),
+        );
 
     let weight_unit = match app.service.config.units {
         Units::Metric => "kg",
@@ -130,36 +289,44 @@ fn render_calendar_workout_list(f: &mut Frame, app: &mut App, area: Rect) {
         "Notes",
     ]
     .into_iter()
-    .map(|h| Cell::from(h).style(Style::default().fg(Color::LightBlue)));
+    .map(|h| Cell::from(h).style(app.theme.header));
     let header = Row::new(header_cells).height(1).bottom_margin(1);
 
-    let rows = app.calendar_workouts_filtered.iter().map(|w| {
-        let weight_display = match app.service.config.units {
-            Units::Metric => w.weight,
-            Units::Imperial => w.weight.map(|kg| kg * 2.20462),
-        };
-        let weight_str = weight_display.map_or("-".to_string(), |v| format!("{:.1}", v));
+    let rows = app
+        .calendar_workouts_filtered
+        .iter()
+        .enumerate()
+        .map(|(i, w)| {
+            let weight_display = match app.service.config.units {
+                Units::Metric => w.weight,
+                Units::Imperial => w.weight.map(|kg| kg * 2.20462),
+            };
+            let weight_str = weight_display.map_or("-".to_string(), |v| format!("{:.1}", v));
 
-        let dist_val = match app.service.config.units {
-            Units::Metric => w.distance,
-            Units::Imperial => w.distance.map(|km| km * 0.621_371),
-        };
-        let dist_str = dist_val.map_or("-".to_string(), |v| format!("{:.1}", v));
-
-        Row::new(vec![
-            Cell::from(w.timestamp.format("%Y-%m-%d").to_string()),
-            Cell::from(w.exercise_name.clone()),
-            Cell::from(w.sets.map_or("-".to_string(), |v| v.to_string())),
-            Cell::from(w.reps.map_or("-".to_string(), |v| v.to_string())),
-            Cell::from(weight_str),
-            Cell::from(
-                w.duration_minutes
-                    .map_or("-".to_string(), |v| format!("{} min", v)),
-            ),
-            Cell::from(dist_str),
-            Cell::from(w.notes.clone().unwrap_or_else(|| "-".to_string())),
-        ])
-    });
+            let dist_val = match app.service.config.units {
+                Units::Metric => w.distance,
+                Units::Imperial => w.distance.map(|km| km * 0.621_371),
+            };
+            let dist_str = dist_val.map_or("-".to_string(), |v| format!("{:.1}", v));
+
+            Row::new(vec![
+                Cell::from(w.timestamp.format("%Y-%m-%d").to_string()),
+                Cell::from(w.exercise_name.clone()),
+                Cell::from(w.sets.map_or("-".to_string(), |v| v.to_string())),
+                Cell::from(w.reps.map_or("-".to_string(), |v| v.to_string())),
+                Cell::from(weight_str),
+                Cell::from(
+                    w.duration_minutes
+                        .map_or("-".to_string(), |v| format!("{} min", v)),
+                ),
+                Cell::from(dist_str),
+                Cell::from(match notes_for_display(w.notes.as_deref()) {
+                    notes if notes.is_empty() => "-".to_string(),
+                    notes => notes,
+                }),
+            ])
+            .style(app.theme.row(i))
+        });
 
     let widths = [
         Constraint::Length(12),     // Date
@@ -175,12 +342,13 @@ fn render_calendar_workout_list(f: &mut Frame, app: &mut App, area: Rect) {
     let table = Table::new(rows, widths)
         .header(header)
         .block(table_block)
-        .highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        )
+        .highlight_style(app.theme.selected)
         .highlight_symbol(">> ");
 
     f.render_stateful_widget(table, area, &mut app.calendar_list_state);
+    app.click_areas.push_rows(
+        list_rows_area(area, 2), // Header and its bottom margin
+        ClickList::CalendarList,
+        app.calendar_list_state.offset(),
+    );
 }
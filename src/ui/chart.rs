@@ -0,0 +1,68 @@
+// src/ui/chart.rs
+//! Pieces shared by the Graphs and Bodyweight charts: date labels for the x axis and the
+//! cursor that reads one point.
+use crate::app::chart_cursor::chart_date;
+use chrono::NaiveDate;
+use ratatui::{
+    layout::Alignment,
+    style::Style,
+    symbols,
+    text::Span,
+    widgets::{block::Title, Dataset, GraphType},
+};
+
+const DAYS_PER_WEEK: f64 = 7.0;
+const DAYS_PER_MONTH: f64 = 30.44;
+const LABEL_WIDTH: u16 = 10; // "Mar 14" or "Mar 2024", with room between labels
+
+/// Date labels for an x axis of days since `anchor`, as many as fit in `width` columns.
+/// Up to a month they count days, up to six months weeks, and months beyond that, with
+/// at most one label per day, week or month.
+pub fn date_labels(anchor: NaiveDate, bounds: [f64; 2], width: u16) -> Vec<Span<'static>> {
+    let span = bounds[1] - bounds[0];
+    if span < 1.0 {
+        return Vec::new(); // A single day, read it with the cursor
+    }
+    let (format, units) = if span <= 31.0 {
+        ("%b %-d", span)
+    } else if span <= 183.0 {
+        ("%b %-d", span / DAYS_PER_WEEK)
+    } else {
+        ("%b %Y", span / DAYS_PER_MONTH)
+    };
+    let count = usize::from(width / LABEL_WIDTH)
+        .min(units as usize + 1)
+        .max(2);
+    (0..count)
+        .map(|i| {
+            // The chart puts the first and last labels at the ends and centres the others
+            // in equal slots, so each label names the date under its middle
+            let fraction = match i {
+                0 => 0.0,
+                i if i == count - 1 => 1.0,
+                i => (i as f64 + 0.5) / count as f64,
+            };
+            let date = chart_date(anchor, bounds[0] + span * fraction);
+            Span::raw(date.format(format).to_string())
+        })
+        .collect()
+}
+
+/// Ends of a vertical line through the cursor's point, across the whole chart.
+pub fn cursor_line(x: f64, y_bounds: [f64; 2]) -> [(f64, f64); 2] {
+    [(x, y_bounds[0]), (x, y_bounds[1])]
+}
+
+pub fn cursor_dataset(line: &[(f64, f64)], style: Style) -> Dataset<'_> {
+    Dataset::default()
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(style)
+        .data(line)
+}
+
+/// The cursor's date and value, shown at the right of the chart's title bar.
+pub fn cursor_title(date: NaiveDate, value: String) -> Title<'static> {
+    Title::from(format!(" {}: {} ", date.format("%a %b %-d, %Y"), value))
+        .alignment(Alignment::Right)
+}
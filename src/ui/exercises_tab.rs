@@ -0,0 +1,186 @@
+// src/ui/exercises_tab.rs
+use crate::app::{
+    exercises::{exercise_type_label, log_flags_label},
+    fuzzy::fuzzy_match,
+    mouse::ClickList,
+    state::ExercisesFocus,
+    theme::Theme,
+    App,
+};
+use crate::ui::layout::list_rows_area;
+use crate::ui::modals::helpers::{highlight_matches, render_input_field};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Search Input
+            Constraint::Min(0),    // List + Details
+        ])
+        .split(area);
+    let body_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(chunks[1]);
+
+    render_search_input(f, app, chunks[0]);
+    render_exercise_table(f, app, body_chunks[0]);
+    render_exercise_details(f, app, body_chunks[1]);
+
+    if app.exercises_focus == ExercisesFocus::SearchInput {
+        let cursor_area = chunks[0].inner(&Margin {
+            vertical: 1,
+            horizontal: 2,
+        }); // Inside the block border plus render_input_field's own margin
+        let cursor_x = (cursor_area.x + app.exercises_search_input.chars().count() as u16)
+            .min(cursor_area.right().saturating_sub(1));
+        f.set_cursor(cursor_x, cursor_area.y);
+    }
+}
+
+fn render_search_input(f: &mut Frame, app: &App, area: Rect) {
+    let focused = app.exercises_focus == ExercisesFocus::SearchInput;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Search (name, alias or muscle):")
+        .border_style(app.theme.border(focused));
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+    render_input_field(
+        f,
+        &app.theme,
+        inner_area,
+        "",
+        &app.exercises_search_input,
+        focused,
+    );
+}
+
+/// The name with the characters matched by the search highlighted.
+fn highlighted_name(theme: &Theme, name: &str, query: &str) -> Line<'static> {
+    let positions = fuzzy_match(query, name).map_or(Vec::new(), |m| m.positions);
+    Line::from(highlight_matches(theme, name, &positions))
+}
+
+fn render_exercise_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let title = if app.exercises_search_input.trim().is_empty() {
+        format!("Exercises ({})", app.exercises_filtered.len())
+    } else {
+        format!(
+            "Exercises ({} of {})",
+            app.exercises_filtered.len(),
+            app.exercises_all.len()
+        )
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(
+            app.theme
+                .border(app.exercises_focus == ExercisesFocus::List),
+        );
+
+    if app.exercises_filtered.is_empty() {
+        let message = if app.exercises_all.is_empty() {
+            "No exercises yet. Press 'c' to create one."
+        } else {
+            "No exercises match the search."
+        };
+        f.render_widget(Paragraph::new(message).italic().block(block), area);
+        return;
+    }
+
+    let header_cells = ["Name", "Type", "Muscles", "Logs"]
+        .into_iter()
+        .map(|h| Cell::from(h).style(app.theme.header));
+    let header = Row::new(header_cells).height(1).bottom_margin(1);
+
+    let query = app.exercises_search_input.trim();
+    let rows = app.exercises_filtered.iter().enumerate().map(|(i, def)| {
+        Row::new(vec![
+            Cell::from(highlighted_name(&app.theme, &def.name, query)),
+            Cell::from(exercise_type_label(def.type_)),
+            Cell::from(def.muscles.clone().unwrap_or_else(|| "-".to_string())),
+            Cell::from(log_flags_label(def)),
+        ])
+        .style(app.theme.row(i))
+    });
+
+    let widths = [
+        Constraint::Percentage(35), // Name
+        Constraint::Length(12),     // Type
+        Constraint::Percentage(30), // Muscles
+        Constraint::Min(10),        // Logs
+    ];
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .highlight_style(app.theme.selected)
+        .highlight_symbol(">> ");
+
+    f.render_stateful_widget(table, area, &mut app.exercises_table_state);
+    app.click_areas.push_rows(
+        list_rows_area(area, 2), // Header and its bottom margin
+        ClickList::Exercises,
+        app.exercises_table_state.offset(),
+    );
+}
+
+fn render_exercise_details(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Details")
+        .border_style(app.theme.unfocused_border);
+
+    let Some(def) = app.selected_exercise_definition() else {
+        f.render_widget(block, area);
+        return;
+    };
+
+    let label = |text: &'static str| Span::styled(text, app.theme.header);
+    let aliases = app
+        .exercises_aliases
+        .get(&def.name)
+        .filter(|a| !a.is_empty())
+        .map_or("-".to_string(), |a| a.join(", "));
+    let mut lines = vec![
+        Line::from(Span::styled(def.name.clone(), Style::new().bold())),
+        Line::from(""),
+        Line::from(vec![
+            label("Type: "),
+            Span::raw(exercise_type_label(def.type_)),
+        ]),
+        Line::from(vec![
+            label("Muscles: "),
+            Span::raw(def.muscles.clone().unwrap_or_else(|| "-".to_string())),
+        ]),
+        Line::from(vec![label("Logs: "), Span::raw(log_flags_label(def))]),
+        Line::from(vec![label("Aliases: "), Span::raw(aliases)]),
+    ];
+    if let Some(usage) = &app.exercises_selected_usage {
+        lines.push(Line::from(vec![
+            label("Logged workouts: "),
+            Span::raw(usage.workout_count.to_string()),
+        ]));
+        lines.push(Line::from(vec![
+            label("Last logged: "),
+            Span::raw(
+                usage
+                    .last_logged
+                    .map_or("never".to_string(), |d| d.format("%Y-%m-%d").to_string()),
+            ),
+        ]));
+    }
+
+    f.render_widget(
+        Paragraph::new(lines).block(block).wrap(Wrap { trim: true }),
+        area,
+    );
+}
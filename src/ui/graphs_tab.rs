@@ -1,22 +1,19 @@
 // src/ui/graphs_tab.rs
 use crate::app::{
-    data::graph_type_to_string, // Import helper
-    state::{App, GraphsFocus},
+    data::{graph_type_to_string, graph_value_title}, // Import helpers
+    graph_range::GraphRange,
+    mouse::ClickList,
+    state::{App, GraphSeries, GraphsFocus},
 };
+use crate::ui::chart::{cursor_dataset, cursor_line, cursor_title, date_labels};
+use crate::ui::layout::{is_compact, list_rows_area};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style, Stylize},
+    style::{Style, Stylize},
     symbols,
-    text::Span,
+    text::{Line, Span},
     widgets::{
-        Axis,
-        Block,
-        Borders,
-        Chart,
-        Dataset,
-        GraphType as ChartGraphType,
-        List,
-        ListItem,
+        Axis, Block, Borders, Chart, Dataset, GraphType as ChartGraphType, List, ListItem,
         Paragraph,
     },
     Frame,
@@ -48,74 +45,91 @@ fn render_graph_exercise_list(f: &mut Frame, app: &mut App, area: Rect) {
     let list_block = Block::default()
         .borders(Borders::ALL)
         .title("Select Exercise")
-        .border_style(if let GraphsFocus::ExerciseList = app.graph_focus {
-            Style::default().fg(Color::Yellow)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        });
+        .border_style(
+            app.theme
+                .border(matches!(app.graph_focus, GraphsFocus::ExerciseList)),
+        );
 
     let list = List::new(list_items)
         .block(list_block)
-        .highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        )
+        .highlight_style(app.theme.selected)
         .highlight_symbol("> ");
 
     f.render_stateful_widget(list, area, &mut app.graph_exercise_list_state);
+    app.click_areas.push_rows(
+        list_rows_area(area, 0),
+        ClickList::GraphExercises,
+        app.graph_exercise_list_state.offset(),
+    );
 }
 
 fn render_graph_type_list(f: &mut Frame, app: &mut App, area: Rect) {
     let list_items: Vec<ListItem> = app
         .graph_types_available
         .iter()
-        .map(|graph_type| ListItem::new(graph_type_to_string(*graph_type))) // Use helper
+        .map(|graph_type| {
+            let mut spans = vec![Span::raw(graph_type_to_string(*graph_type))]; // Use helper
+            let pinned = app.graph_pinned.iter().any(|(exercise, pinned_type)| {
+                app.graph_selected_exercise.as_ref() == Some(exercise) && pinned_type == graph_type
+            });
+            if pinned {
+                spans.push(Span::styled(" [pinned]", app.theme.marked));
+            }
+            ListItem::new(Line::from(spans))
+        })
         .collect();
 
     let list_block = Block::default()
         .borders(Borders::ALL)
         .title("Select Graph Type")
-        .border_style(if let GraphsFocus::GraphTypeList = app.graph_focus {
-            Style::default().fg(Color::Yellow)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        });
+        .border_style(
+            app.theme
+                .border(matches!(app.graph_focus, GraphsFocus::GraphTypeList)),
+        );
 
     let list = List::new(list_items)
         .block(list_block)
-        .highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        )
+        .highlight_style(app.theme.selected)
         .highlight_symbol("> ");
 
     f.render_stateful_widget(list, area, &mut app.graph_type_list_state);
+    app.click_areas.push_rows(
+        list_rows_area(area, 0),
+        ClickList::GraphTypes,
+        app.graph_type_list_state.offset(),
+    );
 }
 
 fn render_graph_display(f: &mut Frame, app: &App, area: Rect) {
-    // Determine title based on selection
-    let graph_pane_title = match (
-        app.graph_selected_exercise.as_ref(),
-        app.graph_selected_type,
-    ) {
-        (Some(ex_name), Some(graph_type)) => {
-            format!("{} - {}", ex_name, graph_type_to_string(graph_type))
+    // Determine title based on what's drawn, or else the selection
+    let graph_pane_title = if app.graph_series.len() > 1 {
+        format!(
+            "Comparing {} Series ({})",
+            app.graph_series.len(),
+            app.graph_range_label()
+        )
+    } else {
+        match (
+            app.graph_selected_exercise.as_ref(),
+            app.graph_selected_type,
+        ) {
+            (Some(ex_name), Some(graph_type)) => format!(
+                "{} - {} ({})",
+                ex_name,
+                graph_type_to_string(graph_type),
+                app.graph_range_label()
+            ),
+            (Some(ex_name), None) => format!("{} - Select Graph Type", ex_name),
+            _ => "Select Exercise and Graph Type".to_string(),
         }
-        (Some(ex_name), None) => format!("{} - Select Graph Type", ex_name),
-        _ => "Select Exercise and Graph Type".to_string(),
     };
 
-    let graph_block = Block::default()
+    let mut graph_block = Block::default()
         .borders(Borders::ALL)
         .title(graph_pane_title)
-        .border_style(Style::default().fg(Color::DarkGray)); // No specific focus for graph display pane itself
+        .border_style(app.theme.unfocused_border); // No specific focus for graph display pane itself
 
-    if app.graph_data_points.is_empty()
-        || app.graph_selected_exercise.is_none()
-        || app.graph_selected_type.is_none()
-    {
+    if app.graph_series.is_empty() {
         // Render placeholder if no data or selection
         f.render_widget(graph_block, area);
         // Optionally add a paragraph inside saying "No data..."
@@ -135,8 +149,13 @@ fn render_graph_display(f: &mut Frame, app: &App, area: Rect) {
                 Constraint::Percentage(20),
             ])
             .split(center_area)[1];
+        let message = if app.graph_range != GraphRange::All && app.graph_selected_type.is_some() {
+            "No data in this date range."
+        } else {
+            "No data to display."
+        };
         f.render_widget(
-            Paragraph::new("No data to display.").alignment(ratatui::layout::Alignment::Center),
+            Paragraph::new(message).alignment(ratatui::layout::Alignment::Center),
             center_area_h,
         );
 
@@ -144,22 +163,76 @@ fn render_graph_display(f: &mut Frame, app: &App, area: Rect) {
     }
 
     // --- Prepare Chart ---
-    // Fix: Convert Option<&String> to Option<&str> before unwrapping or providing default &str
-    let dataset_name = app
-        .graph_selected_exercise
-        .as_deref() // Get Option<&str>
-        .unwrap_or(""); // Default to empty &str if None
-
-    let datasets = vec![Dataset::default()
-        .name(dataset_name) // Use the &str
-        .marker(symbols::Marker::Dot)
-        .graph_type(ChartGraphType::Line)
-        .style(Style::default().fg(Color::Cyan))
-        .data(&app.graph_data_points)];
+    let units = app.service.config.units;
+    let comparing = app.graph_series.len() > 1;
+    let mut datasets: Vec<Dataset> = app
+        .graph_series
+        .iter()
+        .enumerate()
+        .map(|(i, series)| {
+            Dataset::default()
+                .name(series_name(series, comparing, app.graph_normalised, units))
+                .marker(symbols::Marker::Dot)
+                .graph_type(ChartGraphType::Line)
+                .style(Style::default().fg(app.theme.series_color(i)))
+                .data(&series.points)
+        })
+        .collect();
+
+    // Overlays belong to the series drawn on top, the last one
+    let top = app.graph_series.len() - 1;
+    if !app.graph_trends.average.is_empty() {
+        datasets.push(
+            Dataset::default()
+                .name("7-Day Average")
+                .marker(symbols::Marker::Braille)
+                .graph_type(ChartGraphType::Line)
+                .style(Style::default().fg(app.theme.series_color(top + 1)))
+                .data(&app.graph_trends.average),
+        );
+    }
+    if let Some(trend) = &app.graph_trends.regression {
+        let unit = if app.graph_normalised {
+            "%"
+        } else {
+            value_unit(app.graph_series[top].graph_type, units)
+        };
+        datasets.push(
+            Dataset::default()
+                .name(format!("Trend {}", trend.rate_label(unit)))
+                .marker(symbols::Marker::Braille)
+                .graph_type(ChartGraphType::Line)
+                .style(app.theme.muted)
+                .data(&trend.points),
+        );
+    }
+    let has_overlays = datasets.len() > app.graph_series.len();
+
+    // Cursor mode: a line through the point being read, and its date and value in the title
+    let cursor_points;
+    if let Some((date, (x, y))) = app.graph_cursor_point() {
+        cursor_points = cursor_line(x, app.graph_y_bounds);
+        datasets.push(cursor_dataset(&cursor_points, app.theme.warning));
+        let series = &app.graph_series[top];
+        let unit = value_unit(series.graph_type, units);
+        let value = if app.graph_normalised {
+            format!(
+                "{:.0}% ({} {})",
+                y,
+                format_value(y * series.max / 100.0),
+                unit
+            )
+        } else {
+            format!("{} {}", format_value(y), unit)
+        };
+        graph_block = graph_block.title(cursor_title(date, value));
+    }
 
     // Determine axis titles based on graph type and units
-    let (y_title, x_title) =
-        get_axis_titles(app.graph_selected_type.unwrap(), &app.service.config.units);
+    let (mut y_title, x_title) = get_axis_titles(app.graph_series[0].graph_type, &units);
+    if app.graph_normalised {
+        y_title = "% of Best in Range".to_string();
+    }
 
     // Create labels for Y axis (simplistic for now)
     let y_labels: Vec<Span> = if app.graph_y_bounds[0] < app.graph_y_bounds[1] {
@@ -187,54 +260,88 @@ fn render_graph_display(f: &mut Frame, app: &App, area: Rect) {
         vec![Span::raw("0")] // Default if bounds are bad
     };
 
-    let chart = Chart::new(datasets)
+    let mut x_axis = Axis::default()
+        .style(app.theme.axis)
+        .bounds(app.graph_x_bounds);
+    let mut y_axis = Axis::default()
+        .style(app.theme.axis)
+        .bounds(app.graph_y_bounds);
+    // Narrow terminals leave the axis titles and labels out, so the line keeps the room
+    if !is_compact(f.size()) {
+        if let Some(anchor) = app.graph_first_date {
+            x_axis = x_axis.labels(date_labels(anchor, app.graph_x_bounds, area.width));
+        }
+        x_axis = x_axis.title(x_title.italic());
+        y_axis = y_axis.title(y_title.italic()).labels(y_labels);
+    }
+
+    let mut chart = Chart::new(datasets)
         .block(graph_block) // Use the block defined earlier
-        .x_axis(
-            Axis::default()
-                .title(x_title.italic())
-                .style(Style::default().fg(Color::Gray))
-                .bounds(app.graph_x_bounds)
-                // Add labels if needed (e.g., based on date range), skip for now
-                .labels(vec![]),
-        )
-        .y_axis(
-            Axis::default()
-                .title(y_title.italic())
-                .style(Style::default().fg(Color::Gray))
-                .bounds(app.graph_y_bounds)
-                .labels(y_labels),
-        );
+        .x_axis(x_axis)
+        .y_axis(y_axis);
+    // The legend is how compared series and overlays are told apart, so it may take more room
+    if comparing || has_overlays {
+        chart = chart.hidden_legend_constraints((Constraint::Ratio(3, 4), Constraint::Ratio(1, 2)));
+    }
 
     f.render_widget(chart, area);
 }
 
-// Helper to determine axis titles
-fn get_axis_titles(graph_type: LibGraphType, units: &Units) -> (String, String) {
-    let x_title = "Days Since First Workout".to_string();
-    let y_title = match graph_type {
-        LibGraphType::Estimated1RM | LibGraphType::MaxWeight => {
-            // Corrected: Access config units via app.service.config
-            format!(
-                "Weight ({})",
-                if units == &Units::Metric { "kg" } else { "lbs" }
-            )
-        }
-        LibGraphType::MaxReps | LibGraphType::WorkoutReps => "Reps".to_string(),
-        LibGraphType::WorkoutVolume => {
-            // Corrected: Access config units via app.service.config
-            format!(
-                "Volume ({})",
-                if units == &Units::Metric { "kg" } else { "lbs" }
-            ) // Volume units depends on weight unit
+// Legend entry of a series. Compared series also name their graph type, and normalised
+// ones what 100% stands for.
+fn series_name(series: &GraphSeries, comparing: bool, normalised: bool, units: Units) -> String {
+    if !comparing {
+        return series.exercise.clone();
+    }
+    let name = format!(
+        "{} - {}",
+        series.exercise,
+        graph_type_to_string(series.graph_type)
+    );
+    if normalised {
+        format!(
+            "{} (100% = {:.1} {})",
+            name,
+            series.max,
+            value_unit(series.graph_type, units)
+        )
+    } else {
+        name
+    }
+}
+
+// Whole numbers without decimals, like reps
+fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+fn value_unit(graph_type: LibGraphType, units: Units) -> &'static str {
+    match graph_type {
+        LibGraphType::Estimated1RM | LibGraphType::MaxWeight | LibGraphType::WorkoutVolume => {
+            if units == Units::Metric {
+                "kg"
+            } else {
+                "lbs"
+            }
         }
-        LibGraphType::WorkoutDuration => "Duration (min)".to_string(),
+        LibGraphType::MaxReps | LibGraphType::WorkoutReps => "reps",
+        LibGraphType::WorkoutDuration => "min",
         LibGraphType::WorkoutDistance => {
-            // Corrected: Access config units via app.service.config
-            format!(
-                "Distance ({})",
-                if units == &Units::Metric { "km" } else { "mi" }
-            )
+            if units == Units::Metric {
+                "km"
+            } else {
+                "mi"
+            }
         }
-    };
-    (y_title, x_title)
+    }
+}
+
+// Helper to determine axis titles
+fn get_axis_titles(graph_type: LibGraphType, units: &Units) -> (String, String) {
+    let x_title = "Date".to_string();
+    (graph_value_title(graph_type, *units), x_title)
 }
@@ -1,11 +1,12 @@
 // src/ui/history_tab.rs
 use crate::app::{
     data::{format_date_with_ordinal, format_set_line}, // Make sure helpers are imported
+    mouse::{ClickList, ClickTarget},
     state::{App, HistoryFocus},
 };
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style, Stylize},
+    style::{Modifier, Style},
     text::{Line, Span, Text},
     widgets::{Block, Borders, Paragraph},
     Frame,
@@ -17,11 +18,7 @@ pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
     let outer_block = Block::default()
         .borders(Borders::ALL)
         .title("Workout History")
-        .border_style(if app.history_focus == HistoryFocus::DayList {
-            Style::default().fg(Color::Yellow) // Highlight outer block if focused
-        } else {
-            Style::default().fg(Color::DarkGray)
-        });
+        .border_style(app.theme.border(app.history_focus == HistoryFocus::DayList));
     let inner_area = outer_block.inner(area);
     f.render_widget(outer_block, area);
 
@@ -125,9 +122,16 @@ pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
             let day_block = Block::default()
                 .title(title.as_str())
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow)); // Selected is always yellow here
+                .border_style(app.theme.focused_border); // Selected is always focused here
             let paragraph = Paragraph::new(Text::from(content_lines)).block(day_block);
             f.render_widget(paragraph, inner_area); // Render directly into inner_area
+            app.click_areas.push(
+                inner_area,
+                ClickTarget::Item {
+                    list: ClickList::History,
+                    index: current_selection,
+                },
+            );
         } else {
             f.render_widget(
                 Paragraph::new("Error displaying selected item."),
@@ -143,16 +147,12 @@ pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
         .split(inner_area);
 
     // Render the calculated visible items
-    for (i, (_original_index, title, content_lines, is_selected, _height)) in
+    for (i, (original_index, title, content_lines, is_selected, _height)) in
         visible_items_data.iter().enumerate()
     {
         // Check if chunk exists for safety, though layout should match visible_items_data length
         if let Some(chunk) = item_chunks.get(i) {
-            let border_style = if *is_selected {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default().fg(Color::DarkGray)
-            };
+            let border_style = app.theme.border(*is_selected);
             let day_block = Block::default()
                 .title(title.as_str())
                 .borders(Borders::ALL)
@@ -160,6 +160,13 @@ pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
 
             let paragraph = Paragraph::new(Text::from(content_lines.clone())).block(day_block);
             f.render_widget(paragraph, *chunk);
+            app.click_areas.push(
+                *chunk,
+                ClickTarget::Item {
+                    list: ClickList::History,
+                    index: *original_index,
+                },
+            );
         }
     }
 }
@@ -1,17 +1,34 @@
 use crate::{
     app::{ActiveTab, App}, // Use App from crate::app
     ui::{
-        bodyweight_tab, graphs_tab, history_tab, log_tab, modals, status_bar, tabs,
+        bodyweight_tab, calendar_tab, exercises_tab, graphs_tab, history_tab, log_tab, modals,
+        status_bar, tabs,
     },
 };
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    text::Line,
+    widgets::{Paragraph, Wrap},
     Frame,
 };
 
+/// Narrower than this, the Log panes stack, charts drop their axis labels and modals fill the screen.
+const COMPACT_WIDTH: u16 = 90;
+/// Shorter than this, modals fill the screen too, as most of them are taller than a small terminal.
+const COMPACT_HEIGHT: u16 = 30;
+/// Smaller than this, only a "terminal too small" message is drawn.
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 12;
+
 // Main UI rendering function moved here
 pub fn render_ui(f: &mut Frame, app: &mut App) {
     let size = f.size();
+    app.click_areas.clear(); // Re-recorded by the renderers below
+
+    if size.width < MIN_WIDTH || size.height < MIN_HEIGHT {
+        render_too_small(f, app, size);
+        return;
+    }
 
     // Create main layout: Tabs on top, content below, status bar at bottom
     let main_chunks = Layout::default()
@@ -33,6 +50,34 @@ pub fn render_ui(f: &mut Frame, app: &mut App) {
     }
 }
 
+/// Drawn instead of the UI when the terminal can't fit it. Keys still work, e.g. to quit.
+fn render_too_small(f: &mut Frame, app: &App, size: Rect) {
+    let lines = vec![
+        Line::styled("Terminal too small", app.theme.warning),
+        Line::from(format!(
+            "{}x{}, needs at least {}x{}",
+            size.width, size.height, MIN_WIDTH, MIN_HEIGHT
+        )),
+    ];
+    let height = (lines.len() as u16).min(size.height);
+    let area = Rect {
+        y: size.y + (size.height - height) / 2,
+        height,
+        ..size
+    };
+    f.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        area,
+    );
+}
+
+/// Whether the screen is too narrow for the side by side layouts.
+pub fn is_compact(screen: Rect) -> bool {
+    screen.width < COMPACT_WIDTH
+}
+
 // Render the content area based on the active tab
 fn render_main_content(f: &mut Frame, app: &mut App, area: Rect) {
     let content_block = ratatui::widgets::Block::default().borders(ratatui::widgets::Borders::NONE);
@@ -47,12 +92,19 @@ fn render_main_content(f: &mut Frame, app: &mut App, area: Rect) {
         ActiveTab::History => history_tab::render(f, app, content_area),
         ActiveTab::Graphs => graphs_tab::render(f, app, content_area),
         ActiveTab::Bodyweight => bodyweight_tab::render(f, app, content_area),
+        ActiveTab::Calendar => calendar_tab::render(f, app, content_area),
+        ActiveTab::Exercises => exercises_tab::render(f, app, content_area),
     }
 }
 
 /// Helper function to create a centered rectangle with fixed dimensions.
 /// Ensures the dimensions do not exceed the available screen size `r`.
+/// On a compact screen the whole of `r` is used instead.
 pub fn centered_rect(width: u16, height: u16, r: Rect) -> Rect {
+    if is_compact(r) || r.height < COMPACT_HEIGHT {
+        return r;
+    }
+
     // Clamp dimensions to the screen size
     let clamped_width = width.min(r.width);
     let clamped_height = height.min(r.height);
@@ -78,6 +130,19 @@ pub fn centered_rect(width: u16, height: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1] // Take the middle chunk
 }
 
+/// Area of the rows of a bordered list or table, below `header_lines` lines of table header.
+pub fn list_rows_area(area: Rect, header_lines: u16) -> Rect {
+    let inner = area.inner(&Margin {
+        vertical: 1,
+        horizontal: 1,
+    });
+    Rect {
+        y: inner.y + header_lines.min(inner.height),
+        height: inner.height.saturating_sub(header_lines),
+        ..inner
+    }
+}
+
 // Helper function to create a centered rectangle for modals
 // pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
 //     let percent_x = percent_x.min(100);
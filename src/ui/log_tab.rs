@@ -1,11 +1,22 @@
-use crate::app::{state::LogFocus, App}; // Use App from crate::app
+use super::status_bar::rest_timer_span;
+use crate::app::{
+    mouse::ClickList,
+    session::format_elapsed,
+    set_meta::{SetMeta, SetType},
+    state::LogFocus,
+    theme::Theme,
+    App,
+}; // Use App from crate::app
+use crate::ui::layout::{is_compact, list_rows_area};
 use chrono::{Duration, Utc};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style, Stylize},
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, List, ListItem, Paragraph, Row, Table},
     Frame,
 };
+use std::collections::HashSet;
 use task_athlete_lib::{Units, Workout}; // Import Units
 
 pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
@@ -26,16 +37,123 @@ pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
         .constraints([Constraint::Length(1), Constraint::Min(0)])
         .split(area);
 
+    // Session stats on the left, date in the middle, rest timer (if running) on the right
+    let header_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(25),
+            Constraint::Min(0),
+            Constraint::Percentage(25),
+        ])
+        .split(outer_chunks[0]);
+
     let date_header = Paragraph::new(date_header_str).alignment(ratatui::layout::Alignment::Center);
-    f.render_widget(date_header, outer_chunks[0]);
+    f.render_widget(date_header, header_chunks[1]);
 
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
-        .split(outer_chunks[1]);
+    if let Some(session) = app.session.as_ref() {
+        let (unit, factor) = match app.service.config.units {
+            Units::Metric => ("kg", 1.0),
+            Units::Imperial => ("lbs", 2.20462),
+        };
+        let session_header = Line::from(vec![
+            Span::styled("● ", app.theme.error),
+            Span::styled(
+                format_elapsed(session.elapsed()),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                " | {} sets | {:.0} {}",
+                session.total_sets(),
+                session.total_volume() * factor,
+                unit
+            )),
+        ]);
+        f.render_widget(Paragraph::new(session_header), header_chunks[0]);
+    }
+
+    if let Some(timer_span) = rest_timer_span(app) {
+        let timer_header =
+            Paragraph::new(Line::from(timer_span)).alignment(ratatui::layout::Alignment::Right);
+        f.render_widget(timer_header, header_chunks[2]);
+    }
+
+    // Narrow terminals get the exercise list above the sets instead of beside them
+    let chunks = if is_compact(f.size()) {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+            .split(outer_chunks[1])
+    } else {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+            .split(outer_chunks[1])
+    };
 
     render_log_exercise_list(f, app, chunks[0]);
-    render_log_set_list(f, app, chunks[1]);
+
+    // Planned sets from a routine go under the sets table while any are left for the day
+    if app.planned_sets_for_viewed_date().is_empty() {
+        render_log_set_list(f, app, chunks[1]);
+    } else {
+        let right_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(chunks[1]);
+        render_log_set_list(f, app, right_chunks[0]);
+        render_log_planned_list(f, app, right_chunks[1]);
+    }
+}
+
+fn render_log_planned_list(f: &mut Frame, app: &mut App, area: Rect) {
+    let planned = app.planned_sets_for_viewed_date();
+    let list_items: Vec<ListItem> = planned
+        .iter()
+        .map(|p| {
+            let reps = p
+                .reps
+                .map_or_else(|| "AMRAP".to_string(), |r| r.to_string());
+            let weight = p
+                .weight
+                .map_or_else(String::new, |w| format!(" @ {:.1}", w));
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{} ", p.exercise_name)),
+                Span::styled(
+                    format!("{}/{}", p.set_number, p.total_sets),
+                    app.theme.muted,
+                ),
+                Span::raw(format!("  {} reps{}", reps, weight)),
+            ]))
+        })
+        .collect();
+    let routine_names = planned.iter().fold(Vec::<&str>::new(), |mut names, p| {
+        if !names.contains(&p.routine_name.as_str()) {
+            names.push(&p.routine_name);
+        }
+        names
+    });
+    let title = format!(
+        "Planned: {} ({} left)",
+        routine_names.join(", "),
+        planned.len()
+    );
+
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(app.theme.border(app.log_focus == LogFocus::Planned));
+
+    let list = List::new(list_items)
+        .block(list_block)
+        .highlight_style(app.theme.selected)
+        .highlight_symbol(">> ");
+
+    f.render_stateful_widget(list, area, &mut app.log_planned_list_state);
+    app.click_areas.push_rows(
+        list_rows_area(area, 0),
+        ClickList::LogPlanned,
+        app.log_planned_list_state.offset(),
+    );
 }
 
 fn render_log_exercise_list(f: &mut Frame, app: &mut App, area: Rect) {
@@ -48,22 +166,19 @@ fn render_log_exercise_list(f: &mut Frame, app: &mut App, area: Rect) {
     let list_block = Block::default()
         .borders(Borders::ALL)
         .title("Exercises Logged")
-        .border_style(if app.log_focus == LogFocus::ExerciseList {
-            Style::default().fg(Color::Yellow)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        });
+        .border_style(app.theme.border(app.log_focus == LogFocus::ExerciseList));
 
     let list = List::new(list_items)
         .block(list_block)
-        .highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        )
+        .highlight_style(app.theme.selected)
         .highlight_symbol("> ");
 
     f.render_stateful_widget(list, area, &mut app.log_exercise_list_state);
+    app.click_areas.push_rows(
+        list_rows_area(area, 0),
+        ClickList::LogExercises,
+        app.log_exercise_list_state.offset(),
+    );
 }
 
 #[allow(clippy::struct_excessive_bools)]
@@ -72,6 +187,9 @@ struct ColumnVisibility {
     has_weight: bool,
     has_duration: bool,
     has_distance: bool,
+    has_type: bool,
+    has_rpe: bool,
+    has_rir: bool,
     has_notes: bool,
 }
 
@@ -79,6 +197,10 @@ struct ColumnVisibility {
 
 /// Determines which optional columns have data based on the provided sets.
 fn determine_column_visibility(sets: &[Workout]) -> ColumnVisibility {
+    let parsed: Vec<(SetMeta, String)> = sets
+        .iter()
+        .map(|w| SetMeta::parse_notes(w.notes.as_deref()))
+        .collect();
     ColumnVisibility {
         has_reps: sets.iter().any(|w| w.reps.is_some()),
         has_weight: sets
@@ -86,56 +208,57 @@ fn determine_column_visibility(sets: &[Workout]) -> ColumnVisibility {
             .any(|w| w.calculate_effective_weight().is_some()),
         has_duration: sets.iter().any(|w| w.duration_minutes.is_some()),
         has_distance: sets.iter().any(|w| w.distance.is_some()),
-        // Consider empty strings as "no data" for notes if desired:
-        // has_notes: sets.iter().any(|w| w.notes.as_ref().map_or(false, |s| !s.is_empty())),
-        has_notes: sets.iter().any(|w| w.notes.is_some()), // Original check
+        has_type: parsed.iter().any(|(meta, _)| meta.set_type.is_some()),
+        has_rpe: parsed.iter().any(|(meta, _)| meta.rpe.is_some()),
+        has_rir: parsed.iter().any(|(meta, _)| meta.rir.is_some()),
+        // Tags have their own columns, so notes only count if there's free text left
+        has_notes: parsed.iter().any(|(_, text)| !text.is_empty()),
     }
 }
 
 /// Creates the styled block for the table.
-fn create_table_block(title: String, is_focused: bool) -> Block<'static> {
+fn create_table_block(theme: &Theme, title: String, is_focused: bool) -> Block<'static> {
     Block::default()
         .borders(Borders::ALL)
         .title(title)
-        .border_style(if is_focused {
-            Style::default().fg(Color::Yellow)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        })
+        .border_style(theme.border(is_focused))
 }
 
 /// Creates the header row based on visible columns and units.
-fn create_table_header<'a>(visibility: &ColumnVisibility, units: Units) -> Row<'a> {
-    let mut header_cells = vec![Cell::from("Set").style(Style::default().fg(Color::LightBlue))];
+fn create_table_header<'a>(theme: &Theme, visibility: &ColumnVisibility, units: Units) -> Row<'a> {
+    let mut header_cells = vec![Cell::from("Set").style(theme.header)];
 
     if visibility.has_reps {
-        header_cells.push(Cell::from("Reps").style(Style::default().fg(Color::LightBlue)));
+        header_cells.push(Cell::from("Reps").style(theme.header));
     }
     if visibility.has_weight {
         let weight_unit = match units {
             Units::Metric => "kg",
             Units::Imperial => "lbs",
         };
-        header_cells.push(
-            Cell::from(format!("Weight ({})", weight_unit))
-                .style(Style::default().fg(Color::LightBlue)),
-        );
+        header_cells.push(Cell::from(format!("Weight ({})", weight_unit)).style(theme.header));
     }
     if visibility.has_duration {
-        header_cells.push(Cell::from("Duration").style(Style::default().fg(Color::LightBlue)));
+        header_cells.push(Cell::from("Duration").style(theme.header));
     }
     if visibility.has_distance {
         let dist_unit = match units {
             Units::Metric => "km",
             Units::Imperial => "mi",
         };
-        header_cells.push(
-            Cell::from(format!("Distance ({})", dist_unit))
-                .style(Style::default().fg(Color::LightBlue)),
-        );
+        header_cells.push(Cell::from(format!("Distance ({})", dist_unit)).style(theme.header));
+    }
+    if visibility.has_type {
+        header_cells.push(Cell::from("Type").style(theme.header));
+    }
+    if visibility.has_rpe {
+        header_cells.push(Cell::from("RPE").style(theme.header));
+    }
+    if visibility.has_rir {
+        header_cells.push(Cell::from("RIR").style(theme.header));
     }
     if visibility.has_notes {
-        header_cells.push(Cell::from("Notes").style(Style::default().fg(Color::LightBlue)));
+        header_cells.push(Cell::from("Notes").style(theme.header));
     }
 
     Row::new(header_cells).height(1).bottom_margin(1)
@@ -157,6 +280,15 @@ fn calculate_table_widths(visibility: &ColumnVisibility) -> Vec<Constraint> {
     if visibility.has_distance {
         widths.push(Constraint::Length(10));
     }
+    if visibility.has_type {
+        widths.push(Constraint::Length(9));
+    }
+    if visibility.has_rpe {
+        widths.push(Constraint::Length(5));
+    }
+    if visibility.has_rir {
+        widths.push(Constraint::Length(5));
+    }
 
     if visibility.has_notes {
         widths.push(Constraint::Min(10)); // Notes column expands
@@ -178,14 +310,23 @@ fn calculate_table_widths(visibility: &ColumnVisibility) -> Vec<Constraint> {
 
 /// Creates the data rows for the table based on visible columns and units.
 fn create_table_rows<'a>(
+    theme: &Theme,
     sets: &'a [Workout],
     visibility: ColumnVisibility,
     units: Units,
+    marked: &HashSet<i64>,
 ) -> Vec<Row<'a>> {
     sets.iter()
         .enumerate()
         .map(|(i, w)| {
-            let mut row_cells = vec![Cell::from(format!("{}", i + 1))]; // "Set" number cell
+            let (set_meta, notes_text) = SetMeta::parse_notes(w.notes.as_deref());
+            let is_marked = marked.contains(&w.id);
+            let set_label = if is_marked {
+                format!("*{}", i + 1)
+            } else {
+                format!("{}", i + 1)
+            };
+            let mut row_cells = vec![Cell::from(set_label)]; // "Set" number cell
 
             if visibility.has_reps {
                 row_cells.push(Cell::from(
@@ -214,11 +355,36 @@ fn create_table_rows<'a>(
                 let dist_str = dist_val.map_or("-".to_string(), |v| format!("{:.1}", v));
                 row_cells.push(Cell::from(dist_str));
             }
+            if visibility.has_type {
+                row_cells.push(Cell::from(set_meta.set_type.map_or("-", SetType::label)));
+            }
+            if visibility.has_rpe {
+                row_cells.push(Cell::from(
+                    set_meta.rpe.map_or("-".to_string(), |v| v.to_string()),
+                ));
+            }
+            if visibility.has_rir {
+                row_cells.push(Cell::from(
+                    set_meta.rir.map_or("-".to_string(), |v| v.to_string()),
+                ));
+            }
             if visibility.has_notes {
-                row_cells.push(Cell::from(w.notes.as_deref().unwrap_or("-"))); // Use as_deref for efficiency
+                row_cells.push(Cell::from(if notes_text.is_empty() {
+                    "-".to_string()
+                } else {
+                    notes_text
+                }));
             }
 
-            Row::new(row_cells)
+            // Warm-ups are dimmed, by default they don't count towards volume
+            let row_style = if is_marked {
+                theme.marked
+            } else if set_meta.set_type == Some(SetType::Warmup) {
+                theme.muted
+            } else {
+                Style::default()
+            };
+            Row::new(row_cells).style(theme.row(i).patch(row_style))
         })
         .collect()
 }
@@ -232,9 +398,16 @@ fn render_log_set_list(f: &mut Frame, app: &mut App, area: Rect) {
         .selected()
         .and_then(|i| app.log_exercises_today.get(i));
 
-    let title = selected_exercise_name
+    let mut title = selected_exercise_name
         .map(|name| format!("Sets for: {}", name))
         .unwrap_or_else(|| "Select an Exercise".to_string());
+    let marked = app.log_marked_set_ids();
+    if !marked.is_empty() {
+        title.push_str(&format!(" ({} marked)", marked.len()));
+    }
+    if app.log_visual_anchor.is_some() {
+        title.push_str(" [VISUAL]");
+    }
 
     let is_focused = app.log_focus == LogFocus::SetList;
 
@@ -246,22 +419,23 @@ fn render_log_set_list(f: &mut Frame, app: &mut App, area: Rect) {
     let visibility = determine_column_visibility(sets);
 
     // 4. Create reusable table components using helpers
-    let table_block = create_table_block(title, is_focused);
-    let header = create_table_header(&visibility, units);
+    let table_block = create_table_block(&app.theme, title, is_focused);
+    let header = create_table_header(&app.theme, &visibility, units);
     let widths = calculate_table_widths(&visibility);
-    let rows = create_table_rows(sets, visibility, units);
+    let rows = create_table_rows(&app.theme, sets, visibility, units, &marked);
 
     // 5. Build the final table widget
     let table = Table::new(rows, &widths) // Pass widths as a slice
         .header(header)
         .block(table_block)
-        .highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        )
+        .highlight_style(app.theme.selected)
         .highlight_symbol(">> ");
 
     // 6. Render the stateful widget
     f.render_stateful_widget(table, area, &mut app.log_set_table_state);
+    app.click_areas.push_rows(
+        list_rows_area(area, 2), // Header and its bottom margin
+        ClickList::LogSets,
+        app.log_set_table_state.offset(),
+    );
 }
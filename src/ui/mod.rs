@@ -1,4 +1,7 @@
 mod bodyweight_tab;
+mod calendar_tab;
+mod chart;
+mod exercises_tab;
 mod graphs_tab;
 mod history_tab;
 mod layout;
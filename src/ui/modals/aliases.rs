@@ -0,0 +1,120 @@
+// src/ui/modals/aliases.rs
+use super::helpers::{render_error_message, render_input_field};
+use crate::{
+    app::{
+        keymap::{Action, KeyContext},
+        state::{ActiveModal, AliasManagerField},
+        App,
+    },
+    ui::layout::centered_rect,
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin},
+    style::{Style, Stylize},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+pub(super) fn render_alias_manager_modal(f: &mut Frame, app: &App) {
+    if let ActiveModal::AliasManager {
+        exercise_name,
+        alias_input,
+        list_state,
+        focused_field,
+        error_message,
+    } = &app.active_modal
+    {
+        let block = Block::default()
+            .title(format!("Aliases - {}", exercise_name))
+            .borders(Borders::ALL)
+            .border_style(app.theme.modal_border);
+
+        let aliases = app
+            .exercises_aliases
+            .get(exercise_name)
+            .map_or(&[][..], Vec::as_slice);
+        let has_error = error_message.is_some();
+        let list_height = (aliases.len() as u16).clamp(1, 10);
+        // List + input + hint + error + borders
+        let height = list_height + 5 + u16::from(has_error);
+        let area = centered_rect(60, height, f.size());
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let inner_area = area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        let mut constraints = vec![
+            Constraint::Length(list_height), // Alias list
+            Constraint::Length(2),           // New alias field
+            Constraint::Length(1),           // Key hint
+        ];
+        if has_error {
+            constraints.push(Constraint::Length(1)); // Error Message
+        }
+        constraints.push(Constraint::Min(0)); // Fill remainder
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(inner_area);
+
+        if aliases.is_empty() {
+            f.render_widget(Paragraph::new("No aliases yet.").italic(), chunks[0]);
+        } else {
+            let list_focused = *focused_field == AliasManagerField::List;
+            let items: Vec<ListItem> = aliases
+                .iter()
+                .map(|alias| ListItem::new(alias.as_str()))
+                .collect();
+            let highlight = if list_focused {
+                app.theme.selected
+            } else {
+                Style::default()
+            };
+            let list = List::new(items)
+                .highlight_style(highlight)
+                .highlight_symbol(if list_focused { "> " } else { "  " });
+            let mut state = list_state.clone(); // Clone for rendering
+            f.render_stateful_widget(list, chunks[0], &mut state);
+        }
+
+        let input_focused = *focused_field == AliasManagerField::Input;
+        let input_area = render_input_field(
+            f,
+            &app.theme,
+            chunks[1],
+            "New alias:",
+            alias_input,
+            input_focused,
+        );
+
+        let hint = if input_focused {
+            "[Enter] Add | [Tab] List | [Esc] Close".to_string()
+        } else {
+            app.keymap
+                .hints(KeyContext::Aliases, |action| {
+                    !matches!(action, Action::Up | Action::Down)
+                })
+                .join(" | ")
+        };
+        f.render_widget(Paragraph::new(hint).style(app.theme.muted), chunks[2]);
+
+        let error_chunk_index = 3;
+        if chunks.len() > error_chunk_index {
+            render_error_message(
+                f,
+                &app.theme,
+                chunks[error_chunk_index],
+                error_message.as_ref(),
+            );
+        }
+
+        if input_focused {
+            let cursor_x = (input_area.x + alias_input.chars().count() as u16)
+                .min(input_area.right().saturating_sub(1));
+            f.set_cursor(cursor_x, input_area.y);
+        }
+    }
+}
@@ -0,0 +1,263 @@
+// src/ui/modals/bulk.rs
+use super::helpers::{render_button_pair, render_error_message, render_input_field};
+use crate::{
+    app::{
+        set_meta::SetMeta,
+        state::{ActiveModal, BulkDateAction, BulkEditField},
+        App,
+    },
+    ui::layout::centered_rect,
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::Stylize,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use task_athlete_lib::{Units, Workout};
+
+const MAX_LISTED_SETS: usize = 12;
+
+/// `Squat 1x5 @ 100.0 kg [Warm-up]`, as one line of a set list.
+fn describe_set(workout: &Workout, units: Units) -> String {
+    let mut text = workout.exercise_name.clone();
+    match (workout.sets, workout.reps) {
+        (Some(sets), Some(reps)) => text.push_str(&format!(" {}x{}", sets, reps)),
+        (None, Some(reps)) => text.push_str(&format!(" x{}", reps)),
+        _ => {}
+    }
+    if let Some(weight) = workout.calculate_effective_weight() {
+        let (weight, unit) = match units {
+            Units::Metric => (weight, "kg"),
+            Units::Imperial => (weight * 2.20462, "lbs"),
+        };
+        text.push_str(&format!(" @ {:.1} {}", weight, unit));
+    }
+    if let Some(minutes) = workout.duration_minutes {
+        text.push_str(&format!(" {} min", minutes));
+    }
+    let (meta, _) = SetMeta::parse_notes(workout.notes.as_deref());
+    if !meta.is_empty() {
+        text.push_str(&format!(" [{}]", meta.describe()));
+    }
+    text
+}
+
+/// The affected sets, cut off with "... and N more" when there are many.
+fn set_lines(workouts: &[Workout], units: Units) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line> = workouts
+        .iter()
+        .take(MAX_LISTED_SETS)
+        .map(|w| Line::from(format!(" • {}", describe_set(w, units))))
+        .collect();
+    if workouts.len() > MAX_LISTED_SETS {
+        lines.push(Line::from(
+            format!("   ... and {} more", workouts.len() - MAX_LISTED_SETS).italic(),
+        ));
+    }
+    lines
+}
+
+fn modal_area(f: &Frame, content_height: u16) -> Rect {
+    centered_rect(70, content_height + 2, f.size()) // Content + borders
+}
+
+pub(super) fn render_confirm_delete_workouts_modal(f: &mut Frame, app: &mut App) {
+    if let ActiveModal::ConfirmDeleteWorkouts { workouts } = &app.active_modal {
+        let block = Block::default()
+            .title("Confirm Deletion")
+            .borders(Borders::ALL)
+            .border_style(app.theme.danger_border);
+
+        let lines = set_lines(workouts, app.service.config.units);
+        let area = modal_area(f, lines.len() as u16 + 3); // Question, sets, blank, buttons
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let inner_area = area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),                  // Question
+                Constraint::Length(lines.len() as u16), // Sets
+                Constraint::Length(1),                  // Spacer
+                Constraint::Length(1),                  // Buttons
+            ])
+            .split(inner_area);
+
+        f.render_widget(
+            Paragraph::new(format!("Delete these {} sets?", workouts.len())).bold(),
+            chunks[0],
+        );
+        f.render_widget(Paragraph::new(lines), chunks[1]);
+        let button_areas = render_button_pair(f, &app.theme, chunks[3], "Yes", "No", None);
+        app.click_areas.push_buttons(button_areas);
+    }
+}
+
+pub(super) fn render_bulk_date_modal(f: &mut Frame, app: &App) {
+    if let ActiveModal::BulkDate {
+        action,
+        workouts,
+        date_input,
+        error_message,
+    } = &app.active_modal
+    {
+        let verb = match action {
+            BulkDateAction::Copy => "Copy",
+            BulkDateAction::Move => "Move",
+        };
+        let block = Block::default()
+            .title(format!("{} {} Sets to Date", verb, workouts.len()))
+            .borders(Borders::ALL)
+            .border_style(app.theme.modal_border);
+
+        let lines = set_lines(workouts, app.service.config.units);
+        let has_error = error_message.is_some();
+        // Sets, date field, hint, error
+        let area = modal_area(f, lines.len() as u16 + 3 + u16::from(has_error));
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let inner_area = area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        let mut constraints = vec![
+            Constraint::Length(lines.len() as u16), // Sets
+            Constraint::Length(2),                  // Date field
+        ];
+        if has_error {
+            constraints.push(Constraint::Length(1)); // Error Message
+        }
+        constraints.push(Constraint::Min(0)); // Fill remainder
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(inner_area);
+
+        f.render_widget(Paragraph::new(lines), chunks[0]);
+        let input_area = render_input_field(
+            f,
+            &app.theme,
+            chunks[1],
+            "Date (YYYY-MM-DD, today, yesterday) - [Enter] Confirm, [Esc] Cancel:",
+            date_input,
+            true,
+        );
+
+        let error_chunk_index = 2;
+        if chunks.len() > error_chunk_index {
+            render_error_message(
+                f,
+                &app.theme,
+                chunks[error_chunk_index],
+                error_message.as_ref(),
+            );
+        }
+
+        let cursor_x = (input_area.x + date_input.chars().count() as u16)
+            .min(input_area.right().saturating_sub(1));
+        f.set_cursor(cursor_x, input_area.y);
+    }
+}
+
+pub(super) fn render_bulk_edit_modal(f: &mut Frame, app: &mut App) {
+    if let ActiveModal::BulkEdit {
+        workouts,
+        weight_input,
+        reps_input,
+        focused_field,
+        error_message,
+    } = &app.active_modal
+    {
+        let block = Block::default()
+            .title(format!("Edit {} Sets", workouts.len()))
+            .borders(Borders::ALL)
+            .border_style(app.theme.modal_border);
+
+        let lines = set_lines(workouts, app.service.config.units);
+        let has_error = error_message.is_some();
+        // Sets, hint, weight, reps, buttons, error
+        let area = modal_area(f, lines.len() as u16 + 7 + u16::from(has_error));
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let inner_area = area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        let mut constraints = vec![
+            Constraint::Length(lines.len() as u16), // Sets
+            Constraint::Length(1),                  // Format hint
+            Constraint::Length(2),                  // Weight field
+            Constraint::Length(2),                  // Reps field
+            Constraint::Length(1),                  // Buttons row
+        ];
+        if has_error {
+            constraints.push(Constraint::Length(1)); // Error Message
+        }
+        constraints.push(Constraint::Min(0)); // Fill remainder
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(inner_area);
+
+        f.render_widget(Paragraph::new(lines), chunks[0]);
+        f.render_widget(
+            Paragraph::new("+2.5 / -5 adjust, 60 replaces, empty leaves as is")
+                .style(app.theme.muted),
+            chunks[1],
+        );
+        let weight_area = render_input_field(
+            f,
+            &app.theme,
+            chunks[2],
+            "Weight:",
+            weight_input,
+            *focused_field == BulkEditField::Weight,
+        );
+        let reps_area = render_input_field(
+            f,
+            &app.theme,
+            chunks[3],
+            "Reps:",
+            reps_input,
+            *focused_field == BulkEditField::Reps,
+        );
+        let button_focus = match focused_field {
+            BulkEditField::Confirm => Some(0),
+            BulkEditField::Cancel => Some(1),
+            _ => None,
+        };
+        let button_areas =
+            render_button_pair(f, &app.theme, chunks[4], "Apply", "Cancel", button_focus);
+        app.click_areas.push_buttons(button_areas);
+
+        let error_chunk_index = 5;
+        if chunks.len() > error_chunk_index {
+            render_error_message(
+                f,
+                &app.theme,
+                chunks[error_chunk_index],
+                error_message.as_ref(),
+            );
+        }
+
+        // --- Cursor Positioning ---
+        let cursor = match focused_field {
+            BulkEditField::Weight => Some((weight_input, weight_area)),
+            BulkEditField::Reps => Some((reps_input, reps_area)),
+            _ => None, // No cursor for buttons
+        };
+        if let Some((input, input_area)) = cursor {
+            let cursor_x = (input_area.x + input.chars().count() as u16)
+                .min(input_area.right().saturating_sub(1));
+            f.set_cursor(cursor_x, input_area.y);
+        }
+    }
+}
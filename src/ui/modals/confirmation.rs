@@ -1,15 +1,18 @@
+use super::helpers::render_button_pair;
 use crate::{
     app::{state::ActiveModal, App},
     ui::layout::centered_rect,
 };
 use ratatui::{
     layout::{Constraint, Direction, Layout, Margin},
-    style::{Color, Modifier, Style, Stylize},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
-pub(super) fn render_confirmation_modal(f: &mut Frame, app: &App) {
+/// Keeps the Yes/No buttons readable when the question is short.
+const MIN_TEXT_WIDTH: u16 = 20;
+
+pub(super) fn render_confirmation_modal(f: &mut Frame, app: &mut App) {
     if let ActiveModal::ConfirmDeleteWorkout {
         exercise_name,
         set_index,
@@ -19,16 +22,13 @@ pub(super) fn render_confirmation_modal(f: &mut Frame, app: &App) {
         let block = Block::default()
             .title("Confirm Deletion")
             .borders(Borders::ALL)
-            .border_style(Style::new().fg(Color::Red).add_modifier(Modifier::BOLD));
+            .border_style(app.theme.danger_border);
 
         let question = format!("Delete set {} of {}?", set_index, exercise_name);
-        let options = "[Y]es / [N]o (Esc)";
 
-        let question_width = question.len() as u16;
-        let options_width = options.len() as u16;
-        let text_width = question_width.max(options_width);
+        let text_width = (question.len() as u16).max(MIN_TEXT_WIDTH);
         let modal_width = text_width + 4; // Add padding for borders/margins
-        let modal_height = 5; // Title, border, question, options, border
+        let modal_height = 5; // Title, border, question, buttons, border
 
         let area = centered_rect(modal_width, modal_height, f.size());
         f.render_widget(Clear, area);
@@ -38,23 +38,20 @@ pub(super) fn render_confirmation_modal(f: &mut Frame, app: &App) {
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(1), Constraint::Length(1)]) // Question, Options
+            .constraints([Constraint::Length(1), Constraint::Length(1)]) // Question, Buttons
             .split(inner_area);
 
         f.render_widget(
             Paragraph::new(question).alignment(ratatui::layout::Alignment::Center),
             chunks[0],
         );
-        f.render_widget(
-            Paragraph::new(options).alignment(ratatui::layout::Alignment::Center),
-            chunks[1],
-        );
+        let button_areas = render_button_pair(f, &app.theme, chunks[1], "Yes", "No", None);
+        app.click_areas.push_buttons(button_areas);
     }
 }
 
-pub(super) fn render_confirmation_bodyweight_modal(f: &mut Frame, app: &App) {
+pub(super) fn render_confirmation_bodyweight_modal(f: &mut Frame, app: &mut App) {
     if let ActiveModal::ConfirmDeleteBodyWeight {
-        
         set_index,
         .. // Ignore workout_id
     } = &app.active_modal
@@ -62,16 +59,13 @@ pub(super) fn render_confirmation_bodyweight_modal(f: &mut Frame, app: &App) {
         let block = Block::default()
             .title("Confirm Deletion")
             .borders(Borders::ALL)
-            .border_style(Style::new().fg(Color::Red).add_modifier(Modifier::BOLD));
+            .border_style(app.theme.danger_border);
 
         let question = format!("Delete bodyweight entry {}?", set_index);
-        let options = "[Y]es / [N]o (Esc)";
 
-        let question_width = question.len() as u16;
-        let options_width = options.len() as u16;
-        let text_width = question_width.max(options_width);
+        let text_width = (question.len() as u16).max(MIN_TEXT_WIDTH);
         let modal_width = text_width + 4; // Add padding for borders/margins
-        let modal_height = 5; // Title, border, question, options, border
+        let modal_height = 5; // Title, border, question, buttons, border
 
         let area = centered_rect(modal_width, modal_height, f.size());
         f.render_widget(Clear, area);
@@ -81,16 +75,74 @@ pub(super) fn render_confirmation_bodyweight_modal(f: &mut Frame, app: &App) {
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(1), Constraint::Length(1)]) // Question, Options
+            .constraints([Constraint::Length(1), Constraint::Length(1)]) // Question, Buttons
+            .split(inner_area);
+
+        f.render_widget(
+            Paragraph::new(question).alignment(ratatui::layout::Alignment::Center),
+            chunks[0],
+        );
+        let button_areas = render_button_pair(f, &app.theme, chunks[1], "Yes", "No", None);
+        app.click_areas.push_buttons(button_areas);
+    }
+}
+
+pub(super) fn render_confirmation_exercise_modal(f: &mut Frame, app: &mut App) {
+    if let ActiveModal::ConfirmDeleteExercise {
+        exercise_name,
+        workout_count,
+    } = &app.active_modal
+    {
+        let block = Block::default()
+            .title("Confirm Deletion")
+            .borders(Borders::ALL)
+            .border_style(app.theme.danger_border);
+
+        let question = format!("Delete exercise '{}'?", exercise_name);
+        let usage = match workout_count {
+            0 => "No logged workouts reference it.".to_string(),
+            1 => "1 logged workout references it.".to_string(),
+            n => format!("{} logged workouts reference it.", n),
+        };
+
+        let text_width = (question.len().max(usage.len()) as u16).max(MIN_TEXT_WIDTH);
+        let modal_width = text_width + 4; // Add padding for borders/margins
+        let modal_height = 6; // Border, question, usage, buttons, border
+
+        let area = centered_rect(modal_width, modal_height, f.size());
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let inner_area = area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // Question
+                Constraint::Length(1), // Usage
+                Constraint::Length(1), // Buttons
+            ])
             .split(inner_area);
 
         f.render_widget(
             Paragraph::new(question).alignment(ratatui::layout::Alignment::Center),
             chunks[0],
         );
+        let usage_style = if *workout_count > 0 {
+            app.theme.warning
+        } else {
+            app.theme.muted
+        };
         f.render_widget(
-            Paragraph::new(options).alignment(ratatui::layout::Alignment::Center),
+            Paragraph::new(usage)
+                .style(usage_style)
+                .alignment(ratatui::layout::Alignment::Center),
             chunks[1],
         );
+        let button_areas = render_button_pair(f, &app.theme, chunks[2], "Yes", "No", None);
+        app.click_areas.push_buttons(button_areas);
     }
 }
@@ -0,0 +1,149 @@
+// src/ui/modals/copy_day.rs
+use super::helpers::{render_button_pair, render_error_message, render_input_field};
+use crate::{
+    app::{
+        set_meta::SetMeta,
+        state::{ActiveModal, CopyDayField},
+        App,
+    },
+    ui::layout::centered_rect,
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin},
+    style::{Modifier, Style, Stylize},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+pub(super) fn render_copy_day_modal(f: &mut Frame, app: &mut App) {
+    if let ActiveModal::CopyDay {
+        source_date,
+        sets,
+        date_input,
+        selected,
+        focused_field,
+        error_message,
+    } = &app.active_modal
+    {
+        let block = Block::default()
+            .title(format!(
+                "Copy Workouts of {}",
+                source_date.format("%Y-%m-%d")
+            ))
+            .borders(Borders::ALL)
+            .border_style(app.theme.modal_border);
+
+        let has_error = error_message.is_some();
+        // Date field, table header + rows, hint, buttons, error
+        let height = 2 + 2 + sets.len() as u16 + 1 + 1 + u16::from(has_error) + 2;
+        let area = centered_rect(76, height, f.size());
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let inner_area = area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        let mut constraints = vec![
+            Constraint::Length(2), // Date field
+            Constraint::Min(3),    // Sets table
+            Constraint::Length(1), // Key hint
+            Constraint::Length(1), // Buttons row
+        ];
+        if has_error {
+            constraints.push(Constraint::Length(1)); // Error Message
+        }
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(inner_area);
+
+        let date_area = render_input_field(
+            f,
+            &app.theme,
+            chunks[0],
+            "Copy to (YYYY-MM-DD, today, yesterday):",
+            date_input,
+            *focused_field == CopyDayField::Date,
+        );
+
+        // --- Sets Table ---
+        let sets_focused = *focused_field == CopyDayField::Sets;
+        let rows: Vec<Row> = sets
+            .iter()
+            .enumerate()
+            .map(|(i, set)| {
+                let w = &set.workout;
+                let (meta, _) = SetMeta::parse_notes(w.notes.as_deref());
+                let amount = match (w.sets, w.reps, w.duration_minutes) {
+                    (Some(sets), Some(reps), _) => format!("{}x{}", sets, reps),
+                    (None, Some(reps), _) => format!("x{}", reps),
+                    (_, None, Some(minutes)) => format!("{} min", minutes),
+                    _ => "-".to_string(),
+                };
+                let weight_style = if sets_focused && i == *selected {
+                    Style::default().reversed()
+                } else {
+                    Style::default()
+                };
+                let row = Row::new(vec![
+                    Cell::from(if set.include { "[x]" } else { "[ ]" }),
+                    Cell::from(w.exercise_name.clone()),
+                    Cell::from(amount),
+                    Cell::from(set.weight_input.clone()).style(weight_style),
+                    Cell::from(if meta.is_empty() {
+                        String::new()
+                    } else {
+                        meta.describe()
+                    }),
+                ]);
+                if set.include {
+                    row
+                } else {
+                    row.style(app.theme.muted)
+                }
+            })
+            .collect();
+        let header = Row::new(vec!["", "Exercise", "Sets", "Weight", ""])
+            .style(app.theme.header)
+            .bottom_margin(1);
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Min(16),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(16),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_symbol(if sets_focused { ">> " } else { "   " });
+        let mut table_state = TableState::default().with_selected(Some(*selected));
+        f.render_stateful_widget(table, chunks[1], &mut table_state);
+
+        f.render_widget(
+            Paragraph::new("Type/Backspace or +/-: weight | Space: include/skip | Enter: copy")
+                .style(app.theme.muted),
+            chunks[2],
+        );
+
+        let button_focus = match focused_field {
+            CopyDayField::Confirm => Some(0),
+            CopyDayField::Cancel => Some(1),
+            _ => None,
+        };
+        let button_areas =
+            render_button_pair(f, &app.theme, chunks[3], "Copy", "Cancel", button_focus);
+        app.click_areas.push_buttons(button_areas);
+
+        if has_error {
+            render_error_message(f, &app.theme, chunks[4], error_message.as_ref());
+        }
+
+        if *focused_field == CopyDayField::Date {
+            let cursor_x = (date_area.x + date_input.chars().count() as u16)
+                .min(date_area.right().saturating_sub(1));
+            f.set_cursor(cursor_x, date_area.y);
+        }
+    }
+}
@@ -2,19 +2,20 @@ use super::helpers::{render_button_pair, render_error_message, render_input_fiel
 use crate::{
     app::{
         state::{ActiveModal, AddExerciseField},
+        theme::Theme,
         App,
     },
     ui::layout::centered_rect,
 };
 use ratatui::{
     layout::{Constraint, Direction, Layout, Margin, Rect},
-    style::{Color, Modifier, Style, Stylize},
+    style::Modifier,
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 use task_athlete_lib::ExerciseType;
 
-pub(super) fn render_create_exercise_modal(f: &mut Frame, app: &App) {
+pub(super) fn render_create_exercise_modal(f: &mut Frame, app: &mut App) {
     if let ActiveModal::CreateExercise {
         name_input,
         muscles_input,
@@ -25,12 +26,17 @@ pub(super) fn render_create_exercise_modal(f: &mut Frame, app: &App) {
         log_reps,
         log_duration,
         log_distance,
+        editing,
     } = &app.active_modal
     {
+        let title = match editing {
+            Some(name) => format!("Edit Exercise '{}'", name),
+            None => "Create New Exercise".to_string(),
+        };
         let block = Block::default()
-            .title("Create New Exercise")
+            .title(title)
             .borders(Borders::ALL)
-            .border_style(Style::new().yellow());
+            .border_style(app.theme.modal_border);
 
         let has_error = error_message.is_some();
         // Calculate required height:
@@ -77,6 +83,7 @@ pub(super) fn render_create_exercise_modal(f: &mut Frame, app: &App) {
 
         let name_text_area = render_input_field(
             f,
+            &app.theme,
             chunks[0],
             "Name:",
             name_input,
@@ -84,6 +91,7 @@ pub(super) fn render_create_exercise_modal(f: &mut Frame, app: &App) {
         );
         let muscles_text_area = render_input_field(
             f,
+            &app.theme,
             chunks[1],
             "Muscles (comma-separated):",
             muscles_input,
@@ -91,12 +99,13 @@ pub(super) fn render_create_exercise_modal(f: &mut Frame, app: &App) {
         );
 
         f.render_widget(Paragraph::new("Type:"), chunks[2]);
-        render_exercise_type_options(f, chunks[3], selected_type, focused_field);
+        render_exercise_type_options(f, &app.theme, chunks[3], selected_type, focused_field);
 
         f.render_widget(Paragraph::new("Logging:"), chunks[4]);
 
         render_log_flag_checkboxes(
             f,
+            &app.theme,
             chunks[5], // Chunk for checkboxes
             *log_weight,
             *log_reps,
@@ -110,11 +119,25 @@ pub(super) fn render_create_exercise_modal(f: &mut Frame, app: &App) {
             AddExerciseField::Cancel => Some(1),
             _ => None,
         };
-        render_button_pair(f, chunks[7], "OK", "Cancel", button_focus); // Buttons in chunk 5 (after spacer)
+        let confirm_label = if editing.is_some() { "Save" } else { "OK" };
+        let button_areas = render_button_pair(
+            f,
+            &app.theme,
+            chunks[7],
+            confirm_label,
+            "Cancel",
+            button_focus,
+        ); // Buttons in chunk 5 (after spacer)
+        app.click_areas.push_buttons(button_areas);
 
         let error_chunk_index = 8;
         if chunks.len() > error_chunk_index {
-            render_error_message(f, chunks[error_chunk_index], error_message.as_ref());
+            render_error_message(
+                f,
+                &app.theme,
+                chunks[error_chunk_index],
+                error_message.as_ref(),
+            );
         }
 
         // Cursor Positioning
@@ -132,6 +155,7 @@ pub(super) fn render_create_exercise_modal(f: &mut Frame, app: &App) {
 /// Renders the exercise type selection buttons.
 fn render_exercise_type_options(
     f: &mut Frame,
+    theme: &Theme,
     area: Rect,
     selected_type: &ExerciseType,
     focused_field: &AddExerciseField,
@@ -145,7 +169,7 @@ fn render_exercise_type_options(
         ])
         .split(area);
 
-    let base_style = Style::default().fg(Color::White);
+    let base_style = theme.text;
     let types = [
         ExerciseType::Resistance,
         ExerciseType::Cardio,
@@ -163,7 +187,7 @@ fn render_exercise_type_options(
         let is_focused = *focused_field == focus_fields[i];
 
         let mut style = if is_selected {
-            base_style.bg(Color::DarkGray)
+            base_style.patch(theme.selected)
         } else {
             base_style
         };
@@ -207,6 +231,7 @@ fn position_cursor_for_create_exercise(
 /// Renders the logging flag checkboxes.
 fn render_log_flag_checkboxes(
     f: &mut Frame,
+    theme: &Theme,
     area: Rect,
     log_weight: bool,
     log_reps: bool,
@@ -237,13 +262,11 @@ fn render_log_flag_checkboxes(
         let is_focused = *focused_field == fields[i];
         let state_char = if states[i] { "[x]" } else { "[ ]" };
         let text = format!("{} {}", state_char, labels[i]);
-        let style = Style::default()
-            .fg(Color::White)
-            .add_modifier(if is_focused {
-                Modifier::REVERSED
-            } else {
-                Modifier::empty()
-            });
+        let style = theme.text.add_modifier(if is_focused {
+            Modifier::REVERSED
+        } else {
+            Modifier::empty()
+        });
         f.render_widget(Paragraph::new(text).style(style), checkboxes_layout[i]);
     }
 }
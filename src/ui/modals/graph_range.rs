@@ -0,0 +1,97 @@
+use super::helpers::{render_button_pair, render_error_message, render_input_field};
+use crate::{
+    app::{
+        state::{ActiveModal, GraphRangeField},
+        App,
+    },
+    ui::layout::centered_rect,
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin},
+    widgets::{Block, Borders, Clear},
+    Frame,
+};
+
+pub(super) fn render_graph_range_modal(f: &mut Frame, app: &mut App) {
+    if let ActiveModal::GraphRange {
+        start_input,
+        end_input,
+        focused_field,
+        error_message,
+    } = &app.active_modal
+    {
+        let block = Block::default()
+            .title("Graph Date Range")
+            .borders(Borders::ALL)
+            .border_style(app.theme.modal_border);
+
+        let has_error = error_message.is_some();
+        let height = 8 + u16::from(has_error); // Base height + error line
+        let area = centered_rect(60, height, f.size());
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let inner_area = area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+
+        let mut constraints = vec![
+            Constraint::Length(2), // Start field
+            Constraint::Length(2), // End field
+            Constraint::Length(1), // Buttons row
+        ];
+        if has_error {
+            constraints.push(Constraint::Length(1)); // Error Message
+        }
+        constraints.push(Constraint::Min(0)); // Fill remainder
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(inner_area);
+
+        let start_text_area = render_input_field(
+            f,
+            &app.theme,
+            chunks[0],
+            "From (YYYY-MM-DD / today/yesterday, empty: first workout):",
+            start_input,
+            *focused_field == GraphRangeField::Start,
+        );
+        let end_text_area = render_input_field(
+            f,
+            &app.theme,
+            chunks[1],
+            "To (YYYY-MM-DD / today/yesterday, empty: last workout):",
+            end_input,
+            *focused_field == GraphRangeField::End,
+        );
+
+        let button_focus = match focused_field {
+            GraphRangeField::Confirm => Some(0),
+            GraphRangeField::Cancel => Some(1),
+            _ => None,
+        };
+        let button_areas =
+            render_button_pair(f, &app.theme, chunks[2], "OK", "Cancel", button_focus);
+        app.click_areas.push_buttons(button_areas);
+
+        if has_error {
+            render_error_message(f, &app.theme, chunks[3], error_message.as_ref());
+        }
+
+        // --- Cursor Positioning ---
+        let cursor = match focused_field {
+            GraphRangeField::Start => Some((start_text_area, start_input)),
+            GraphRangeField::End => Some((end_text_area, end_input)),
+            _ => None, // No cursor for buttons
+        };
+        if let Some((text_area, input)) = cursor {
+            let cursor_x = (text_area.x + input.chars().count() as u16)
+                .min(text_area.right().saturating_sub(1));
+            f.set_cursor(cursor_x, text_area.y);
+        }
+    }
+}
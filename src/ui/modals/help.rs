@@ -1,70 +1,56 @@
+use crate::app::{keymap::KeyContext, App};
 use crate::ui::layout::centered_rect;
 use ratatui::{
     layout::Margin,
     style::{Style, Stylize},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
-pub(super) fn render_help_modal(f: &mut Frame) {
+/// Clamps `app.help_scroll` to the generated text, since the input handler can't measure it.
+pub(super) fn render_help_modal(f: &mut Frame, app: &mut App) {
     let block = Block::default()
         .title("Help (?)")
         .borders(Borders::ALL)
         .title_style(Style::new().bold())
-        .border_style(Style::new().yellow());
-    let area = centered_rect(60, 70, f.size());
+        .border_style(app.theme.modal_border);
+    let area = centered_rect(80, 70, f.size());
     f.render_widget(Clear, area);
     f.render_widget(block, area);
 
-    let help_text = vec![
-        Line::from("--- Global ---").style(Style::new().bold().underlined()),
-        Line::from(" Q: Quit Application"),
-        Line::from(" ?: Show/Hide This Help"),
-        Line::from(" F1-F4: Switch Tabs"),
-        Line::from(""),
-        Line::from("--- Log Tab (F1) ---").style(Style::new().bold().underlined()),
-        Line::from(" k / ↑: Navigate Up"),
-        Line::from(" j / ↓: Navigate Down"),
-        Line::from(" Tab: Switch Focus (Exercises List <=> Sets Table)"),
-        Line::from(" h / ←: View Previous Day"),
-        Line::from(" l / →: View Next Day"),
-        Line::from(" a: Add New Workout Entry (for viewed day)"),
-        Line::from(" c: Create New Exercise Definition"),
-        Line::from(" e / Enter: Edit Selected Set/Entry (in Sets Table)"),
-        Line::from(" d / Delete: Delete Selected Set/Entry (in Sets Table)"),
-        Line::from(" g: Go to Graphs for Selected Exercise (TODO)"),
-        Line::from(""),
-        Line::from("--- Exercises Tab (F2) ---").style(Style::new().bold().underlined()),
-        Line::from(" k/j / ↑/↓: Navigate List"),
-        Line::from(" Enter: View/Edit Selected Exercise (TODO)"),
-        Line::from(" d / Delete: Delete Selected Exercise (TODO)"),
-        Line::from(" c: Create New Exercise Definition"),
-        Line::from(" /: Start Search/Filter"),
-        Line::from(" Esc: Clear Search/Filter"),
-        Line::from(""),
-        Line::from("--- Planning Tab (F3) ---").style(Style::new().bold().underlined()),
-        Line::from(" (TODO)"),
-        Line::from(""),
-        Line::from("--- Bodyweight Tab (F4) ---").style(Style::new().bold().underlined()),
-        Line::from(" Tab: Cycle Focus (Graph, Actions, History) (TODO)"),
-        Line::from(" k/j / ↑/↓: Navigate History Table (when focused)"),
-        Line::from(" l: Log New Bodyweight Entry"),
-        Line::from(" t: Set/Clear Target Bodyweight"),
-        Line::from(" r: Cycle Graph Time Range (1M > 3M > 6M > 1Y > All)"),
+    // Keys come from the active keymap, so rebinding them in keymap.toml shows up here
+    let mut help_text = Vec::new();
+    for context in KeyContext::ALL {
+        help_text.push(Line::from(format!("--- {} ---", context.title())).style(app.theme.title));
+        for binding in app.keymap.bindings(context) {
+            let keys = if binding.keys.is_empty() {
+                "(unbound)".to_string()
+            } else {
+                binding.keys_label()
+            };
+            help_text.push(Line::from(format!(" {}: {}", keys, binding.spec.help)));
+        }
+        help_text.push(Line::from(""));
+    }
+    help_text.extend([
+        Line::from("--- In Modals (fixed keys) ---").style(app.theme.title),
+        Line::from(" Tab/↑/↓: Move Between Fields, Enter: Confirm/Next, Esc: Cancel"),
+        Line::from(" Set Type field: ←/→ to Cycle (Warm-up, Working, Drop, Failure, AMRAP)"),
         Line::from(""),
         Line::from(Span::styled(
-            " Press Esc, ?, or Enter to close ",
-            Style::new().italic().yellow(),
+            " ↑/↓ or PgUp/PgDn to scroll, Esc or Enter to close ",
+            app.theme.warning.italic(),
         )),
-    ];
+    ]);
+
+    let inner_area = area.inner(&Margin {
+        vertical: 1,
+        horizontal: 1,
+    });
+    let max_scroll = (help_text.len() as u16).saturating_sub(inner_area.height);
+    app.help_scroll = app.help_scroll.min(max_scroll);
 
-    let paragraph = Paragraph::new(help_text).wrap(Wrap { trim: false });
-    f.render_widget(
-        paragraph,
-        area.inner(&Margin {
-            vertical: 1,
-            horizontal: 1,
-        }),
-    );
+    let paragraph = Paragraph::new(help_text).scroll((app.help_scroll, 0));
+    f.render_widget(paragraph, inner_area);
 }
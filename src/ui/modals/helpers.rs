@@ -1,6 +1,8 @@
+use crate::app::{suggestions::ExerciseSuggestion, theme::Theme};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Margin, Rect},
-    style::{Color, Modifier, Style, Stylize},
+    style::Stylize,
+    text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame,
 };
@@ -8,8 +10,9 @@ use ratatui::{
 // --- Rendering Helpers ---
 
 /// Renders a labeled input field and returns the area used by the input paragraph itself.
-pub(super) fn render_input_field(
+pub(crate) fn render_input_field(
     f: &mut Frame,
+    theme: &Theme,
     area: Rect, // The Rect allocated for this field (label + input line)
     label: &str,
     value: &str,
@@ -25,7 +28,7 @@ pub(super) fn render_input_field(
         f.render_widget(Paragraph::new(label), chunks[0]);
     }
 
-    let base_input_style = Style::default().fg(Color::White);
+    let base_input_style = theme.text;
     let input_style = if is_focused {
         base_input_style.reversed()
     } else {
@@ -49,19 +52,21 @@ pub(super) fn render_input_field(
 }
 
 /// Renders a standard horizontal pair of buttons (e.g., OK/Cancel).
+/// Returns the area of each button, for mouse clicks.
 pub(super) fn render_button_pair(
     f: &mut Frame,
+    theme: &Theme,
     area: Rect,
     label1: &str,
     label2: &str,
     focused_button: Option<u8>, // 0 for first, 1 for second
-) {
+) -> (Rect, Rect) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    let base_style = Style::default().fg(Color::White);
+    let base_style = theme.text;
 
     let style1 = if focused_button == Some(0) {
         base_style.reversed()
@@ -86,21 +91,25 @@ pub(super) fn render_button_pair(
             .style(style2),
         chunks[1],
     );
+    (chunks[0], chunks[1])
 }
 
 /// Renders an optional error message line.
-pub(super) fn render_error_message(f: &mut Frame, area: Rect, error_message: Option<&String>) {
+pub(super) fn render_error_message(
+    f: &mut Frame,
+    theme: &Theme,
+    area: Rect,
+    error_message: Option<&String>,
+) {
     if let Some(err) = error_message {
-        f.render_widget(
-            Paragraph::new(err.as_str()).style(Style::default().fg(Color::Red)),
-            area,
-        );
+        f.render_widget(Paragraph::new(err.as_str()).style(theme.error), area);
     }
 }
 
 // Helper function to render a pair of input fields horizontally
 pub(super) fn render_horizontal_input_pair(
     f: &mut Frame,
+    theme: &Theme,
     area: Rect,
     label1: &str,
     value1: &str,
@@ -115,15 +124,34 @@ pub(super) fn render_horizontal_input_pair(
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    let text_area1 = render_input_field(f, chunks[0], label1, value1, is_focused1);
-    let text_area2 = render_input_field(f, chunks[1], label2, value2, is_focused2);
+    let text_area1 = render_input_field(f, theme, chunks[0], label1, value1, is_focused1);
+    let text_area2 = render_input_field(f, theme, chunks[1], label2, value2, is_focused2);
     (text_area1, text_area2)
 }
 
+/// Splits `text` into spans with the chars at `positions` (fuzzy matches) highlighted.
+pub(crate) fn highlight_matches(
+    theme: &Theme,
+    text: &str,
+    positions: &[usize],
+) -> Vec<Span<'static>> {
+    text.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if positions.contains(&i) {
+                Span::styled(c.to_string(), theme.warning.bold())
+            } else {
+                Span::raw(c.to_string())
+            }
+        })
+        .collect()
+}
+
 /// Renders the exercise suggestions popup list below a given input area.
 pub(super) fn render_exercise_suggestions_popup(
     f: &mut Frame,
-    suggestions: &[String],
+    theme: &Theme,
+    suggestions: &[ExerciseSuggestion],
     list_state: &ListState,
     input_area: Rect, // The area of the exercise input field
 ) {
@@ -143,17 +171,20 @@ pub(super) fn render_exercise_suggestions_popup(
         height: suggestions_height.min(f.size().height.saturating_sub(suggestions_y)),
     };
 
+    // Aliases show the exercise they resolve to
     let list_items: Vec<ListItem> = suggestions
         .iter()
-        .map(|s| ListItem::new(s.as_str()))
+        .map(|s| {
+            let mut spans = highlight_matches(theme, &s.identifier, &s.positions);
+            if let Some(canonical) = &s.canonical {
+                spans.push(Span::styled(format!(" → {}", canonical), theme.muted));
+            }
+            ListItem::new(Line::from(spans))
+        })
         .collect();
     let suggestions_list = List::new(list_items)
         .block(Block::default().borders(Borders::ALL).title("Suggestions"))
-        .highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        )
+        .highlight_style(theme.selected)
         .highlight_symbol("> ");
 
     f.render_widget(Clear, popup_area); // Clear the area behind the popup
@@ -162,8 +193,14 @@ pub(super) fn render_exercise_suggestions_popup(
 }
 
 /// Renders a single centered button.
-pub(super) fn render_button(f: &mut Frame, area: Rect, label: &str, is_focused: bool) {
-    let base_style = Style::default().fg(Color::White);
+pub(super) fn render_button(
+    f: &mut Frame,
+    theme: &Theme,
+    area: Rect,
+    label: &str,
+    is_focused: bool,
+) {
+    let base_style = theme.text;
     let style = if is_focused {
         base_style.reversed()
     } else {
@@ -8,13 +8,12 @@ use crate::{
 };
 use ratatui::{
     layout::{Constraint, Direction, Layout, Margin, Rect},
-    style::{Style, Stylize},
     widgets::{Block, Borders, Clear},
     Frame,
 };
 use task_athlete_lib::Units;
 
-pub(super) fn render_log_bodyweight_modal(f: &mut Frame, app: &App) {
+pub(super) fn render_log_bodyweight_modal(f: &mut Frame, app: &mut App) {
     if let ActiveModal::LogBodyweight {
         weight_input,
         date_input,
@@ -29,7 +28,7 @@ pub(super) fn render_log_bodyweight_modal(f: &mut Frame, app: &App) {
         let block = Block::default()
             .title("Log New Bodyweight")
             .borders(Borders::ALL)
-            .border_style(Style::new().yellow());
+            .border_style(app.theme.modal_border);
 
         let has_error = error_message.is_some();
         let height = 8 + u16::from(has_error); // Base height + error line
@@ -60,6 +59,7 @@ pub(super) fn render_log_bodyweight_modal(f: &mut Frame, app: &App) {
 
         let weight_text_area = render_input_field(
             f,
+            &app.theme,
             chunks[0],
             &format!("Weight ({weight_unit}):"),
             weight_input,
@@ -68,6 +68,7 @@ pub(super) fn render_log_bodyweight_modal(f: &mut Frame, app: &App) {
 
         let date_text_area = render_input_field(
             f,
+            &app.theme,
             chunks[1],
             "Date (YYYY-MM-DD / today/yesterday):",
             date_input,
@@ -79,11 +80,18 @@ pub(super) fn render_log_bodyweight_modal(f: &mut Frame, app: &App) {
             LogBodyweightField::Cancel => Some(1),
             _ => None,
         };
-        render_button_pair(f, chunks[2], "OK", "Cancel", button_focus);
+        let button_areas =
+            render_button_pair(f, &app.theme, chunks[2], "OK", "Cancel", button_focus);
+        app.click_areas.push_buttons(button_areas);
 
         let error_chunk_index = 3;
         if chunks.len() > error_chunk_index {
-            render_error_message(f, chunks[error_chunk_index], error_message.as_ref());
+            render_error_message(
+                f,
+                &app.theme,
+                chunks[error_chunk_index],
+                error_message.as_ref(),
+            );
         }
 
         // --- Cursor Positioning ---
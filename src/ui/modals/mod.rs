@@ -1,9 +1,15 @@
+mod aliases;
+mod bulk;
 mod confirmation;
+mod copy_day;
 mod create_exercise;
+mod graph_range;
 mod help;
-mod helpers; // Keep helpers private to the modals module unless needed elsewhere
+pub(crate) mod helpers; // Also used by tabs that embed input fields (Calendar filter)
 mod log_bodyweight;
 mod pb_modal;
+mod routines;
+mod session_summary;
 mod set_target_weight;
 mod workout;
 
@@ -12,10 +18,10 @@ use crate::app::App;
 use ratatui::Frame;
 
 /// Renders the currently active modal, if any.
-pub fn render(f: &mut Frame, app: &App) {
+pub fn render(f: &mut Frame, app: &mut App) {
     // Dispatch rendering to the appropriate function based on the active modal
     match &app.active_modal {
-        ActiveModal::Help => help::render_help_modal(f),
+        ActiveModal::Help => help::render_help_modal(f, app),
         ActiveModal::LogBodyweight { .. } => log_bodyweight::render_log_bodyweight_modal(f, app),
         ActiveModal::SetTargetWeight { .. } => {
             set_target_weight::render_set_target_weight_modal(f, app)
@@ -25,9 +31,23 @@ pub fn render(f: &mut Frame, app: &App) {
         ActiveModal::EditWorkout { .. } => workout::render_edit_workout_modal(f, app),
         ActiveModal::ConfirmDeleteWorkout { .. } => confirmation::render_confirmation_modal(f, app),
         ActiveModal::PersonalBest { .. } => pb_modal::render(f, app),
+        ActiveModal::SessionSummary { .. } => session_summary::render(f, app),
+        ActiveModal::Routines { .. } => routines::render_routines_modal(f, app),
+        ActiveModal::CreateRoutine { .. } => routines::render_create_routine_modal(f, app),
         ActiveModal::ConfirmDeleteBodyWeight { .. } => {
             confirmation::render_confirmation_bodyweight_modal(f, app);
         }
+        ActiveModal::ConfirmDeleteExercise { .. } => {
+            confirmation::render_confirmation_exercise_modal(f, app);
+        }
+        ActiveModal::AliasManager { .. } => aliases::render_alias_manager_modal(f, app),
+        ActiveModal::ConfirmDeleteWorkouts { .. } => {
+            bulk::render_confirm_delete_workouts_modal(f, app);
+        }
+        ActiveModal::BulkDate { .. } => bulk::render_bulk_date_modal(f, app),
+        ActiveModal::BulkEdit { .. } => bulk::render_bulk_edit_modal(f, app),
+        ActiveModal::CopyDay { .. } => copy_day::render_copy_day_modal(f, app),
+        ActiveModal::GraphRange { .. } => graph_range::render_graph_range_modal(f, app),
         ActiveModal::None => {} // Do nothing if no modal is active
     }
 }
@@ -1,6 +1,7 @@
 // src/ui/modals/pb_modal.rs
 use crate::{
     app::{
+        mouse::{ClickTarget, ModalButton},
         state::{ActiveModal, PbModalField},
         App,
     },
@@ -16,7 +17,7 @@ use ratatui::{
 };
 use task_athlete_lib::Units; // Import Units
 
-pub(super) fn render(f: &mut Frame, app: &App) {
+pub(super) fn render(f: &mut Frame, app: &mut App) {
     if let ActiveModal::PersonalBest {
         exercise_name,
         pb_info,
@@ -26,7 +27,7 @@ pub(super) fn render(f: &mut Frame, app: &App) {
         let block = Block::default()
             .title("🎉 Personal Best! 🎉")
             .borders(Borders::ALL)
-            .border_style(Style::new().yellow().bold());
+            .border_style(app.theme.pb);
 
         let mut text_lines = vec![Line::from(Span::styled(
             exercise_name.as_str(),
@@ -98,6 +99,14 @@ pub(super) fn render(f: &mut Frame, app: &App) {
 
         f.render_widget(Paragraph::new(Text::from(text_lines)), chunks[0]);
 
-        helpers::render_button(f, chunks[1], "OK", *focused_field == PbModalField::OkButton);
+        helpers::render_button(
+            f,
+            &app.theme,
+            chunks[1],
+            "OK",
+            *focused_field == PbModalField::OkButton,
+        );
+        app.click_areas
+            .push(chunks[1], ClickTarget::Button(ModalButton::Confirm));
     }
 }
@@ -0,0 +1,181 @@
+// src/ui/modals/routines.rs
+use super::helpers::{render_button_pair, render_error_message, render_input_field};
+use crate::{
+    app::{
+        keymap::{Action, KeyContext},
+        state::{ActiveModal, CreateRoutineField},
+        App,
+    },
+    ui::layout::centered_rect,
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+pub(super) fn render_routines_modal(f: &mut Frame, app: &App) {
+    if let ActiveModal::Routines { list_state } = &app.active_modal {
+        let block = Block::default()
+            .title(format!(
+                "Routines - apply to {}",
+                app.log_viewed_date.format("%Y-%m-%d")
+            ))
+            .borders(Borders::ALL)
+            .border_style(app.theme.modal_border);
+
+        let routines = &app.routines.routines;
+        let list_height = (routines.len() as u16 * 2).clamp(2, 16);
+        let area = centered_rect(70, list_height + 4, f.size()); // List + hint + borders
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let inner_area = area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(inner_area);
+
+        if routines.is_empty() {
+            f.render_widget(
+                Paragraph::new("No routines yet. Press 'n' to create one.").italic(),
+                chunks[0],
+            );
+        } else {
+            // Name on the first line, the exercises underneath
+            let items: Vec<ListItem> = routines
+                .iter()
+                .map(|routine| {
+                    let entries = routine
+                        .entries
+                        .iter()
+                        .map(|e| format!("{} {}", e.exercise, e.scheme()))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    ListItem::new(vec![
+                        Line::from(Span::styled(routine.name.clone(), Style::new().bold())),
+                        Line::from(Span::styled(format!("  {}", entries), app.theme.axis)),
+                    ])
+                })
+                .collect();
+            let list = List::new(items)
+                .highlight_style(app.theme.selected)
+                .highlight_symbol("> ");
+            let mut state = list_state.clone(); // Clone for rendering
+            f.render_stateful_widget(list, chunks[0], &mut state);
+        }
+
+        let hint = app
+            .keymap
+            .hints(KeyContext::Routines, |action| {
+                !matches!(action, Action::Up | Action::Down)
+            })
+            .join(" | ");
+        f.render_widget(Paragraph::new(hint).style(app.theme.muted), chunks[1]);
+    }
+}
+
+pub(super) fn render_create_routine_modal(f: &mut Frame, app: &mut App) {
+    if let ActiveModal::CreateRoutine {
+        name_input,
+        entries_input,
+        focused_field,
+        error_message,
+    } = &app.active_modal
+    {
+        let block = Block::default()
+            .title("Create Routine")
+            .borders(Borders::ALL)
+            .border_style(app.theme.modal_border);
+
+        let has_error = error_message.is_some();
+        let height = 11 + u16::from(has_error); // Base height + error line
+        let area = centered_rect(70, height, f.size());
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let inner_area = area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+
+        let mut constraints = vec![
+            Constraint::Length(2), // Name field
+            Constraint::Length(2), // Entries field
+            Constraint::Length(2), // Format hint
+            Constraint::Length(1), // Buttons row
+        ];
+        if has_error {
+            constraints.push(Constraint::Length(1)); // Error Message
+        }
+        constraints.push(Constraint::Min(0)); // Fill remainder
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(inner_area);
+
+        let name_area = render_input_field(
+            f,
+            &app.theme,
+            chunks[0],
+            "Name:",
+            name_input,
+            *focused_field == CreateRoutineField::Name,
+        );
+        let entries_area = render_input_field(
+            f,
+            &app.theme,
+            chunks[1],
+            "Exercises (comma separated):",
+            entries_input,
+            *focused_field == CreateRoutineField::Entries,
+        );
+        f.render_widget(
+            Paragraph::new(
+                "e.g. bench 3x5 @60, ohp 3x8, dips 3xAMRAP. Weight as in Add Workout, optional.",
+            )
+            .style(app.theme.muted)
+            .wrap(Wrap { trim: true }),
+            chunks[2],
+        );
+
+        let button_focus = match focused_field {
+            CreateRoutineField::Confirm => Some(0),
+            CreateRoutineField::Cancel => Some(1),
+            _ => None,
+        };
+        let button_areas =
+            render_button_pair(f, &app.theme, chunks[3], "Save", "Cancel", button_focus);
+        app.click_areas.push_buttons(button_areas);
+
+        let error_chunk_index = 4;
+        if chunks.len() > error_chunk_index {
+            render_error_message(
+                f,
+                &app.theme,
+                chunks[error_chunk_index],
+                error_message.as_ref(),
+            );
+        }
+
+        // --- Cursor Positioning ---
+        let cursor = match focused_field {
+            CreateRoutineField::Name => Some((name_input, name_area)),
+            CreateRoutineField::Entries => Some((entries_input, entries_area)),
+            _ => None, // No cursor for buttons
+        };
+        if let Some((input, input_area)) = cursor {
+            let cursor_x = (input_area.x + input.chars().count() as u16)
+                .min(input_area.right().saturating_sub(1));
+            f.set_cursor(cursor_x, input_area.y);
+        }
+    }
+}
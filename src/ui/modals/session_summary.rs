@@ -0,0 +1,131 @@
+// src/ui/modals/session_summary.rs
+use crate::{
+    app::{
+        mouse::{ClickTarget, ModalButton},
+        session::format_elapsed,
+        state::ActiveModal,
+        App,
+    },
+    ui::layout::centered_rect,
+    ui::modals::helpers,
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin},
+    style::{Style, Stylize},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use task_athlete_lib::{PBInfo, Units};
+
+pub(super) fn render(f: &mut Frame, app: &mut App) {
+    if let ActiveModal::SessionSummary { summary } = &app.active_modal {
+        let block = Block::default()
+            .title("Session Complete")
+            .borders(Borders::ALL)
+            .border_style(app.theme.success.bold());
+
+        let units = app.service.config.units;
+        let (weight_unit, factor) = match units {
+            Units::Metric => ("kg", 1.0),
+            Units::Imperial => ("lbs", 2.20462),
+        };
+
+        let mut text_lines = vec![
+            Line::from(format!(
+                "Started {}, lasted {}",
+                summary.started_at.format("%H:%M"),
+                format_elapsed(summary.duration)
+            )),
+            Line::from(" "), // Spacer
+            Line::from(Span::styled(
+                "Exercises",
+                Style::default().bold().underlined(),
+            )),
+        ];
+
+        if summary.exercises.is_empty() {
+            text_lines.push(Line::from("  Nothing logged this session"));
+        }
+        for exercise in &summary.exercises {
+            text_lines.push(Line::from(format!(
+                "  {:<24} {:>3} sets {:>9.0} {}",
+                exercise.name,
+                exercise.sets,
+                exercise.volume * factor,
+                weight_unit
+            )));
+        }
+        text_lines.push(Line::from(vec![
+            Span::styled("  Total", Style::default().bold()),
+            Span::raw(format!(
+                "{:<19} {:>3} sets {:>9.0} {}",
+                "",
+                summary.total_sets(),
+                summary.total_volume() * factor,
+                weight_unit
+            )),
+        ]));
+
+        if !summary.pbs.is_empty() {
+            text_lines.push(Line::from(" "));
+            text_lines.push(Line::from(Span::styled(
+                "Personal Bests",
+                app.theme.pb.underlined(),
+            )));
+            for (exercise_name, pb_info) in &summary.pbs {
+                text_lines.push(Line::from(format!(
+                    "  {}: {}",
+                    exercise_name,
+                    describe_pb(pb_info, units)
+                )));
+            }
+        }
+        text_lines.push(Line::from(" ")); // Spacer
+
+        let modal_height = text_lines.len() as u16 + 4; // Content + borders + button
+        let area = centered_rect(60, modal_height, f.size());
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let inner_area = area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(1)])
+            .split(inner_area);
+
+        f.render_widget(Paragraph::new(Text::from(text_lines)), chunks[0]);
+        helpers::render_button(f, &app.theme, chunks[1], "OK", true);
+        app.click_areas
+            .push(chunks[1], ClickTarget::Button(ModalButton::Confirm));
+    }
+}
+
+/// One-line description of which records were broken, e.g. `weight 100.0 kg, reps 8`.
+fn describe_pb(pb_info: &PBInfo, units: Units) -> String {
+    let (weight_unit, dist_unit, weight_factor, dist_factor) = match units {
+        Units::Metric => ("kg", "km", 1.0, 1.0),
+        Units::Imperial => ("lbs", "mi", 2.20462, 0.621371),
+    };
+    let mut parts = Vec::new();
+    if pb_info.weight.achieved {
+        let weight = pb_info.weight.new_value.unwrap_or(0.0) * weight_factor;
+        parts.push(format!("weight {:.1} {}", weight, weight_unit));
+    }
+    if pb_info.reps.achieved {
+        parts.push(format!("reps {}", pb_info.reps.new_value.unwrap_or(0)));
+    }
+    if pb_info.duration.achieved {
+        let duration = pb_info.duration.new_value.unwrap_or(0);
+        parts.push(format!("duration {} min", duration));
+    }
+    if pb_info.distance.achieved {
+        let distance = pb_info.distance.new_value.unwrap_or(0.0) * dist_factor;
+        parts.push(format!("distance {:.1} {}", distance, dist_unit));
+    }
+    parts.join(", ")
+}
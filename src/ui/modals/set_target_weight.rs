@@ -1,20 +1,22 @@
 use super::helpers::{render_error_message, render_input_field};
 use crate::{
     app::{
+        mouse::{ClickTarget, ModalButton},
         state::{ActiveModal, SetTargetWeightField},
+        theme::Theme,
         App,
     },
     ui::layout::centered_rect,
 };
 use ratatui::{
     layout::{Constraint, Direction, Layout, Margin, Rect},
-    style::{Color, Style, Stylize},
+    style::Stylize,
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 use task_athlete_lib::Units;
 
-pub(super) fn render_set_target_weight_modal(f: &mut Frame, app: &App) {
+pub(super) fn render_set_target_weight_modal(f: &mut Frame, app: &mut App) {
     if let ActiveModal::SetTargetWeight {
         weight_input,
         focused_field,
@@ -28,7 +30,7 @@ pub(super) fn render_set_target_weight_modal(f: &mut Frame, app: &App) {
         let block = Block::default()
             .title("Set Target Bodyweight")
             .borders(Borders::ALL)
-            .border_style(Style::new().yellow());
+            .border_style(app.theme.modal_border);
 
         let has_error = error_message.is_some();
         let height = 5 + u16::from(has_error);
@@ -58,17 +60,27 @@ pub(super) fn render_set_target_weight_modal(f: &mut Frame, app: &App) {
 
         let weight_text_area = render_input_field(
             f,
+            &app.theme,
             chunks[0],
             &format!("Target Weight ({weight_unit}):"),
             weight_input,
             *focused_field == SetTargetWeightField::Weight,
         );
 
-        render_target_weight_buttons(f, chunks[1], focused_field);
+        let (set_area, clear_area, cancel_area) =
+            render_target_weight_buttons(f, &app.theme, chunks[1], focused_field);
+        app.click_areas.push_buttons((set_area, cancel_area));
+        app.click_areas
+            .push(clear_area, ClickTarget::Button(ModalButton::Clear));
 
         let error_chunk_index = 2;
         if chunks.len() > error_chunk_index {
-            render_error_message(f, chunks[error_chunk_index], error_message.as_ref());
+            render_error_message(
+                f,
+                &app.theme,
+                chunks[error_chunk_index],
+                error_message.as_ref(),
+            );
         }
 
         // --- Cursor Positioning ---
@@ -81,7 +93,13 @@ pub(super) fn render_set_target_weight_modal(f: &mut Frame, app: &App) {
 }
 
 /// Renders the three buttons (Set, Clear, Cancel) for the target weight modal.
-fn render_target_weight_buttons(f: &mut Frame, area: Rect, focused_field: &SetTargetWeightField) {
+/// Returns the area of each button, for mouse clicks.
+fn render_target_weight_buttons(
+    f: &mut Frame,
+    theme: &Theme,
+    area: Rect,
+    focused_field: &SetTargetWeightField,
+) -> (Rect, Rect, Rect) {
     let button_layout = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
@@ -91,7 +109,7 @@ fn render_target_weight_buttons(f: &mut Frame, area: Rect, focused_field: &SetTa
         ])
         .split(area);
 
-    let base_style = Style::default().fg(Color::White);
+    let base_style = theme.text;
     let focused_style = base_style.reversed();
 
     f.render_widget(
@@ -124,4 +142,5 @@ fn render_target_weight_buttons(f: &mut Frame, area: Rect, focused_field: &SetTa
             }),
         button_layout[2],
     );
+    (button_layout[0], button_layout[1], button_layout[2])
 }
@@ -5,21 +5,29 @@ use super::helpers::{
 };
 use crate::{
     app::{
+        keymap::{Action, KeyChord, KeyContext},
+        plates::calculate_plates,
+        progression::{ProgressionSuggestion, SuggestionKind},
+        set_meta::SetType,
         state::{ActiveModal, AddWorkoutField, App, WorkoutLogFlags}, // Import WorkoutLogFlags
+        theme::Theme,
     },
     ui::layout::centered_rect,
 };
 use ratatui::{
     layout::{Constraint, Direction, Layout, Margin, Rect},
-    style::{Color, Style, Stylize},
-    widgets::{Block, Borders, Clear, ListState, Paragraph, Wrap},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 use task_athlete_lib::{ExerciseDefinition, ExerciseType, Units};
 
+const PLATE_PANEL_HEIGHT: u16 = 4; // Borders + plates line + status line
+
 // --- Main Render Functions ---
 
-pub(super) fn render_add_workout_modal(f: &mut Frame, app: &App) {
+pub(super) fn render_add_workout_modal(f: &mut Frame, app: &mut App) {
     if let ActiveModal::AddWorkout {
          exercise_input,
          sets_input,
@@ -27,21 +35,32 @@ pub(super) fn render_add_workout_modal(f: &mut Frame, app: &App) {
          weight_input,
          duration_input,
          distance_input,
+         set_type,
+         rpe_input,
+         rir_input,
          notes_input,
          focused_field,
          error_message,
          resolved_exercise,
          exercise_suggestions,
          suggestion_list_state,
+         progression_suggestion,
          .. // Ignore all_exercise_identifiers
      } = &app.active_modal {
          let block = Block::default()
              .title("Add New Workout Entry")
              .borders(Borders::ALL)
-             .border_style(Style::new().yellow());
+             .border_style(app.theme.modal_border);
 
+         // Only show a suggestion that belongs to the exercise currently resolved
+         let progression_suggestion = progression_suggestion.as_ref().filter(|s| {
+             resolved_exercise.as_ref().is_some_and(|def| def.name == s.exercise_name)
+         });
          let flags = WorkoutLogFlags::from_def(resolved_exercise.as_ref());
-         let height = calculate_workout_modal_height(&flags, error_message.is_some());
+         let show_plates = shows_plate_panel(app, resolved_exercise.as_ref());
+         let height = calculate_workout_modal_height(
+             &flags, error_message.is_some(), progression_suggestion.is_some(), show_plates
+         );
          let area = centered_rect(80, height, f.size());
 
          f.render_widget(Clear, area);
@@ -49,15 +68,29 @@ pub(super) fn render_add_workout_modal(f: &mut Frame, app: &App) {
 
          let inner_area = area.inner(&Margin { vertical: 1, horizontal: 1 });
 
-         let input_areas = render_workout_modal_content(
-             f, app, inner_area,
-             "Exercise Name/Alias:".to_string(), true, // Editable
-             exercise_input, sets_input, reps_input, weight_input, duration_input, distance_input, notes_input,
-             focused_field, error_message.as_ref(), resolved_exercise.as_ref(),
-             Some(exercise_suggestions), Some(suggestion_list_state)
-         );
-
-         position_cursor_for_workout(f, focused_field, exercise_input, sets_input, reps_input, weight_input, duration_input, distance_input, notes_input, &input_areas);
+         let fields = WorkoutFields {
+             title_line: "Exercise Name/Alias:",
+             is_exercise_editable: true,
+             exercise_input,
+             sets_input,
+             reps_input,
+             weight_input,
+             duration_input,
+             distance_input,
+             set_type: *set_type,
+             rpe_input,
+             rir_input,
+             notes_input,
+             focused_field,
+             error_message: error_message.as_ref(),
+             resolved_exercise: resolved_exercise.as_ref(),
+             progression_suggestion,
+         };
+         let (input_areas, button_areas) =
+             render_workout_modal_content(f, app, inner_area, &fields, show_plates);
+         app.click_areas.push_buttons(button_areas);
+
+         position_cursor_for_workout(f, &fields, &input_areas);
 
          // Render suggestions popup if needed (after positioning cursor for main input)
          if let (true, Some(suggestions), Some(list_state)) = (
@@ -66,13 +99,13 @@ pub(super) fn render_add_workout_modal(f: &mut Frame, app: &App) {
              Some(suggestion_list_state),
          ) {
              if !input_areas.is_empty() { // Ensure input_areas[0] exists
-                 render_exercise_suggestions_popup(f, suggestions, list_state, input_areas[0]);
+                 render_exercise_suggestions_popup(f, &app.theme, suggestions, list_state, input_areas[0]);
              }
          }
      }
 }
 
-pub(super) fn render_edit_workout_modal(f: &mut Frame, app: &App) {
+pub(super) fn render_edit_workout_modal(f: &mut Frame, app: &mut App) {
     if let ActiveModal::EditWorkout {
          exercise_name,
          sets_input,
@@ -80,6 +113,9 @@ pub(super) fn render_edit_workout_modal(f: &mut Frame, app: &App) {
          weight_input,
          duration_input,
          distance_input,
+         set_type,
+         rpe_input,
+         rir_input,
          notes_input,
          focused_field,
          error_message,
@@ -89,10 +125,11 @@ pub(super) fn render_edit_workout_modal(f: &mut Frame, app: &App) {
          let block = Block::default()
              .title(format!("Edit Workout Entry ({})", exercise_name))
              .borders(Borders::ALL)
-             .border_style(Style::new().yellow());
+             .border_style(app.theme.modal_border);
 
          let flags = WorkoutLogFlags::from_def(resolved_exercise.as_ref());
-         let height = calculate_workout_modal_height(&flags, error_message.is_some());
+         let show_plates = shows_plate_panel(app, resolved_exercise.as_ref());
+         let height = calculate_workout_modal_height(&flags, error_message.is_some(), false, show_plates);
          let area = centered_rect(80, height, f.size());
 
          f.render_widget(Clear, area);
@@ -100,23 +137,63 @@ pub(super) fn render_edit_workout_modal(f: &mut Frame, app: &App) {
 
          let inner_area = area.inner(&Margin { vertical: 1, horizontal: 1 });
 
-         let input_areas = render_workout_modal_content(
-             f, app, inner_area,
-             format!("Exercise: {}", exercise_name), false, // Not editable
-             "", // Exercise input value not needed here
-             sets_input, reps_input, weight_input, duration_input, distance_input, notes_input,
-             focused_field, error_message.as_ref(), resolved_exercise.as_ref(),
-             None, None // No suggestions needed for edit modal
-         );
-
-         position_cursor_for_workout(f, focused_field, "", sets_input, reps_input, weight_input, duration_input, distance_input, notes_input, &input_areas);
+         let title_line = format!("Exercise: {}", exercise_name);
+         let fields = WorkoutFields {
+             title_line: &title_line,
+             is_exercise_editable: false,
+             exercise_input: "", // Exercise input value not needed here
+             sets_input,
+             reps_input,
+             weight_input,
+             duration_input,
+             distance_input,
+             set_type: *set_type,
+             rpe_input,
+             rir_input,
+             notes_input,
+             focused_field,
+             error_message: error_message.as_ref(),
+             resolved_exercise: resolved_exercise.as_ref(),
+             progression_suggestion: None, // No suggestions needed for edit modal
+         };
+         let (input_areas, button_areas) =
+             render_workout_modal_content(f, app, inner_area, &fields, show_plates);
+         app.click_areas.push_buttons(button_areas);
+
+         position_cursor_for_workout(f, &fields, &input_areas);
      }
 }
 
 // --- Shared Rendering Logic ---
 
+/// What the Add and Edit Workout modals show, borrowed from the open modal.
+#[derive(Clone, Copy)]
+struct WorkoutFields<'a> {
+    title_line: &'a str, // e.g., "Exercise: Bench Press" or "Exercise Name/Alias:"
+    is_exercise_editable: bool,
+    exercise_input: &'a str,
+    sets_input: &'a str,
+    reps_input: &'a str,
+    weight_input: &'a str,
+    duration_input: &'a str,
+    distance_input: &'a str,
+    set_type: Option<SetType>,
+    rpe_input: &'a str,
+    rir_input: &'a str,
+    notes_input: &'a str,
+    focused_field: &'a AddWorkoutField,
+    error_message: Option<&'a String>,
+    resolved_exercise: Option<&'a ExerciseDefinition>,
+    progression_suggestion: Option<&'a ProgressionSuggestion>,
+}
+
 /// Calculates the required height dynamically based on visible fields.
-fn calculate_workout_modal_height(flags: &WorkoutLogFlags, has_error: bool) -> u16 {
+fn calculate_workout_modal_height(
+    flags: &WorkoutLogFlags,
+    has_error: bool,
+    has_progression_suggestion: bool,
+    show_plates: bool,
+) -> u16 {
     let mut height = 0;
     height += 1; // Exercise title/label
     height += 1; // Exercise input (always reserve space, even if read-only label)
@@ -129,10 +206,23 @@ fn calculate_workout_modal_height(flags: &WorkoutLogFlags, has_error: bool) -> u
     if flags.log_weight || flags.log_duration {
         height += 2;
     }
+    // Progression suggestion line
+    if has_progression_suggestion {
+        height += 1;
+    }
+    // Plate calculator panel
+    if show_plates {
+        height += PLATE_PANEL_HEIGHT;
+    }
     // Distance row
     if flags.log_distance {
         height += 2;
     }
+    // Set type row, plus RPE/RIR for sets with reps
+    height += 2;
+    if flags.log_effort {
+        height += 2;
+    }
     // Notes
     if flags.log_notes {
         height += 1; // Label
@@ -150,26 +240,33 @@ fn calculate_workout_modal_height(flags: &WorkoutLogFlags, has_error: bool) -> u
 
 /// Renders the common fields for Add/Edit Workout modals.
 /// Returns a Vec of Rects corresponding to the *text input areas* for cursor positioning.
-/// Indices: 0:Exercise(or dummy), 1:Sets, 2:Reps, 3:Weight, 4:Duration, 5:Distance, 6:Notes
+/// Indices: 0:Exercise(or dummy), 1:Sets, 2:Reps, 3:Weight, 4:Duration, 5:Distance, 6:Notes, 7:RPE, 8:RIR
+/// Also returns the areas of the OK and Cancel buttons.
 fn render_workout_modal_content(
     f: &mut Frame,
     app: &App,
-    area: Rect,         // Inner area after block
-    title_line: String, // e.g., "Exercise: Bench Press" or "Exercise Name/Alias:"
-    is_exercise_editable: bool,
-    exercise_input: &str,
-    sets_input: &str,
-    reps_input: &str,
-    weight_input: &str,
-    duration_input: &str,
-    distance_input: &str,
-    notes_input: &str,
-    focused_field: &AddWorkoutField,
-    error_message: Option<&String>,
-    resolved_exercise: Option<&ExerciseDefinition>,
-    _exercise_suggestions: Option<&Vec<String>>, // Handled separately now
-    _suggestion_list_state: Option<&ListState>,  // Handled separately now
-) -> Vec<Rect> {
+    area: Rect, // Inner area after block
+    fields: &WorkoutFields,
+    show_plates: bool,
+) -> (Vec<Rect>, (Rect, Rect)) {
+    let WorkoutFields {
+        title_line,
+        is_exercise_editable,
+        exercise_input,
+        sets_input,
+        reps_input,
+        weight_input,
+        duration_input,
+        distance_input,
+        set_type,
+        rpe_input,
+        rir_input,
+        notes_input,
+        focused_field,
+        error_message,
+        resolved_exercise,
+        progression_suggestion,
+    } = *fields;
     let flags = WorkoutLogFlags::from_def(resolved_exercise); // Get flags
     let (weight_unit, dist_unit) = get_units(&app.service.config.units);
 
@@ -184,9 +281,19 @@ fn render_workout_modal_content(
     if flags.log_weight || flags.log_duration {
         constraints.push(Constraint::Length(2)); // Weight/Duration pair
     }
+    if progression_suggestion.is_some() {
+        constraints.push(Constraint::Length(1)); // Suggested next target
+    }
+    if show_plates {
+        constraints.push(Constraint::Length(PLATE_PANEL_HEIGHT)); // Plate calculator
+    }
     if flags.log_distance {
         constraints.push(Constraint::Length(2)); // Distance field
     }
+    constraints.push(Constraint::Length(2)); // Set type
+    if flags.log_effort {
+        constraints.push(Constraint::Length(2)); // RPE/RIR pair
+    }
     if flags.log_notes {
         constraints.push(Constraint::Length(1)); // Notes label
         constraints.push(Constraint::Length(3)); // Notes input
@@ -211,7 +318,7 @@ fn render_workout_modal_content(
     // --- Render fields conditionally, tracking chunk index ---
     let mut current_chunk_index = 0;
     // Store results in fixed-size array or vec, using default Rect for hidden fields
-    let mut input_areas = vec![Rect::default(); 7]; // 0:Ex, 1:Sets, 2:Reps, 3:Wt, 4:Dur, 5:Dist, 6:Notes
+    let mut input_areas = vec![Rect::default(); 9]; // 0:Ex, 1:Sets, 2:Reps, 3:Wt, 4:Dur, 5:Dist, 6:Notes, 7:RPE, 8:RIR
 
     // Exercise
     f.render_widget(Paragraph::new(title_line), chunks[current_chunk_index]);
@@ -219,6 +326,7 @@ fn render_workout_modal_content(
     if is_exercise_editable {
         let exercise_text_area = render_input_field(
             f,
+            &app.theme,
             chunks[current_chunk_index], // Use the dedicated input chunk
             "",                          // No separate label needed, title acts as label
             exercise_input,
@@ -236,6 +344,7 @@ fn render_workout_modal_content(
         let (sets_area, reps_area) = if flags.log_sets && flags.log_reps {
             render_horizontal_input_pair(
                 f,
+                &app.theme,
                 chunk,
                 "Sets:",
                 sets_input,
@@ -248,6 +357,7 @@ fn render_workout_modal_content(
             // Only Sets visible
             let area = render_input_field(
                 f,
+                &app.theme,
                 chunk,
                 "Sets:",
                 sets_input,
@@ -258,6 +368,7 @@ fn render_workout_modal_content(
             // Only Reps visible
             let area = render_input_field(
                 f,
+                &app.theme,
                 chunk,
                 "Reps:",
                 reps_input,
@@ -277,6 +388,7 @@ fn render_workout_modal_content(
         let (weight_area, duration_area) = if flags.log_weight && flags.log_duration {
             render_horizontal_input_pair(
                 f,
+                &app.theme,
                 chunk,
                 &weight_label_text,
                 weight_input,
@@ -288,6 +400,7 @@ fn render_workout_modal_content(
         } else if flags.log_weight {
             let area = render_input_field(
                 f,
+                &app.theme,
                 chunk,
                 &weight_label_text,
                 weight_input,
@@ -297,6 +410,7 @@ fn render_workout_modal_content(
         } else {
             let area = render_input_field(
                 f,
+                &app.theme,
                 chunk,
                 "Duration (min):",
                 duration_input,
@@ -308,12 +422,36 @@ fn render_workout_modal_content(
         input_areas[4] = duration_area;
     }
 
+    // Progression suggestion, right under the prefilled reps/weight
+    if let Some(suggestion) = progression_suggestion {
+        let chunk = chunks[current_chunk_index];
+        current_chunk_index += 1;
+        f.render_widget(
+            Paragraph::new(progression_suggestion_line(
+                &app.theme,
+                suggestion,
+                weight_unit,
+                app.keymap
+                    .first_key(KeyContext::WorkoutModal, Action::AcceptSuggestion),
+            )),
+            chunk,
+        );
+    }
+
+    // Plate calculator
+    if show_plates {
+        let chunk = chunks[current_chunk_index];
+        current_chunk_index += 1;
+        render_plate_panel(f, app, chunk, weight_input, weight_unit);
+    }
+
     // Distance
     if flags.log_distance {
         let chunk = chunks[current_chunk_index];
         current_chunk_index += 1;
         let distance_area = render_input_field(
             f,
+            &app.theme,
             chunk,
             &format!("Distance ({dist_unit}):"),
             distance_input,
@@ -322,14 +460,48 @@ fn render_workout_modal_content(
         input_areas[5] = distance_area;
     }
 
+    // Set type (cycled, so no cursor) and RPE/RIR
+    let set_type_text = format!("◀ {} ▶", set_type.map_or("Untagged", SetType::label));
+    render_input_field(
+        f,
+        &app.theme,
+        chunks[current_chunk_index],
+        "Set Type (←/→):",
+        &set_type_text,
+        *focused_field == AddWorkoutField::SetType,
+    );
+    current_chunk_index += 1;
+    if flags.log_effort {
+        let (rpe_area, rir_area) = render_horizontal_input_pair(
+            f,
+            &app.theme,
+            chunks[current_chunk_index],
+            "RPE (1-10):",
+            rpe_input,
+            *focused_field == AddWorkoutField::Rpe,
+            "RIR:",
+            rir_input,
+            *focused_field == AddWorkoutField::Rir,
+        );
+        input_areas[7] = rpe_area;
+        input_areas[8] = rir_area;
+        current_chunk_index += 1;
+    }
+
     // Notes
     if flags.log_notes {
         let label_chunk = chunks[current_chunk_index];
         current_chunk_index += 1;
         let input_chunk = chunks[current_chunk_index];
         current_chunk_index += 1;
-        input_areas[6] =
-            render_notes_field(f, label_chunk, input_chunk, notes_input, focused_field);
+        input_areas[6] = render_notes_field(
+            f,
+            &app.theme,
+            label_chunk,
+            input_chunk,
+            notes_input,
+            focused_field,
+        );
     }
 
     // Spacer
@@ -341,15 +513,121 @@ fn render_workout_modal_content(
         AddWorkoutField::Cancel => Some(1),
         _ => None,
     };
-    render_button_pair(f, chunks[current_chunk_index], "OK", "Cancel", button_focus);
+    let button_areas = render_button_pair(
+        f,
+        &app.theme,
+        chunks[current_chunk_index],
+        "OK",
+        "Cancel",
+        button_focus,
+    );
     current_chunk_index += 1;
 
     // Error Message
     if chunks.len() > current_chunk_index {
-        render_error_message(f, chunks[current_chunk_index], error_message);
+        render_error_message(f, &app.theme, chunks[current_chunk_index], error_message);
+    }
+
+    (input_areas, button_areas)
+}
+
+/// `Next: 102.5 kg x 5 (hit 5 reps on every set (05-12))  [Ctrl+A] accept`
+fn progression_suggestion_line(
+    theme: &Theme,
+    suggestion: &ProgressionSuggestion,
+    weight_unit: &str,
+    accept_key: Option<KeyChord>,
+) -> Line<'static> {
+    let style = match suggestion.kind {
+        SuggestionKind::AddWeight | SuggestionKind::AddRep => theme.success,
+        SuggestionKind::Repeat => theme.warning,
+        SuggestionKind::Deload => theme.error,
+    };
+    let mut spans = vec![
+        Span::raw("Next: "),
+        Span::styled(
+            format!(
+                "{:.1} {} x {}",
+                suggestion.weight, weight_unit, suggestion.reps
+            ),
+            style.bold(),
+        ),
+        Span::styled(format!(" ({})", suggestion.reason), theme.axis),
+    ];
+    if let Some(key) = accept_key {
+        spans.push(Span::styled(format!("  [{}] accept", key), theme.muted));
     }
+    Line::from(spans)
+}
+
+/// The plate panel only makes sense for barbell-style resistance exercises.
+fn shows_plate_panel(app: &App, resolved_exercise: Option<&ExerciseDefinition>) -> bool {
+    app.plate_calculator_visible
+        && resolved_exercise.is_some_and(|def| {
+            def.type_ == ExerciseType::Resistance && WorkoutLogFlags::from_def(Some(def)).log_weight
+        })
+}
 
-    input_areas
+/// Lists the plates for one side of the bar, and warns when the weight can't be loaded.
+fn render_plate_panel(f: &mut Frame, app: &App, area: Rect, weight_input: &str, weight_unit: &str) {
+    let inventory = app.settings.plates.for_units(app.service.config.units);
+    let hide_hint = app
+        .keymap
+        .first_key(KeyContext::WorkoutModal, Action::TogglePlates)
+        .map(|key| format!(" ({} to hide)", key))
+        .unwrap_or_default();
+    let block = Block::default()
+        .title(format!(
+            "Plates per side - {} {} bar{}",
+            inventory.bar_weight, weight_unit, hide_hint
+        ))
+        .borders(Borders::ALL)
+        .border_style(app.theme.unfocused_border);
+
+    let warning = app.theme.warning;
+    let lines = match weight_input.trim().parse::<f64>() {
+        Err(_) => vec![Line::styled(
+            "Enter a weight to see the plates",
+            app.theme.muted,
+        )],
+        Ok(target) => match calculate_plates(target, inventory) {
+            None => vec![Line::styled("Too heavy to calculate", warning)],
+            Some(load) if load.below_bar() => vec![Line::styled(
+                format!("Lighter than the {} {} bar", load.bar_weight, weight_unit),
+                warning,
+            )],
+            Some(load) => {
+                let plates = if load.per_side.is_empty() {
+                    "Empty bar".to_string()
+                } else {
+                    load.per_side
+                        .iter()
+                        .map(|(weight, count)| match count {
+                            1 => format!("{}", weight),
+                            n => format!("{} x{}", weight, n),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" + ")
+                };
+                let status = if load.is_exact() {
+                    Line::styled(
+                        format!("= {:.2} {}", load.loaded, weight_unit),
+                        app.theme.success,
+                    )
+                } else {
+                    Line::styled(
+                        format!(
+                            "Can't load {:.2} {} exactly, closest is {:.2}",
+                            load.target, weight_unit, load.loaded
+                        ),
+                        warning,
+                    )
+                };
+                vec![Line::from(plates).bold(), status]
+            }
+        },
+    };
+    f.render_widget(Paragraph::new(lines).block(block), area);
 }
 
 /// Helper to get the appropriate weight and distance units based on config.
@@ -372,6 +650,7 @@ fn get_weight_label(resolved_exercise: Option<&ExerciseDefinition>, weight_unit:
 /// Renders the notes label and input field. Returns the Rect of the text input area.
 fn render_notes_field(
     f: &mut Frame,
+    theme: &Theme,
     label_area: Rect,
     input_area: Rect,
     notes_input: &str,
@@ -380,9 +659,9 @@ fn render_notes_field(
     f.render_widget(Paragraph::new("Notes:"), label_area);
 
     let notes_style = if *focused_field == AddWorkoutField::Notes {
-        Style::default().fg(Color::White).reversed()
+        theme.text.reversed()
     } else {
-        Style::default().fg(Color::White)
+        theme.text
     };
     // Add a small margin for the notes input and a visual indicator (like border)
     let notes_text_area = input_area.inner(&Margin {
@@ -402,17 +681,23 @@ fn render_notes_field(
 /// Helper to position the cursor within the Add/Edit Workout modal fields.
 fn position_cursor_for_workout(
     f: &mut Frame,
-    focused_field: &AddWorkoutField,
-    exercise_input: &str,
-    sets_input: &str,
-    reps_input: &str,
-    weight_input: &str,
-    duration_input: &str,
-    distance_input: &str,
-    notes_input: &str,
-    input_areas: &[Rect], // Expecting 7 areas (index 0 might be dummy)
+    fields: &WorkoutFields,
+    input_areas: &[Rect], // Expecting 9 areas (index 0 might be dummy)
 ) {
-    if input_areas.len() < 7 {
+    let WorkoutFields {
+        focused_field,
+        exercise_input,
+        sets_input,
+        reps_input,
+        weight_input,
+        duration_input,
+        distance_input,
+        rpe_input,
+        rir_input,
+        notes_input,
+        ..
+    } = *fields;
+    if input_areas.len() < 9 {
         return;
     } // Safety check
 
@@ -447,6 +732,14 @@ fn position_cursor_for_workout(
             let (x, y) = get_cursor_pos(distance_input, &input_areas[5]);
             f.set_cursor(x, y);
         }
+        AddWorkoutField::Rpe if !input_areas[7].is_empty() => {
+            let (x, y) = get_cursor_pos(rpe_input, &input_areas[7]);
+            f.set_cursor(x, y);
+        }
+        AddWorkoutField::Rir if !input_areas[8].is_empty() => {
+            let (x, y) = get_cursor_pos(rir_input, &input_areas[8]);
+            f.set_cursor(x, y);
+        }
         AddWorkoutField::Notes if !input_areas[6].is_empty() => {
             let lines: Vec<&str> = notes_input.lines().collect();
             let last_line = lines.last().unwrap_or(&"");
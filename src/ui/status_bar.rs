@@ -1,22 +1,22 @@
 //src/ui/status_bar.rs
 // task-athlete-tui/src/ui/status_bar.rs
-use crate::app::{state::ActiveModal, AddWorkoutField, App}; // Use App from crate::app
+use crate::app::{
+    keymap::{Action, KeyContext},
+    state::ActiveModal,
+    AddWorkoutField, App,
+}; // Use App from crate::app
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::Modifier,
+    text::{Line, Span},
     widgets::Paragraph,
     Frame,
 };
 
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let status_text = match &app.active_modal {
-         ActiveModal::None => match app.active_tab {
-             crate::app::ActiveTab::Log => "[Tab] Focus | [↑↓/jk] Nav | [←→/hl] Date | [a]dd | [l]og set | [e]dit | [d]elete | [g]raphs | [?] Help | [Q]uit ",
-              crate::app::ActiveTab::History => "[↑↓/jk] Scroll Days | [?] Help | [Q]uit ",
-             crate::app::ActiveTab::Bodyweight => "[↑↓/jk] Nav Hist | [l]og | [t]arget | [r]ange | [?] Help | [Q]uit ",
-             crate::app::ActiveTab::Graphs => "[Tab] Focus | [↑↓/jk] Nav List | [Enter] Select | [?] Help | [Q]uit ",
-         }.to_string(),
-         ActiveModal::Help => " [Esc/Enter/?] Close Help ".to_string(),
+         ActiveModal::None => tab_hints(app),
+         ActiveModal::Help => " [Esc/Enter] Close Help | [↑↓/jk/PgUp/PgDn] Scroll ".to_string(),
          ActiveModal::LogBodyweight { .. } => " [Esc] Cancel | [Enter] Confirm | [Tab/↑↓] Navigate ".to_string(),
          ActiveModal::SetTargetWeight { .. } => " [Esc] Cancel | [Enter] Confirm | [Tab/↑↓] Navigate ".to_string(),
          ActiveModal::AddWorkout { focused_field, exercise_suggestions, .. } => { // Destructure focused_field
@@ -27,29 +27,170 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                      "Type name/alias | [Tab] Next Field | [Esc] Cancel".to_string(),
                  AddWorkoutField::Suggestions =>
                      "[↑↓] Select | [Enter] Confirm Suggestion | [Esc/Tab] Back to Input".to_string(),
+                 AddWorkoutField::SetType =>
+                     "[←→/Space] Cycle Set Type | [Backspace] Untag | [Tab] Next Field | [Esc] Cancel".to_string(),
                  _ => // Generic hint for other fields
-                      "[Esc] Cancel | [Enter] Confirm/Next | [Tab/↑↓] Navigate | [↑↓ Arrow] Inc/Dec Number ".to_string(),
+                      modal_hints(app, "[Esc] Cancel | [Enter] Confirm/Next | [Tab/↑↓] Navigate | [↑↓ Arrow] Inc/Dec Number", KeyContext::WorkoutModal, |_| true),
              }
              },
          ActiveModal::CreateExercise { .. } => " [Esc] Cancel | [Enter] Confirm/Next | [Tab/↑↓/←→] Navigate ".to_string(),
-         ActiveModal::EditWorkout { .. } => " [Esc] Cancel | [Enter] Confirm/Next | [Tab/↑↓] Navigate ".to_string(),
+         ActiveModal::EditWorkout { .. } => modal_hints(app, "[Esc] Cancel | [Enter] Confirm/Next | [Tab/↑↓] Navigate", KeyContext::WorkoutModal, |action| action == Action::TogglePlates),
          ActiveModal::PersonalBest{ .. } => " [Esc] Cancel | [Enter] Confirm/Next | [Tab/↑↓] Navigate ".to_string(),
-         ActiveModal::ConfirmDeleteWorkout {..} | ActiveModal::ConfirmDeleteBodyWeight  { .. } => " Confirm Deletion: [Y]es / [N]o (Esc) ".to_string(),
+         ActiveModal::SessionSummary { .. } => " [Esc/Enter] Close Summary ".to_string(),
+         ActiveModal::Routines { .. } => modal_hints(app, "", KeyContext::Routines, |_| true),
+         ActiveModal::AliasManager { focused_field, .. } => match focused_field {
+             crate::app::state::AliasManagerField::Input => " Type alias | [Enter] Add | [Tab] Alias List | [Esc] Close ".to_string(),
+             crate::app::state::AliasManagerField::List => modal_hints(app, "", KeyContext::Aliases, |_| true),
+         },
+         ActiveModal::BulkDate { .. } => " Type date | [Enter] Confirm | [Esc] Cancel ".to_string(),
+         ActiveModal::BulkEdit { .. } => " [Esc] Cancel | [Enter] Confirm/Next | [Tab/↑↓] Navigate ".to_string(),
+         ActiveModal::CopyDay { focused_field, .. } => match focused_field {
+             crate::app::state::CopyDayField::Sets => " [↑↓/jk] Select | Type/[+-] Weight | [Space] Include/Skip | [Enter] Copy | [Tab] Buttons | [Esc] Cancel ",
+             _ => " [Esc] Cancel | [Enter] Copy | [Tab/↑↓] Navigate ",
+         }.to_string(),
+         ActiveModal::GraphRange { .. } => " Type dates | [Esc] Cancel | [Enter] Confirm/Next | [Tab/↑↓] Navigate ".to_string(),
+         ActiveModal::CreateRoutine { .. } => " [Esc] Back | [Enter] Confirm/Next | [Tab/↑↓] Navigate ".to_string(),
+         ActiveModal::ConfirmDeleteWorkout {..} | ActiveModal::ConfirmDeleteBodyWeight  { .. } | ActiveModal::ConfirmDeleteExercise { .. }
+         | ActiveModal::ConfirmDeleteWorkouts { .. } => modal_hints(app, "Confirm Deletion:", KeyContext::Confirm, |_| true),
      };
 
-    let error_text = app.last_error.as_deref().unwrap_or("");
+    // Errors take precedence over the sync spinner, which takes precedence over notices
+    let right_line = if let Some(err) = app.last_error.as_deref() {
+        Line::styled(err.to_string(), app.theme.error)
+    } else if let Some(frame) = app.sync_spinner_frame() {
+        Line::styled(format!("{} Syncing... ", frame), app.theme.warning)
+    } else if let Some(info) = app.last_info.as_deref() {
+        Line::styled(info.to_string(), app.theme.success)
+    } else if let Some(timer) = rest_timer_span(app) {
+        Line::from(vec![timer, Span::raw(" ")])
+    } else {
+        Line::default()
+    };
 
     let status_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(80), Constraint::Percentage(20)])
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(area);
 
-    let status_paragraph =
-        Paragraph::new(status_text).style(Style::default().bg(Color::DarkGray).fg(Color::White));
+    let status_paragraph = Paragraph::new(status_text).style(app.theme.status_bar);
     f.render_widget(status_paragraph, status_chunks[0]);
 
-    let error_paragraph = Paragraph::new(error_text)
-        .style(Style::default().bg(Color::DarkGray).fg(Color::Red))
+    let right_paragraph = Paragraph::new(right_line)
+        .style(app.theme.status_bar)
         .alignment(ratatui::layout::Alignment::Right);
-    f.render_widget(error_paragraph, status_chunks[1]);
+    f.render_widget(right_paragraph, status_chunks[1]);
+}
+
+/// Paired actions shown as one hint, like "[k/j] Nav".
+const PAIRED_HINTS: &[(Action, Action, &str)] = &[
+    (Action::Up, Action::Down, "Nav"),
+    (Action::PreviousDay, Action::NextDay, "Day"),
+    (Action::PreviousWeek, Action::NextWeek, "Week"),
+    (Action::PreviousMonth, Action::NextMonth, "Month"),
+];
+
+/// Hints of the paired actions of `context` that are bound, like "[k/j] Nav".
+fn paired_hints(app: &App, context: KeyContext) -> Vec<String> {
+    PAIRED_HINTS
+        .iter()
+        .filter_map(|&(previous, next, label)| {
+            let previous = app.keymap.first_key(context, previous)?;
+            let next = app.keymap.first_key(context, next)?;
+            Some(format!("[{}/{}] {}", previous, next, label))
+        })
+        .collect()
+}
+
+/// A modal's fixed keys (typing and field navigation), then its shortcuts from the keymap.
+fn modal_hints(
+    app: &App,
+    fixed: &str,
+    context: KeyContext,
+    shown: impl Fn(Action) -> bool,
+) -> String {
+    let mut hints = paired_hints(app, context);
+    hints.extend(app.keymap.hints(context, shown));
+    let hints = hints.join(" | ");
+    match (fixed.is_empty(), hints.is_empty()) {
+        (true, _) => format!(" {} ", hints),
+        (false, true) => format!(" {} ", fixed),
+        (false, false) if fixed.ends_with(':') => format!(" {} {} ", fixed, hints),
+        (false, false) => format!(" {} | {} ", fixed, hints),
+    }
+}
+
+/// Hints for the active tab and the part of it that has focus, when no modal is open.
+fn tab_hints(app: &App) -> String {
+    match app.active_tab {
+        crate::app::ActiveTab::Log => {
+            keymap_hints(app, &[app.log_focus_context(), KeyContext::Log])
+        }
+        crate::app::ActiveTab::History => keymap_hints(app, &[KeyContext::History]),
+        crate::app::ActiveTab::Bodyweight => keymap_hints(app, &[KeyContext::Bodyweight]),
+        crate::app::ActiveTab::Graphs => keymap_hints(app, &[KeyContext::Graphs]),
+        crate::app::ActiveTab::Calendar => match app.calendar_view {
+            crate::app::state::CalendarView::CalendarView => {
+                keymap_hints(app, &[KeyContext::CalendarGrid, KeyContext::Calendar])
+            }
+            crate::app::state::CalendarView::ListView => match app.calendar_focus {
+                crate::app::state::CalendarFocus::FilterInput => {
+                    "[Enter] Apply Filter | [Esc] Cancel | [Tab] Focus List ".to_string()
+                }
+                crate::app::state::CalendarFocus::WorkoutList => {
+                    keymap_hints(app, &[KeyContext::CalendarList, KeyContext::Calendar])
+                }
+            },
+        },
+        crate::app::ActiveTab::Exercises => match app.exercises_focus {
+            crate::app::state::ExercisesFocus::SearchInput => {
+                "Type to search | [Enter/Tab] Focus List | [Esc] Clear Search ".to_string()
+            }
+            crate::app::state::ExercisesFocus::List => keymap_hints(app, &[KeyContext::Exercises]),
+        },
+    }
+}
+
+/// Hints of the given contexts (innermost first) from the active keymap, then the global ones.
+/// While a rest timer runs the Log tab shows its controls instead of the usual keys.
+fn keymap_hints(app: &App, contexts: &[KeyContext]) -> String {
+    let timer_running = app.active_tab == crate::app::ActiveTab::Log && app.rest_timer.is_some();
+    let mut hints = Vec::new();
+    for &context in contexts {
+        if !timer_running {
+            hints.extend(paired_hints(app, context));
+        }
+        hints.extend(app.keymap.hints(context, |action| {
+            if timer_running {
+                action.is_rest_timer() || action == Action::AddWorkout
+            } else {
+                !action.is_rest_timer()
+            }
+        }));
+    }
+    hints.extend(app.keymap.hints(KeyContext::Global, |_| true));
+    format!("{} ", hints.join(" | "))
+}
+
+/// Rest timer text, shared by the status bar and the Log tab header.
+pub(super) fn rest_timer_span(app: &App) -> Option<Span<'static>> {
+    let timer = app.rest_timer.as_ref()?;
+    let span = if timer.is_finished() {
+        let style = if app.rest_timer_flash_on() {
+            app.theme.success.add_modifier(Modifier::REVERSED)
+        } else {
+            app.theme.success
+        };
+        Span::styled("Rest over!", style.add_modifier(Modifier::BOLD))
+    } else if timer.is_paused() {
+        Span::styled(
+            format!("Rest {} (paused)", timer.format_remaining()),
+            app.theme.axis,
+        )
+    } else {
+        Span::styled(
+            format!("Rest {}", timer.format_remaining()),
+            app.theme.warning,
+        )
+    };
+    Some(span)
 }
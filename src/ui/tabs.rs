@@ -1,34 +1,45 @@
-use crate::app::{ActiveTab, App}; // Use App from crate::app
+use crate::app::{mouse::ClickTarget, ActiveTab, App}; // Use App from crate::app
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Tabs},
     Frame,
 };
 
-pub fn render(f: &mut Frame, app: &App, area: Rect) {
-    let titles: Vec<Line> = ["Log (F1)", "History (F2)", "Graphs (F3)", "Bodyweight (F4)"]
+const TABS: [(ActiveTab, &str); 6] = [
+    (ActiveTab::Log, "Log (F1)"),
+    (ActiveTab::History, "History (F2)"),
+    (ActiveTab::Graphs, "Graphs (F3)"),
+    (ActiveTab::Bodyweight, "Bodyweight (F4)"),
+    (ActiveTab::Calendar, "Calendar (F5)"),
+    (ActiveTab::Exercises, "Exercises (F6)"),
+];
+
+pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
+    let titles: Vec<Line> = TABS
         .iter()
-        .map(|t| Line::from(Span::styled(*t, Style::default().fg(Color::Gray))))
+        .map(|(_, t)| Line::from(Span::styled(*t, app.theme.tab)))
         .collect();
 
-    let selected_tab_index = match app.active_tab {
-        ActiveTab::Log => 0,
-        ActiveTab::History => 1,
-        ActiveTab::Graphs => 2,
-        ActiveTab::Bodyweight => 3,
-    };
+    let selected_tab_index = TABS
+        .iter()
+        .position(|(tab, _)| *tab == app.active_tab)
+        .unwrap_or(0);
 
     let tabs = Tabs::new(titles)
         .block(Block::default().borders(Borders::BOTTOM))
         .select(selected_tab_index)
-        .style(Style::default().fg(Color::Gray))
-        .highlight_style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        );
+        .style(app.theme.tab)
+        .highlight_style(app.theme.tab_active);
 
     f.render_widget(tabs, area);
+
+    // Tabs pads each title with a space on both sides and puts a one-column divider between them
+    let mut x = area.x;
+    for (tab, title) in TABS {
+        let width = title.chars().count() as u16 + 2;
+        let title_area = Rect::new(x, area.y, width, area.height).intersection(area);
+        app.click_areas.push(title_area, ClickTarget::Tab(tab));
+        x = x.saturating_add(width + 1);
+    }
 }